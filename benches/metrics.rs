@@ -0,0 +1,56 @@
+//! Benchmarks for curriculum metrics on synthetic DAGs
+//!
+//! Run with `cargo bench --bench metrics`.
+
+#![allow(missing_docs)]
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use nu_analytics::core::metrics::{compute_blocking, compute_centrality, compute_delay};
+use nu_analytics::core::synthetic::generate_dag;
+use std::hint::black_box;
+
+/// Graph sizes for delay and blocking, which scale polynomially
+const SIZES: [usize; 3] = [100, 500, 1000];
+
+/// Smaller graph sizes for centrality, which enumerates every path
+const CENTRALITY_SIZES: [usize; 3] = [25, 50, 75];
+
+/// Edge probability used for every generated graph
+const DENSITY: f64 = 0.05;
+
+fn bench_delay_and_blocking(c: &mut Criterion) {
+    let mut group = c.benchmark_group("delay_blocking");
+    for size in SIZES {
+        let dag = generate_dag(size, DENSITY, 7);
+        group.bench_with_input(BenchmarkId::new("compute_delay", size), &dag, |b, dag| {
+            b.iter(|| compute_delay(black_box(dag)));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("compute_blocking", size),
+            &dag,
+            |b, dag| {
+                b.iter(|| compute_blocking(black_box(dag)));
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_centrality(c: &mut Criterion) {
+    let mut group = c.benchmark_group("centrality");
+    group.sample_size(10);
+    for size in CENTRALITY_SIZES {
+        let dag = generate_dag(size, DENSITY, 7);
+        group.bench_with_input(
+            BenchmarkId::new("compute_centrality", size),
+            &dag,
+            |b, dag| {
+                b.iter(|| compute_centrality(black_box(dag)));
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_delay_and_blocking, bench_centrality);
+criterion_main!(benches);
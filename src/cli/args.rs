@@ -4,6 +4,7 @@ use clap::{builder::BoolishValueParser, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 use nu_analytics::config::ConfigOverrides;
+use nu_analytics::core::metrics::MetricsSort;
 use nu_analytics::logger::Level;
 
 /// CLI log level argument
@@ -12,6 +13,8 @@ use nu_analytics::logger::Level;
 /// strings for config storage and to `nu_analytics::logger::Level` for runtime use.
 #[derive(Copy, Clone, Debug, ValueEnum, PartialEq, Eq)]
 pub enum LogLevelArg {
+    /// No logging at all, not even errors
+    Off,
     /// Error-level logging
     Error,
     /// Warning-level logging
@@ -20,15 +23,19 @@ pub enum LogLevelArg {
     Info,
     /// Debug-level logging
     Debug,
+    /// Trace-level logging (requires the `log-trace` feature)
+    Trace,
 }
 
 impl From<LogLevelArg> for Level {
     fn from(arg: LogLevelArg) -> Self {
         match arg {
+            LogLevelArg::Off => Self::Off,
             LogLevelArg::Error => Self::Error,
             LogLevelArg::Warn => Self::Warn,
             LogLevelArg::Info => Self::Info,
             LogLevelArg::Debug => Self::Debug,
+            LogLevelArg::Trace => Self::Trace,
         }
     }
 }
@@ -36,10 +43,12 @@ impl From<LogLevelArg> for Level {
 impl std::fmt::Display for LogLevelArg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let as_str = match self {
+            Self::Off => "off",
             Self::Error => "error",
             Self::Warn => "warn",
             Self::Info => "info",
             Self::Debug => "debug",
+            Self::Trace => "trace",
         };
         write!(f, "{as_str}")
     }
@@ -56,6 +65,8 @@ pub enum ReportFormatArg {
     Md,
     /// PDF format (not yet implemented)
     Pdf,
+    /// Edge-list CSV of the requisite graph (`from,to,type`) for graph tools
+    Edges,
 }
 
 impl ReportFormatArg {
@@ -66,6 +77,7 @@ impl ReportFormatArg {
             Self::Html => "html",
             Self::Md => "md",
             Self::Pdf => "pdf",
+            Self::Edges => "csv",
         }
     }
 
@@ -76,6 +88,7 @@ impl ReportFormatArg {
             "html" | "htm" => Some(Self::Html),
             "md" | "markdown" => Some(Self::Md),
             "pdf" => Some(Self::Pdf),
+            "edges" => Some(Self::Edges),
             _ => None,
         }
     }
@@ -83,7 +96,37 @@ impl ReportFormatArg {
 
 impl std::fmt::Display for ReportFormatArg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.extension())
+        match self {
+            Self::Edges => write!(f, "edges"),
+            _ => write!(f, "{}", self.extension()),
+        }
+    }
+}
+
+/// Column used to order the metrics table and CSV rows
+#[derive(Copy, Clone, Debug, ValueEnum, PartialEq, Eq)]
+pub enum MetricsSortArg {
+    /// Highest complexity first (default)
+    Complexity,
+    /// Highest blocking factor first
+    Blocking,
+    /// Highest delay factor first
+    Delay,
+    /// Highest centrality first
+    Centrality,
+    /// Course key, ascending
+    Key,
+}
+
+impl From<MetricsSortArg> for MetricsSort {
+    fn from(arg: MetricsSortArg) -> Self {
+        match arg {
+            MetricsSortArg::Complexity => Self::Complexity,
+            MetricsSortArg::Blocking => Self::Blocking,
+            MetricsSortArg::Delay => Self::Delay,
+            MetricsSortArg::Centrality => Self::Centrality,
+            MetricsSortArg::Key => Self::Key,
+        }
     }
 }
 
@@ -114,7 +157,15 @@ pub enum ConfigSubcommand {
         key: String,
     },
     /// Reset configuration to defaults (requires confirmation).
-    Reset,
+    ///
+    /// Prompts on a terminal; non-interactive runs must pass `--yes`.
+    Reset {
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Show only the values that differ from the defaults.
+    Diff,
 }
 
 #[derive(Debug, Subcommand)]
@@ -160,7 +211,7 @@ pub enum Command {
         #[arg(short, long, value_name = "FILES", num_args = 1..)]
         output: Vec<std::path::PathBuf>,
 
-        /// Report format when generating reports (html, md, pdf)
+        /// Report format when generating reports (html, md, pdf, edges)
         ///
         /// Used when -o is not provided or when -o extension conflicts (with warning).
         /// Defaults to html if not specified.
@@ -186,6 +237,23 @@ pub enum Command {
         #[arg(long, value_name = "CREDITS")]
         term_credits: Option<f32>,
 
+        /// Order metrics table and CSV rows by this column
+        /// (default: complexity, highest first)
+        #[arg(long, value_enum, value_name = "COLUMN")]
+        sort: Option<MetricsSortArg>,
+
+        /// Compute metrics over the plan's courses only instead of the whole catalog
+        ///
+        /// Blocking, delay, and centrality then count only requisite chains
+        /// within the plan, matching what a student on it experiences.
+        #[arg(long)]
+        plan_scoped: bool,
+
+        /// Write a single html or md report with the metrics and schedule embedded
+        /// as JSON, instead of a separate metrics CSV
+        #[arg(long, conflicts_with = "no_report")]
+        combined: bool,
+
         /// Skip CSV metrics generation
         #[arg(long)]
         no_csv: bool,
@@ -193,6 +261,55 @@ pub enum Command {
         /// Skip report generation
         #[arg(long)]
         no_report: bool,
+
+        /// Keep running and regenerate reports whenever an input file changes
+        /// (requires the `watch` feature)
+        #[cfg(feature = "watch")]
+        #[arg(long, conflicts_with = "no_report")]
+        watch: bool,
+    },
+    /// List the plans in a curriculum CSV.
+    ///
+    /// Prints each plan's name, degree, course count, and total credits.
+    Plans {
+        /// Path to the curriculum CSV file
+        #[arg(value_name = "FILE")]
+        input_file: std::path::PathBuf,
+    },
+    /// Find courses in a curriculum CSV by partial name or code.
+    ///
+    /// Matching is a case-insensitive substring search over each course's
+    /// code, name, and canonical name.
+    Search {
+        /// Path to the curriculum CSV file
+        #[arg(value_name = "FILE")]
+        input_file: std::path::PathBuf,
+
+        /// Partial course name or code (e.g., "discrete" or "cs 18")
+        #[arg(value_name = "QUERY")]
+        query: String,
+    },
+    /// Write a blank curriculum CSV template to start a new curriculum from.
+    ///
+    /// The template has the metadata rows, the course header row, and two example courses.
+    InitTemplate {
+        /// Path to write the template to
+        #[arg(value_name = "OUTPUT")]
+        output: std::path::PathBuf,
+
+        /// Overwrite OUTPUT if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Serve curriculum analysis over HTTP (requires the `server` feature).
+    ///
+    /// Exposes `POST /analyze`, which accepts curriculum CSV text and returns
+    /// the computed metrics as JSON.
+    #[cfg(feature = "server")]
+    Serve {
+        /// Address to listen on
+        #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:8080")]
+        addr: String,
     },
 }
 
@@ -316,6 +433,8 @@ mod tests {
         assert_eq!(LogLevelArg::Warn.to_string(), "warn");
         assert_eq!(LogLevelArg::Info.to_string(), "info");
         assert_eq!(LogLevelArg::Debug.to_string(), "debug");
+        assert_eq!(LogLevelArg::Trace.to_string(), "trace");
+        assert_eq!(LogLevelArg::Off.to_string(), "off");
     }
 
     #[test]
@@ -2,7 +2,8 @@
 
 use crate::args::ConfigSubcommand;
 use nu_analytics::config::Config;
-use std::io::{self, Write};
+use nu_analytics::logger::flush_log_file;
+use std::io::{self, BufRead, IsTerminal, Write};
 
 /// Dispatch config subcommands
 ///
@@ -19,7 +20,8 @@ pub fn run(subcommand: Option<ConfigSubcommand>, config: &mut Config, defaults:
         Some(ConfigSubcommand::Get { key }) => handle_config_get(config, key),
         Some(ConfigSubcommand::Set { key, value }) => handle_config_set(config, &key, &value),
         Some(ConfigSubcommand::Unset { key }) => handle_config_unset(config, defaults, &key),
-        Some(ConfigSubcommand::Reset) => handle_config_reset(),
+        Some(ConfigSubcommand::Reset { yes }) => handle_config_reset(config, yes),
+        Some(ConfigSubcommand::Diff) => handle_config_diff(config, defaults),
     }
 }
 
@@ -57,14 +59,17 @@ pub fn handle_config_get(config: &Config, key: Option<String>) {
 pub fn handle_config_set(config: &mut Config, key: &str, value: &str) {
     if let Err(e) = config.set(key, value) {
         eprintln!("{e}");
+        flush_log_file();
         std::process::exit(1);
     }
 
     if let Err(e) = config.save() {
         eprintln!("Failed to save config: {e}");
+        flush_log_file();
         std::process::exit(1);
     }
 
+    write_audit_log(config);
     println!("✓ Set {key} = {value}");
 }
 
@@ -80,49 +85,128 @@ pub fn handle_config_set(config: &mut Config, key: &str, value: &str) {
 pub fn handle_config_unset(config: &mut Config, defaults: &Config, key: &str) {
     if let Err(e) = config.unset(key, defaults) {
         eprintln!("{e}");
+        flush_log_file();
         std::process::exit(1);
     }
 
     if let Err(e) = config.save() {
         eprintln!("Failed to save config: {e}");
+        flush_log_file();
         std::process::exit(1);
     }
 
+    write_audit_log(config);
     println!("✓ Reset {key} to default");
 }
 
+/// Handle the config diff subcommand
+///
+/// Displays each value that differs from its default alongside the default.
+///
+/// # Arguments
+/// * `config` - The configuration to compare
+/// * `defaults` - Default configuration values to compare against
+pub fn handle_config_diff(config: &Config, defaults: &Config) {
+    let changes = config.diff_from(defaults);
+    if changes.is_empty() {
+        println!("✓ Config matches defaults");
+        return;
+    }
+    for (key, default, current) in changes {
+        println!("{key} = \"{current}\" (default: \"{default}\")");
+    }
+}
+
+/// Append any recorded config changes to the audit log
+///
+/// Failing to write the audit log is reported but does not undo the change.
+fn write_audit_log(config: &mut Config) {
+    if let Err(e) = config.append_audit_log(&Config::get_audit_file_path()) {
+        eprintln!("Warning: Failed to write config audit log: {e}");
+    }
+}
+
 /// Handle the config reset subcommand
 ///
 /// Resets all configuration to defaults by deleting the config file. Requires user
-/// confirmation before proceeding. If the config file doesn't exist, reports success
-/// without prompting.
-pub fn handle_config_reset() {
+/// confirmation before proceeding: prompts on a terminal, and refuses to run
+/// non-interactively unless `yes` is set. If the config file doesn't exist,
+/// reports success without prompting.
+///
+/// # Arguments
+/// * `config` - The configuration being discarded (used to record the reset for auditing)
+/// * `yes` - Skip the confirmation prompt (`--yes`)
+pub fn handle_config_reset(config: &mut Config, yes: bool) {
     if !Config::get_config_file_path().exists() {
         println!("✓ Config is already at defaults");
         return;
     }
 
-    // Ask for confirmation
-    print!("Are you sure you want to reset config to defaults? (y/n): ");
-    if io::stdout().flush().is_err() {
-        eprintln!("Warning: Failed to flush stdout");
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    let confirmed = confirm_reset(yes, interactive, &mut stdin.lock(), &mut io::stdout());
+    match confirmed {
+        Ok(true) => {}
+        Ok(false) => {
+            println!("✗ Reset cancelled");
+            return;
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            flush_log_file();
+            std::process::exit(1);
+        }
     }
 
-    let mut response = String::new();
-    if io::stdin().read_line(&mut response).is_err() {
-        eprintln!("Failed to read user input");
+    if let Err(e) = Config::reset() {
+        eprintln!("Failed to remove config file: {e}");
+        flush_log_file();
         std::process::exit(1);
     }
+    config.record_reset();
+    write_audit_log(config);
+    println!("✓ Config reset to defaults");
+}
 
-    if response.trim().eq_ignore_ascii_case("y") || response.trim().eq_ignore_ascii_case("yes") {
-        if let Err(e) = Config::reset() {
-            eprintln!("Failed to remove config file: {e}");
-            std::process::exit(1);
-        }
-        println!("✓ Config reset to defaults");
-    } else {
-        println!("✗ Reset cancelled");
+/// Decide whether a config reset may proceed
+///
+/// `yes` skips the prompt entirely. Otherwise the user is asked on `output` and
+/// must answer `y`/`yes` on `input`; anything else (including an empty answer)
+/// declines. Without a terminal there is nobody to ask, so the reset is refused.
+///
+/// # Errors
+/// Returns an error when not `interactive` and `yes` is unset, or when the
+/// answer cannot be read
+fn confirm_reset(
+    yes: bool,
+    interactive: bool,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<bool, String> {
+    if yes {
+        return Ok(true);
+    }
+    if !interactive {
+        return Err(
+            "Refusing to reset config without confirmation; pass --yes to reset non-interactively"
+                .to_string(),
+        );
     }
+
+    write!(
+        output,
+        "Are you sure you want to reset config to defaults? [y/N]: "
+    )
+    .and_then(|()| output.flush())
+    .map_err(|e| format!("Failed to write prompt: {e}"))?;
+
+    let mut response = String::new();
+    input
+        .read_line(&mut response)
+        .map_err(|e| format!("Failed to read user input: {e}"))?;
+
+    let response = response.trim();
+    Ok(response.eq_ignore_ascii_case("y") || response.eq_ignore_ascii_case("yes"))
 }
 
 #[cfg(test)]
@@ -230,6 +314,47 @@ mod tests {
         assert!(result.unwrap_err().contains("Unknown config key"));
     }
 
+    #[test]
+    fn test_confirm_reset_yes_skips_prompt() {
+        let mut input = io::Cursor::new("");
+        let mut output = Vec::new();
+
+        assert_eq!(
+            confirm_reset(true, false, &mut input, &mut output),
+            Ok(true)
+        );
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_confirm_reset_refuses_without_terminal() {
+        let mut input = io::Cursor::new("y\n");
+        let mut output = Vec::new();
+
+        let result = confirm_reset(false, false, &mut input, &mut output);
+        assert!(result.unwrap_err().contains("--yes"));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_confirm_reset_prompts_on_terminal() {
+        for (answer, expected) in [
+            ("y\n", true),
+            ("YES\n", true),
+            ("\n", false),
+            ("n\n", false),
+        ] {
+            let mut input = io::Cursor::new(answer);
+            let mut output = Vec::new();
+
+            assert_eq!(
+                confirm_reset(false, true, &mut input, &mut output),
+                Ok(expected)
+            );
+            assert!(String::from_utf8(output).unwrap().contains("[y/N]"));
+        }
+    }
+
     #[test]
     fn test_handle_config_unset_all_keys() {
         let mut config = test_config();
@@ -0,0 +1,31 @@
+//! Init-template command handler - write a blank curriculum CSV
+
+use nu_analytics::core::planner::curriculum_template;
+use nu_analytics::error;
+use std::fs;
+use std::path::Path;
+
+/// Write the curriculum template to `output`
+///
+/// Refuses to replace an existing file unless `force` is set.
+///
+/// # Arguments
+/// * `output` - Path to write the template to
+/// * `force` - Overwrite `output` if it exists
+pub fn run(output: &Path, force: bool) {
+    if output.exists() && !force {
+        eprintln!(
+            "✗ {} already exists (use --force to overwrite)",
+            output.display()
+        );
+        return;
+    }
+
+    match fs::write(output, curriculum_template()) {
+        Ok(()) => println!("✓ Template written to {}", output.display()),
+        Err(e) => {
+            error!("Failed to write template {}: {e}", output.display());
+            eprintln!("✗ Failed to write {}: {e}", output.display());
+        }
+    }
+}
@@ -4,11 +4,20 @@
 //!
 //! ## Command Handlers
 //! - [`config`] - Configuration management
+//! - [`init_template`] - Write a blank curriculum CSV template
 //! - [`planner`] - Curriculum planning and CSV export
+//! - [`plans`] - List the plans in a curriculum file
+//! - [`search`] - Find courses by partial name or code
+//! - `watch` - Regenerate reports on input changes (requires the `watch` feature)
 //!
 //! ## Utilities
 //! - [`report`] - Report generation utilities (used by multiple commands)
 
 pub mod config;
+pub mod init_template;
 pub mod planner;
+pub mod plans;
 pub mod report;
+pub mod search;
+#[cfg(feature = "watch")]
+pub mod watch;
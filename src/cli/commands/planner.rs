@@ -1,7 +1,8 @@
 //! Planner command handler - CSV metrics export
 
 use nu_analytics::core::{
-    metrics, metrics_export,
+    metrics::{self, MetricsSort},
+    metrics_export::{self, CurriculumSummary, ExportError, ExportOptions},
     models::{Degree, Plan},
     planner::parse_curriculum_csv,
 };
@@ -14,9 +15,25 @@ use std::path::{Path, PathBuf};
 /// * `input_file` - Path to input CSV file
 /// * `output_file` - Optional explicit output path
 /// * `metrics_dir` - Directory for output when `output_file` is None
+/// * `sort` - Optional row order (defaults to CSV ID order)
+/// * `plan_scoped` - Compute metrics over the plan's courses only
 /// * `verbose` - Whether to show detailed metrics output
-pub fn run_single(input_file: &Path, output_file: Option<&Path>, metrics_dir: &str, verbose: bool) {
-    if let Err(err) = export_csv(input_file, output_file, metrics_dir, verbose) {
+pub fn run_single(
+    input_file: &Path,
+    output_file: Option<&Path>,
+    metrics_dir: &str,
+    sort: Option<MetricsSort>,
+    plan_scoped: bool,
+    verbose: bool,
+) {
+    if let Err(err) = export_csv(
+        input_file,
+        output_file,
+        metrics_dir,
+        sort,
+        plan_scoped,
+        verbose,
+    ) {
         error!("Planner failed for {}: {err}", input_file.display());
         eprintln!("{err}");
     }
@@ -26,6 +43,8 @@ fn export_csv(
     input_file: &Path,
     output_file: Option<&Path>,
     metrics_dir: &str,
+    sort: Option<MetricsSort>,
+    plan_scoped: bool,
     verbose: bool,
 ) -> Result<(), String> {
     let school = parse_curriculum_csv(input_file).map_err(|e| {
@@ -44,17 +63,6 @@ fn export_csv(
 
     let dag = school.build_dag();
 
-    let all_metrics = metrics::compute_all_metrics(&dag).map_err(|e| {
-        error!(
-            "Metrics computation failed for {}: {e}",
-            input_file.display()
-        );
-        format!(
-            "✗ Failed to compute metrics for {}: {e}",
-            input_file.display()
-        )
-    })?;
-
     let plan = if let Some(p) = school.plans.first() {
         p.clone()
     } else {
@@ -72,35 +80,25 @@ fn export_csv(
         default_plan
     };
 
-    let final_output_path: PathBuf = if let Some(output) = output_file {
-        // Ensure parent directory exists
-        if let Some(parent) = output.parent() {
-            std::fs::create_dir_all(parent).map_err(|e| {
-                format!(
-                    "✗ Failed to create output directory {}: {e}",
-                    parent.display()
-                )
-            })?;
-        }
-        output.to_path_buf()
+    let dag = if plan_scoped {
+        school.build_plan_dag(&plan)
     } else {
-        let metrics_path = PathBuf::from(metrics_dir);
-        std::fs::create_dir_all(&metrics_path).map_err(|e| {
-            format!(
-                "✗ Failed to create metrics directory {}: {e}",
-                metrics_path.display()
-            )
-        })?;
-
-        let filename = input_file
-            .file_stem()
-            .and_then(|stem| stem.to_str())
-            .unwrap_or("curriculum")
-            .to_string();
-        let output_filename = format!("{filename}_w_metrics.csv");
-        metrics_path.join(output_filename)
+        dag
     };
 
+    let all_metrics = metrics::compute_all_metrics(&dag).map_err(|e| {
+        error!(
+            "Metrics computation failed for {}: {e}",
+            input_file.display()
+        );
+        format!(
+            "✗ Failed to compute metrics for {}: {e}",
+            input_file.display()
+        )
+    })?;
+
+    let final_output_path = metrics_output_path(input_file, output_file, metrics_dir)?;
+
     let plan_name = plan.name.clone();
     let degree_label = school
         .degrees
@@ -111,8 +109,22 @@ fn export_csv(
         .clone()
         .unwrap_or_else(|| school.name.clone());
 
-    match metrics_export::export_metrics_csv(&school, &plan, &all_metrics, &final_output_path) {
-        Ok(summary) => {
+    let summary = CurriculumSummary::from_metrics(&plan, &school, &all_metrics)
+        .with_delay_path(&dag, &all_metrics);
+    let options = ExportOptions {
+        sort,
+        ..ExportOptions::default()
+    };
+
+    match metrics_export::export_metrics_csv_with_options(
+        &school,
+        &plan,
+        &all_metrics,
+        &summary,
+        &options,
+        &final_output_path,
+    ) {
+        Ok(()) => {
             println!("✓ Metrics exported to: {}", final_output_path.display());
             info!(
                 "Exported curriculum metrics to: {}",
@@ -134,9 +146,49 @@ fn export_csv(
             }
             Ok(())
         }
-        Err(e) => Err(format!(
-            "✗ Failed to export metrics to {}: {e}",
+        Err(ExportError::Io(e)) => Err(format!(
+            "✗ Could not write metrics file {}: {e}",
             final_output_path.display()
         )),
     }
 }
+
+/// Resolve where the metrics CSV is written, creating its directory
+///
+/// An explicit `output_file` wins; otherwise the file is named after the input
+/// inside `metrics_dir`.
+fn metrics_output_path(
+    input_file: &Path,
+    output_file: Option<&Path>,
+    metrics_dir: &str,
+) -> Result<PathBuf, String> {
+    let path = if let Some(output) = output_file {
+        // Ensure parent directory exists
+        if let Some(parent) = output.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                format!(
+                    "✗ Failed to create output directory {}: {e}",
+                    parent.display()
+                )
+            })?;
+        }
+        output.to_path_buf()
+    } else {
+        let metrics_path = PathBuf::from(metrics_dir);
+        std::fs::create_dir_all(&metrics_path).map_err(|e| {
+            format!(
+                "✗ Failed to create metrics directory {}: {e}",
+                metrics_path.display()
+            )
+        })?;
+
+        let filename = input_file
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("curriculum")
+            .to_string();
+        let output_filename = format!("{filename}_w_metrics.csv");
+        metrics_path.join(output_filename)
+    };
+    Ok(path)
+}
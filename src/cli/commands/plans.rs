@@ -0,0 +1,82 @@
+//! Plans command handler - list the plans defined in a curriculum CSV
+
+use nu_analytics::core::{models::School, planner::parse_curriculum_csv};
+use nu_analytics::error;
+use std::path::Path;
+
+/// List each plan in a curriculum file, one per line
+///
+/// # Arguments
+/// * `input_file` - Path to input CSV file
+pub fn run(input_file: &Path) {
+    match parse_curriculum_csv(input_file) {
+        Ok(school) => {
+            let lines = plan_summaries(&school);
+            if lines.is_empty() {
+                println!("No plans found in {}", input_file.display());
+            }
+            for line in lines {
+                println!("{line}");
+            }
+        }
+        Err(e) => {
+            error!("Failed to load curriculum {}: {e}", input_file.display());
+            eprintln!("✗ Failed to load {}: {e}", input_file.display());
+        }
+    }
+}
+
+/// Describe each plan as `name (degree): N courses, C credits`
+///
+/// Credits count cross-listed courses once, matching report totals.
+fn plan_summaries(school: &School) -> Vec<String> {
+    school
+        .plans
+        .iter()
+        .map(|plan| {
+            format!(
+                "{} ({}): {} courses, {:.1} credits",
+                plan.name,
+                plan.degree_id,
+                plan.courses.len(),
+                school.total_credits(plan)
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_analytics::core::models::{Course, Plan};
+
+    #[test]
+    fn test_plan_summaries_lists_every_plan() {
+        let mut school = School::new("Test University".to_string());
+        for (number, credits) in [("1800", 4.0), ("2500", 4.0), ("2510", 4.0)] {
+            school.add_course(Course::new(
+                format!("CS {number}"),
+                "CS".to_string(),
+                number.to_string(),
+                credits,
+            ));
+        }
+
+        let mut standard = Plan::new("Standard Track".to_string(), "BS CS".to_string());
+        standard.add_course("CS1800".to_string());
+        standard.add_course("CS2500".to_string());
+        standard.add_course("CS2510".to_string());
+        let mut minor = Plan::new("Minor".to_string(), "Minor CS".to_string());
+        minor.add_course("CS1800".to_string());
+        school.add_plan(standard);
+        school.add_plan(minor);
+
+        assert_eq!(
+            plan_summaries(&school),
+            vec![
+                "Standard Track (BS CS): 3 courses, 12.0 credits".to_string(),
+                "Minor (Minor CS): 1 courses, 4.0 credits".to_string(),
+            ]
+        );
+    }
+}
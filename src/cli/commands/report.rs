@@ -9,13 +9,16 @@
 //! the full report generation pipeline from an input CSV file.
 
 use crate::args::ReportFormatArg;
+#[cfg(feature = "pdf")]
+use nu_analytics::core::report::PdfReporter;
 use nu_analytics::core::{
-    metrics, metrics_export,
+    metrics::{self, MetricsSort},
+    metrics_export,
     models::{Degree, Plan, School, DAG},
     planner::parse_curriculum_csv,
     report::{
-        formats::ReportFormat, HtmlReporter, MarkdownReporter, PdfReporter, ReportContext,
-        ReportGenerator, SchedulerConfig, TermPlan, TermScheduler,
+        available_formats, formats::ReportFormat, CombinedReporter, EdgeListExporter, HtmlReporter,
+        MarkdownReporter, ReportContext, ReportGenerator, SchedulerConfig, TermPlan, TermScheduler,
     },
 };
 use nu_analytics::{error, info};
@@ -35,7 +38,11 @@ struct ReportData {
 }
 
 /// Load and prepare all data needed for report generation
-fn prepare_report_data(input_file: &Path, term_credits: Option<f32>) -> Result<ReportData, String> {
+fn prepare_report_data(
+    input_file: &Path,
+    term_credits: Option<f32>,
+    plan_scoped: bool,
+) -> Result<ReportData, String> {
     // Load curriculum
     let school = parse_curriculum_csv(input_file).map_err(|e| {
         error!("Failed to load curriculum {}: {e}", input_file.display());
@@ -47,18 +54,6 @@ fn prepare_report_data(input_file: &Path, term_credits: Option<f32>) -> Result<R
     // Build DAG
     let dag = school.build_dag();
 
-    // Compute metrics
-    let all_metrics = metrics::compute_all_metrics(&dag).map_err(|e| {
-        error!(
-            "Metrics computation failed for {}: {e}",
-            input_file.display()
-        );
-        format!(
-            "✗ Failed to compute metrics for {}: {e}",
-            input_file.display()
-        )
-    })?;
-
     // Get or create plan
     let plan = school.plans.first().cloned().unwrap_or_else(|| {
         let mut default_plan = Plan::new(
@@ -71,6 +66,25 @@ fn prepare_report_data(input_file: &Path, term_credits: Option<f32>) -> Result<R
         default_plan
     });
 
+    // Restrict the graph to the plan when metrics should be plan-scoped
+    let dag = if plan_scoped {
+        school.build_plan_dag(&plan)
+    } else {
+        dag
+    };
+
+    // Compute metrics
+    let all_metrics = metrics::compute_all_metrics(&dag).map_err(|e| {
+        error!(
+            "Metrics computation failed for {}: {e}",
+            input_file.display()
+        );
+        format!(
+            "✗ Failed to compute metrics for {}: {e}",
+            input_file.display()
+        )
+    })?;
+
     // Get degree for context
     let degree = school.degrees.first();
 
@@ -106,6 +120,8 @@ fn write_report(
     data: &ReportData,
     format: ReportFormat,
     output_path: &Path,
+    sort: MetricsSort,
+    combined: bool,
     pdf_converter: Option<&str>,
 ) -> Result<(), String> {
     let degree = data.school.degrees.first();
@@ -117,7 +133,15 @@ fn write_report(
         &data.summary,
         &data.dag,
         &data.term_plan,
-    );
+    )
+    .with_metrics_sort(sort);
+
+    if combined {
+        let reporter = CombinedReporter::new(format).map_err(|e| format!("✗ {e}"))?;
+        return reporter
+            .generate(&ctx, output_path)
+            .map_err(|e| format!("✗ Failed to generate combined report: {e}"));
+    }
 
     match format {
         ReportFormat::Markdown => {
@@ -132,12 +156,23 @@ fn write_report(
                 .generate(&ctx, output_path)
                 .map_err(|e| format!("✗ Failed to generate HTML report: {e}"))?;
         }
+        #[cfg(feature = "pdf")]
         ReportFormat::Pdf => {
             let reporter = pdf_converter.map_or_else(PdfReporter::new, PdfReporter::with_converter);
             reporter
                 .generate(&ctx, output_path)
                 .map_err(|e| format!("✗ Failed to generate PDF report: {e}"))?;
         }
+        #[cfg(not(feature = "pdf"))]
+        ReportFormat::Pdf => {
+            let _ = pdf_converter;
+            return Err(unavailable_message(format));
+        }
+        ReportFormat::Edges => {
+            EdgeListExporter::new()
+                .generate(&ctx, output_path)
+                .map_err(|e| format!("✗ Failed to write edge list: {e}"))?;
+        }
     }
 
     Ok(())
@@ -177,9 +212,35 @@ const fn to_report_format(fmt: ReportFormatArg) -> ReportFormat {
         ReportFormatArg::Html => ReportFormat::Html,
         ReportFormatArg::Md => ReportFormat::Markdown,
         ReportFormatArg::Pdf => ReportFormat::Pdf,
+        ReportFormatArg::Edges => ReportFormat::Edges,
     }
 }
 
+/// Check that the requested report format is compiled into this build
+///
+/// # Errors
+/// Returns a message naming the missing feature and the formats that are available.
+pub fn ensure_available(format: ReportFormatArg) -> Result<(), String> {
+    let report_format = to_report_format(format);
+    if report_format.is_available() {
+        Ok(())
+    } else {
+        Err(unavailable_message(report_format))
+    }
+}
+
+/// Explain that a format is not compiled in and list the alternatives
+fn unavailable_message(format: ReportFormat) -> String {
+    let available: Vec<String> = available_formats()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    format!(
+        "✗ {format} reports are not available in this build (rebuild with `--features {format}`); available formats: {}",
+        available.join(", ")
+    )
+}
+
 /// Generate a report file from an input curriculum CSV
 ///
 /// # Arguments
@@ -188,23 +249,31 @@ const fn to_report_format(fmt: ReportFormatArg) -> ReportFormat {
 /// * `format` - Report format (Html, Md, Pdf)
 /// * `reports_dir` - Directory for output when `output_file` is None
 /// * `term_credits` - Optional target credits per term
+/// * `sort` - Order of the metrics table rows
+/// * `plan_scoped` - Compute metrics over the plan's courses only
+/// * `combined` - Embed the metrics and schedule as JSON (html and md only)
 /// * `pdf_converter` - Optional custom PDF converter command
 ///
 /// # Returns
 /// Path to the generated report file
+#[allow(clippy::too_many_arguments)]
 pub fn generate_report_file(
     input_file: &Path,
     output_file: Option<&Path>,
     format: ReportFormatArg,
     reports_dir: &str,
     term_credits: Option<f32>,
+    sort: MetricsSort,
+    plan_scoped: bool,
+    combined: bool,
     pdf_converter: Option<&str>,
 ) -> Result<PathBuf, String> {
     // Convert to internal format type
+    ensure_available(format)?;
     let report_format = to_report_format(format);
 
     // Prepare report data
-    let data = prepare_report_data(input_file, term_credits)?;
+    let data = prepare_report_data(input_file, term_credits, plan_scoped)?;
 
     // Determine output path
     let output_path: PathBuf = if let Some(explicit_path) = output_file {
@@ -238,7 +307,14 @@ pub fn generate_report_file(
     };
 
     // Write the report
-    write_report(&data, report_format, &output_path, pdf_converter)?;
+    write_report(
+        &data,
+        report_format,
+        &output_path,
+        sort,
+        combined,
+        pdf_converter,
+    )?;
 
     info!("Report exported to: {}", output_path.display());
     print_summary(&data);
@@ -0,0 +1,73 @@
+//! Search command handler - find courses by partial name or code
+
+use nu_analytics::core::{models::School, planner::parse_curriculum_csv};
+use nu_analytics::error;
+use std::path::Path;
+
+/// Print the courses in a curriculum file that match `query`
+///
+/// # Arguments
+/// * `input_file` - Path to input CSV file
+/// * `query` - Partial course name or code (case-insensitive)
+pub fn run(input_file: &Path, query: &str) {
+    match parse_curriculum_csv(input_file) {
+        Ok(school) => {
+            let lines = search_results(&school, query);
+            if lines.is_empty() {
+                println!("No courses match '{query}' in {}", input_file.display());
+            }
+            for line in lines {
+                println!("{line}");
+            }
+        }
+        Err(e) => {
+            error!("Failed to load curriculum {}: {e}", input_file.display());
+            eprintln!("✗ Failed to load {}: {e}", input_file.display());
+        }
+    }
+}
+
+/// Describe each matching course as `KEY  Name (C credits)`
+fn search_results(school: &School, query: &str) -> Vec<String> {
+    school
+        .search(query)
+        .into_iter()
+        .map(|course| {
+            format!(
+                "{}  {} ({} credits)",
+                course.key(),
+                course.name,
+                course.credit_hours_for_export()
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_analytics::core::models::Course;
+
+    #[test]
+    fn test_search_results_lists_matches() {
+        let mut school = School::new("Test University".to_string());
+        school.add_course(Course::new(
+            "Discrete Structures".to_string(),
+            "CS".to_string(),
+            "1800".to_string(),
+            4.0,
+        ));
+        school.add_course(Course::new(
+            "Calculus I".to_string(),
+            "MATH".to_string(),
+            "1341".to_string(),
+            4.0,
+        ));
+
+        assert_eq!(
+            search_results(&school, "discrete"),
+            vec!["CS1800  Discrete Structures (4 credits)".to_string()]
+        );
+        assert!(search_results(&school, "chemistry").is_empty());
+    }
+}
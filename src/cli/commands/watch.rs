@@ -0,0 +1,241 @@
+//! Watch mode - regenerate reports when input files change
+//!
+//! Watches the directories containing the input CSV files and re-runs report
+//! generation for any input that is created or modified. Editors often emit
+//! several events per save (or replace the file outright), so events are
+//! coalesced over a short settle window before regenerating.
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use nu_analytics::logger::flush_log_file;
+use nu_analytics::{debug, info, warn};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+/// How long to wait for further events (and between retries) before acting
+const SETTLE_DELAY: Duration = Duration::from_millis(200);
+
+/// Attempts per change before giving up (the file may still be mid-write)
+const MAX_ATTEMPTS: usize = 3;
+
+/// An input file being watched
+struct WatchedFile {
+    /// Path as given on the command line (passed back to the regenerate callback)
+    input: PathBuf,
+    /// Canonical path used to match incoming events
+    resolved: PathBuf,
+}
+
+impl WatchedFile {
+    fn new(input: &Path) -> Self {
+        Self {
+            input: input.to_path_buf(),
+            resolved: input.canonicalize().unwrap_or_else(|_| input.to_path_buf()),
+        }
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        path == self.resolved || path.canonicalize().is_ok_and(|p| p == self.resolved)
+    }
+}
+
+/// Watch input files and regenerate whenever one of them changes
+///
+/// Blocks until the watcher shuts down (in practice, until the process is
+/// interrupted).
+///
+/// # Arguments
+/// * `input_files` - Curriculum CSV files to watch
+/// * `regenerate` - Called with the changed input path; returns the written report path
+///
+/// # Errors
+/// Returns an error if the file watcher cannot be created or a directory cannot be watched.
+pub fn watch_files<F>(input_files: &[PathBuf], regenerate: F) -> Result<(), notify::Error>
+where
+    F: FnMut(&Path) -> Result<PathBuf, String>,
+{
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+
+    let files: Vec<WatchedFile> = input_files.iter().map(|p| WatchedFile::new(p)).collect();
+    for file in &files {
+        // Watch the parent directory so files replaced on save are still seen
+        let dir = file.resolved.parent().unwrap_or_else(|| Path::new("."));
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    }
+
+    println!(
+        "✓ Watching {} file(s) for changes (Ctrl+C to stop)",
+        files.len()
+    );
+    run_watch_loop(&rx, &files, regenerate, SETTLE_DELAY);
+    Ok(())
+}
+
+/// Process watcher events until the channel closes
+///
+/// # Returns
+/// The number of successful regenerations
+fn run_watch_loop<F>(
+    rx: &Receiver<notify::Result<Event>>,
+    watched: &[WatchedFile],
+    mut regenerate: F,
+    settle: Duration,
+) -> usize
+where
+    F: FnMut(&Path) -> Result<PathBuf, String>,
+{
+    let mut regenerations = 0;
+
+    while let Ok(first) = rx.recv() {
+        let mut changed: Vec<&Path> = Vec::new();
+        collect_changed(first, watched, &mut changed);
+        while let Ok(next) = rx.recv_timeout(settle) {
+            collect_changed(next, watched, &mut changed);
+        }
+
+        for input in changed {
+            if regenerate_with_retry(input, &mut regenerate, settle) {
+                regenerations += 1;
+            }
+        }
+        // Watching ends with Ctrl+C, so write out buffered log lines as we go
+        flush_log_file();
+    }
+
+    regenerations
+}
+
+/// Add the inputs touched by a create/modify event to `changed`
+fn collect_changed<'a>(
+    event: notify::Result<Event>,
+    watched: &'a [WatchedFile],
+    changed: &mut Vec<&'a Path>,
+) {
+    let event = match event {
+        Ok(event) => event,
+        Err(e) => {
+            warn!("File watch error: {e}");
+            return;
+        }
+    };
+    if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+        return;
+    }
+
+    for path in &event.paths {
+        for file in watched.iter().filter(|f| f.matches(path)) {
+            if !changed.contains(&file.input.as_path()) {
+                changed.push(&file.input);
+            }
+        }
+    }
+}
+
+/// Regenerate for one input, retrying while the file may still be mid-write
+///
+/// # Returns
+/// `true` if regeneration eventually succeeded
+fn regenerate_with_retry<F>(input: &Path, regenerate: &mut F, settle: Duration) -> bool
+where
+    F: FnMut(&Path) -> Result<PathBuf, String>,
+{
+    for attempt in 1..=MAX_ATTEMPTS {
+        match regenerate(input) {
+            Ok(path) => {
+                info!(
+                    "Regenerated report for {}: {}",
+                    input.display(),
+                    path.display()
+                );
+                println!("✓ Report regenerated: {}", path.display());
+                return true;
+            }
+            Err(e) if attempt < MAX_ATTEMPTS => {
+                debug!(
+                    "Regeneration attempt {attempt} for {} failed ({e}); retrying",
+                    input.display()
+                );
+                std::thread::sleep(settle);
+            }
+            Err(e) => {
+                warn!(
+                    "Giving up on {} after {MAX_ATTEMPTS} attempts: {e}",
+                    input.display()
+                );
+                eprintln!("{e}");
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, ModifyKind};
+
+    fn modify_event(path: &Path) -> Event {
+        Event::new(EventKind::Modify(ModifyKind::Any)).add_path(path.to_path_buf())
+    }
+
+    #[test]
+    fn test_change_event_triggers_one_regeneration() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("plan.csv");
+        std::fs::write(&input, "Curriculum,Test\n").unwrap();
+        let watched = vec![WatchedFile::new(&input)];
+
+        let (tx, rx) = mpsc::channel();
+        // A save typically produces a burst of events; access events are ignored
+        tx.send(Ok(modify_event(&input))).unwrap();
+        tx.send(Ok(modify_event(&input))).unwrap();
+        tx.send(Ok(
+            Event::new(EventKind::Access(AccessKind::Any)).add_path(input.clone())
+        ))
+        .unwrap();
+        drop(tx);
+
+        let mut calls = Vec::new();
+        let count = run_watch_loop(
+            &rx,
+            &watched,
+            |path| {
+                calls.push(path.to_path_buf());
+                Ok(path.with_extension("html"))
+            },
+            Duration::from_millis(10),
+        );
+
+        assert_eq!(count, 1);
+        assert_eq!(calls, vec![input]);
+    }
+
+    #[test]
+    fn test_regeneration_retries_while_mid_write() {
+        let input = PathBuf::from("mid_write.csv");
+        let watched = vec![WatchedFile::new(&input)];
+
+        let (tx, rx) = mpsc::channel();
+        tx.send(Ok(modify_event(&input))).unwrap();
+        drop(tx);
+
+        let mut attempts = 0;
+        let count = run_watch_loop(
+            &rx,
+            &watched,
+            |path| {
+                attempts += 1;
+                if attempts == 1 {
+                    Err("✗ Failed to parse: unexpected end of file".to_string())
+                } else {
+                    Ok(path.with_extension("html"))
+                }
+            },
+            Duration::from_millis(1),
+        );
+
+        assert_eq!(count, 1);
+        assert_eq!(attempts, 2);
+    }
+}
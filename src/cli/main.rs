@@ -9,7 +9,11 @@ mod commands;
 use args::{Cli, Command, ReportFormatArg};
 use clap::Parser;
 use nu_analytics::config::Config;
-use nu_analytics::logger::{enable_debug, enable_verbose, init_file_logging, set_level, Level};
+use nu_analytics::core::metrics::MetricsSort;
+use nu_analytics::logger::{
+    enable_debug, enable_verbose, flush_log_file, global, init_file_logging, init_from_env,
+    set_level, Level, DEFAULT_LEVEL_ENV_VAR,
+};
 use nu_analytics::{info, warn};
 use std::path::{Path, PathBuf};
 
@@ -18,6 +22,8 @@ use std::path::{Path, PathBuf};
 /// Parses command-line arguments, loads configuration, sets up logging,
 /// and dispatches to the appropriate subcommand handler.
 fn main() {
+    // Apply NU_LOG first so argument parsing and config loading log at that level
+    let env_level = init_from_env(DEFAULT_LEVEL_ENV_VAR).then(|| global().level());
     let args = Cli::parse();
 
     // Load configuration once at startup and apply CLI overrides to it
@@ -25,16 +31,27 @@ fn main() {
     let defaults = Config::from_defaults();
     config.apply_overrides(&args.to_config_overrides());
 
-    // Determine effective runtime log level: CLI flag overrides config; otherwise use config logging.level; fallback warn
+    // Report configuration problems up front rather than silently falling back later
+    if let Err(problems) = config.validate() {
+        for problem in problems {
+            eprintln!("✗ Config: {problem}");
+        }
+    }
+
+    // Determine effective runtime log level: CLI flag overrides NU_LOG, which overrides
+    // config logging.level; fallback warn
     let effective_level = args
         .log_level
         .map(std::convert::Into::into)
-        .or_else(|| parse_level(&config.logging.level))
+        .or(env_level)
+        .or_else(|| config.logging.level.parse::<Level>().ok())
         .unwrap_or(Level::Warn);
 
     let mut level = effective_level;
-    if args.debug_flag || level == Level::Debug {
+    if args.debug_flag && level != Level::Trace {
         level = Level::Debug;
+    }
+    if matches!(level, Level::Debug | Level::Trace) {
         enable_debug();
     }
 
@@ -78,30 +95,57 @@ fn main() {
             report_dir,
             metrics_dir,
             term_credits,
+            sort,
+            plan_scoped,
+            combined,
             no_csv,
             no_report,
+            #[cfg(feature = "watch")]
+            watch,
         } => {
             let opts = PlannerOptions {
                 input_files: &input_files,
                 output: &output,
-                report_format,
+                report_format: report_format
+                    .or_else(|| ReportFormatArg::from_extension(&config.report.format)),
                 pdf_converter: pdf_converter.as_deref(),
                 report_dir,
                 metrics_dir,
                 term_credits,
+                sort: sort.map(MetricsSort::from),
+                plan_scoped,
+                combined,
                 no_csv,
                 no_report,
                 verbose,
             };
             run_planner(&config, &opts);
+            #[cfg(feature = "watch")]
+            if watch {
+                watch_planner(&config, &opts);
+            }
+        }
+        Command::Plans { input_file } => commands::plans::run(&input_file),
+        Command::Search { input_file, query } => commands::search::run(&input_file, &query),
+        Command::InitTemplate { output, force } => commands::init_template::run(&output, force),
+        #[cfg(feature = "server")]
+        Command::Serve { addr } => {
+            println!("✓ Listening on http://{addr}/analyze");
+            if let Err(e) = nu_analytics::core::server::serve(&addr) {
+                eprintln!("✗ Server failed on {addr}: {e}");
+            }
         }
     }
+
+    // File logging is buffered; write out whatever is left before exiting
+    flush_log_file();
 }
 
 /// Options for the planner command
 ///
 /// Collects all planner-related options into a single struct to avoid
 /// passing many individual arguments to functions.
+#[allow(clippy::struct_excessive_bools)]
 struct PlannerOptions<'a> {
     /// Input CSV files to process
     input_files: &'a [PathBuf],
@@ -117,6 +161,12 @@ struct PlannerOptions<'a> {
     metrics_dir: Option<PathBuf>,
     /// Target credits per term for scheduling
     term_credits: Option<f32>,
+    /// Row order for the metrics table and CSV
+    sort: Option<MetricsSort>,
+    /// Compute metrics over the plan's courses only
+    plan_scoped: bool,
+    /// Embed metrics in the report instead of writing a CSV
+    combined: bool,
     /// Skip CSV metrics export
     no_csv: bool,
     /// Skip report generation
@@ -137,10 +187,17 @@ fn run_planner(config: &Config, opts: &PlannerOptions<'_>) {
         || config.paths.metrics_dir.clone(),
         |p| p.to_string_lossy().to_string(),
     );
-    let effective_reports_dir = opts.report_dir.as_ref().map_or_else(
-        || config.paths.reports_dir.clone(),
-        |p| p.to_string_lossy().to_string(),
-    );
+    let effective_reports_dir = effective_reports_dir(config, opts);
+
+    // Reject formats that are not compiled in before doing any work
+    if let Err(e) = opts
+        .report_format
+        .filter(|_| !opts.no_report)
+        .map_or(Ok(()), commands::report::ensure_available)
+    {
+        eprintln!("{e}");
+        return;
+    }
 
     // Validate output count matches input count if provided
     if !opts.output.is_empty() && opts.output.len() != opts.input_files.len() {
@@ -164,6 +221,50 @@ fn run_planner(config: &Config, opts: &PlannerOptions<'_>) {
     }
 }
 
+/// Reports directory: the `--report-dir` override, or the configured directory
+fn effective_reports_dir(config: &Config, opts: &PlannerOptions<'_>) -> String {
+    opts.report_dir.as_ref().map_or_else(
+        || config.paths.reports_dir.clone(),
+        |p| p.to_string_lossy().to_string(),
+    )
+}
+
+/// Watches the planner inputs and regenerates their reports on change
+///
+/// Only reports are regenerated; CSV metrics are left as written by the
+/// initial run.
+#[cfg(feature = "watch")]
+fn watch_planner(config: &Config, opts: &PlannerOptions<'_>) {
+    let reports_dir = effective_reports_dir(config, opts);
+
+    let result = commands::watch::watch_files(opts.input_files, |input| {
+        let explicit_output = opts
+            .input_files
+            .iter()
+            .position(|p| p == input)
+            .and_then(|idx| opts.output.get(idx));
+        let (_, generate_report, output_path, format) = determine_output_type(
+            explicit_output,
+            opts.report_format,
+            opts.no_csv,
+            opts.no_report,
+        );
+        match format {
+            Some(fmt) if generate_report => {
+                render_report(input, output_path, fmt, &reports_dir, opts)
+            }
+            _ => Err(format!(
+                "✗ No report is generated for {}; nothing to regenerate",
+                input.display()
+            )),
+        }
+    });
+
+    if let Err(e) = result {
+        eprintln!("✗ Failed to watch input files: {e}");
+    }
+}
+
 /// Processes a single input file, generating CSV and/or report output
 fn process_single_input(
     input_file: &Path,
@@ -172,11 +273,12 @@ fn process_single_input(
     metrics_dir: &str,
     reports_dir: &str,
 ) {
-    // Determine what to generate based on -o extension or flags
+    // Determine what to generate based on -o extension or flags; a combined
+    // report carries the metrics itself, so no CSV is written alongside it
     let (generate_csv, generate_report, output_path, effective_format) = determine_output_type(
         explicit_output,
         opts.report_format,
-        opts.no_csv,
+        opts.no_csv || opts.combined,
         opts.no_report,
     );
 
@@ -187,7 +289,14 @@ fn process_single_input(
                 .and_then(|e| e.to_str())
                 .is_some_and(|e| e.eq_ignore_ascii_case("csv"))
         });
-        commands::planner::run_single(input_file, csv_output.as_deref(), metrics_dir, opts.verbose);
+        commands::planner::run_single(
+            input_file,
+            csv_output.as_deref(),
+            metrics_dir,
+            opts.sort,
+            opts.plan_scoped,
+            opts.verbose,
+        );
     }
 
     // Generate report
@@ -206,34 +315,49 @@ fn generate_report_output(
     reports_dir: &str,
     opts: &PlannerOptions<'_>,
 ) {
+    match render_report(input_file, output_path, format, reports_dir, opts) {
+        Ok(path) => {
+            println!("✓ Report generated: {}", path.display());
+        }
+        Err(e) => {
+            eprintln!("{e}");
+        }
+    }
+}
+
+/// Renders the report for one input, ignoring a `.csv` explicit output path
+/// unless the report itself is a CSV edge list
+fn render_report(
+    input_file: &Path,
+    output_path: Option<PathBuf>,
+    format: ReportFormatArg,
+    reports_dir: &str,
+    opts: &PlannerOptions<'_>,
+) -> Result<PathBuf, String> {
     let report_output = output_path.filter(|p| {
         p.extension()
             .and_then(|e| e.to_str())
-            .is_some_and(|e| !e.eq_ignore_ascii_case("csv"))
+            .is_some_and(|e| format == ReportFormatArg::Edges || !e.eq_ignore_ascii_case("csv"))
     });
 
-    match commands::report::generate_report_file(
+    commands::report::generate_report_file(
         input_file,
         report_output.as_deref(),
         format,
         reports_dir,
         opts.term_credits,
+        opts.sort.unwrap_or_default(),
+        opts.plan_scoped,
+        opts.combined,
         opts.pdf_converter,
-    ) {
-        Ok(path) => {
-            println!("✓ Report generated: {}", path.display());
-        }
-        Err(e) => {
-            eprintln!("{e}");
-        }
-    }
+    )
 }
 
 /// Determines output type and format based on explicit path or flags
 ///
 /// Logic:
 /// - If no explicit output: use configured directories, respect `--no-csv`/`--no-report` flags
-/// - If explicit output with `.csv` extension: CSV only
+/// - If explicit output with `.csv` extension: CSV only (edge-list report with `--report-format edges`)
 /// - If explicit output with report extension (`.html`, `.md`, `.pdf`): report only
 /// - Unknown extension: treat as report with default HTML format
 ///
@@ -261,7 +385,10 @@ fn determine_output_type(
             // Explicit output path provided - infer type from extension
             let ext = out_path.extension().and_then(|e| e.to_str()).unwrap_or("");
 
-            if ext.eq_ignore_ascii_case("csv") {
+            if ext.eq_ignore_ascii_case("csv") && report_format == Some(ReportFormatArg::Edges) {
+                // Edge list is a report written as CSV
+                (false, true, Some(out_path.clone()), report_format)
+            } else if ext.eq_ignore_ascii_case("csv") {
                 // CSV output only
                 (true, false, Some(out_path.clone()), None)
             } else if let Some(fmt) = ReportFormatArg::from_extension(ext) {
@@ -310,19 +437,3 @@ fn handle_report_format_conflict(
         },
     )
 }
-
-/// Parses a log level string into a `Level` enum
-///
-/// Supported values (case-insensitive): "error", "warn", "info", "debug"
-///
-/// # Returns
-/// `Some(Level)` if the string is valid, `None` otherwise
-fn parse_level(val: &str) -> Option<Level> {
-    match val.to_ascii_lowercase().as_str() {
-        "error" => Some(Level::Error),
-        "warn" => Some(Level::Warn),
-        "info" => Some(Level::Info),
-        "debug" => Some(Level::Debug),
-        _ => None,
-    }
-}
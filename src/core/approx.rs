@@ -0,0 +1,48 @@
+//! Tolerant floating-point comparison
+//!
+//! Credit hours and metric scores are summed from `f32` values parsed out of
+//! CSVs, so exact equality breaks on harmless rounding (e.g. three 0.1-credit
+//! seminars). Compare through these helpers instead so the tolerance policy
+//! lives in one place.
+
+/// Largest difference at which two credit totals are considered equal
+pub const CREDIT_TOLERANCE: f64 = 1e-4;
+
+/// Whether `a` and `b` differ by at most `tol`
+///
+/// Takes `f64` so both `f32` credits (via `f64::from`) and `f64` ratios can be
+/// compared; the boundary is inclusive.
+#[must_use]
+pub fn approx_eq(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol
+}
+
+/// Whether two credit-hour values are equal within [`CREDIT_TOLERANCE`]
+#[must_use]
+pub fn credits_eq(a: f32, b: f32) -> bool {
+    approx_eq(f64::from(a), f64::from(b), CREDIT_TOLERANCE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approx_eq_tolerance_boundary() {
+        assert!(approx_eq(1.0, 1.0, 0.0));
+        assert!(approx_eq(1.0, 1.5, 0.5));
+        assert!(approx_eq(1.5, 1.0, 0.5));
+        assert!(!approx_eq(1.0, 1.500_001, 0.5));
+        assert!(!approx_eq(1.0, 0.499_999, 0.5));
+    }
+
+    #[test]
+    fn test_credits_eq_absorbs_summation_error() {
+        // Not exactly 1.0 once rounded through f32
+        let total: f32 = [0.1_f32; 10].iter().sum();
+        assert!(credits_eq(total, 1.0));
+        assert!(credits_eq(3.0, 3.000_05));
+        assert!(!credits_eq(3.0, 3.001));
+        assert!(!credits_eq(120.0, 119.5));
+    }
+}
@@ -0,0 +1,137 @@
+//! Binary cache of parsed schools
+//!
+//! Reparsing a curriculum CSV is cheap once but adds up when many curricula are
+//! loaded at start-up. [`SchoolCache`] stores each parsed [`School`] as
+//! `<hash>.bin`, where the hash covers the CSV contents, the parse options, and
+//! the crate version, so an entry is reused only while the source is unchanged.
+
+use crate::core::hash::Fnv1a;
+use crate::core::models::School;
+use crate::core::planner::{parse_curriculum_reader, ParseOptions};
+use std::error::Error;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// On-disk cache of parsed schools keyed by source hash
+#[derive(Debug, Clone)]
+pub struct SchoolCache {
+    /// Directory holding the `<hash>.bin` entries
+    dir: PathBuf,
+}
+
+impl SchoolCache {
+    /// Create a cache rooted at `dir` (created on first write)
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Path of the cache entry for the given CSV contents and options
+    #[must_use]
+    pub fn entry_path(&self, contents: &[u8], options: &ParseOptions) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}.bin", cache_key(contents, options)))
+    }
+
+    /// Load a school from the cache, parsing and caching the CSV on a miss
+    ///
+    /// Unreadable or stale entries are treated as misses; failing to write a new
+    /// entry is logged and does not fail the load.
+    ///
+    /// # Arguments
+    /// * `csv_path` - Path to the curriculum CSV
+    /// * `options` - Parse options (part of the cache key)
+    ///
+    /// # Errors
+    /// Returns an error if the CSV cannot be read or parsed
+    pub fn load_or_parse(
+        &self,
+        csv_path: &Path,
+        options: &ParseOptions,
+    ) -> Result<School, Box<dyn Error>> {
+        let contents = fs::read(csv_path)?;
+        let entry = self.entry_path(&contents, options);
+
+        if let Ok(bytes) = fs::read(&entry) {
+            match School::from_bytes(&bytes) {
+                Ok(school) => {
+                    crate::debug!(
+                        "Loaded {} from cache {}",
+                        csv_path.display(),
+                        entry.display()
+                    );
+                    return Ok(school);
+                }
+                Err(e) => crate::warn!("Ignoring unreadable cache entry {}: {e}", entry.display()),
+            }
+        }
+
+        let school = parse_curriculum_reader(contents.as_slice(), options)?;
+        if let Err(e) = self.store(&entry, &school) {
+            crate::warn!("Failed to write cache entry {}: {e}", entry.display());
+        }
+        Ok(school)
+    }
+
+    /// Write an encoded school to `entry`
+    fn store(&self, entry: &Path, school: &School) -> Result<(), Box<dyn Error>> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(entry, school.to_bytes()?)?;
+        Ok(())
+    }
+}
+
+/// Stable hash of the CSV contents, parse options, and crate version
+fn cache_key(contents: &[u8], options: &ParseOptions) -> u64 {
+    let mut hasher = Fnv1a::default();
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    options.hash(&mut hasher);
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::planner::parse_curriculum_csv;
+
+    const SAMPLE: &str = "samples/plans/Colostate_CSDegree.csv";
+
+    #[test]
+    fn test_school_binary_round_trip() {
+        let school = parse_curriculum_csv(SAMPLE).unwrap();
+        let bytes = school.to_bytes().unwrap();
+        let decoded = School::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded.name, school.name);
+        assert_eq!(decoded.degrees, school.degrees);
+        assert_eq!(decoded.plans, school.plans);
+        assert_eq!(decoded.courses().len(), school.courses().len());
+        for (key, course) in school.courses_with_keys() {
+            assert_eq!(decoded.get_course(key), Some(course));
+        }
+    }
+
+    #[test]
+    fn test_cache_reuses_entry_until_source_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let csv = dir.path().join("plan.csv");
+        fs::copy(SAMPLE, &csv).unwrap();
+        let cache = SchoolCache::new(dir.path().join("cache"));
+        let options = ParseOptions::default();
+
+        let first = cache.load_or_parse(&csv, &options).unwrap();
+        let entry = cache.entry_path(&fs::read(&csv).unwrap(), &options);
+        assert!(entry.exists());
+
+        let cached = cache.load_or_parse(&csv, &options).unwrap();
+        assert_eq!(cached.plans, first.plans);
+
+        // Different options or contents map to a different entry
+        let upper = ParseOptions::default().with_normalize_prefix_case(true);
+        assert_ne!(cache.entry_path(&fs::read(&csv).unwrap(), &upper), entry);
+        fs::write(&csv, fs::read_to_string(SAMPLE).unwrap() + "\n").unwrap();
+        assert_ne!(cache.entry_path(&fs::read(&csv).unwrap(), &options), entry);
+    }
+}
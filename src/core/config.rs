@@ -1,9 +1,13 @@
 //! Configuration module for `NuAnalytics`
 
+use crate::core::report::ReportFormat;
+use crate::logger::Level;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Default CLI configuration loaded based on build profile.
 /// Uses release defaults in release mode, debug defaults in debug mode.
@@ -19,10 +23,39 @@ const CONFIG_FILE_NAME: &str = "config.toml";
 #[cfg(debug_assertions)]
 const CONFIG_FILE_NAME: &str = "dconfig.toml";
 
+/// Optional deployer-provided defaults file in the `$NU_ANALYTICS` directory.
+/// When present it takes precedence over the compiled-in defaults.
+const DEFAULTS_FILE_NAME: &str = "defaults.toml";
+
+#[cfg(not(debug_assertions))]
+const AUDIT_FILE_NAME: &str = "config.audit.log";
+
+#[cfg(debug_assertions)]
+const AUDIT_FILE_NAME: &str = "dconfig.audit.log";
+
+/// Every configuration key accepted by [`Config::get`], in display order
+pub const CONFIG_KEYS: [&str; 9] = [
+    "level",
+    "file",
+    "verbose",
+    "audit",
+    "token",
+    "endpoint",
+    "metrics_dir",
+    "reports_dir",
+    "report_format",
+];
+
+/// Keys whose values are written to the audit log as `***`
+const SECRET_KEYS: [&str; 1] = ["token"];
+
+/// Value recorded in the audit log in place of a secret
+const REDACTED: &str = "***";
+
 /// Logging configuration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LoggingConfig {
-    /// Log level (error, warn, info, debug)
+    /// Log level (off, error, warn, info, debug, trace)
     #[serde(default)]
     pub level: String,
     /// Log file path
@@ -31,6 +64,9 @@ pub struct LoggingConfig {
     /// Enable verbose output
     #[serde(default)]
     pub verbose: bool,
+    /// Append an audit line for every config change
+    #[serde(default)]
+    pub audit: bool,
 }
 
 /// Database configuration
@@ -55,6 +91,14 @@ pub struct PathsConfig {
     pub reports_dir: String,
 }
 
+/// Report configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReportConfig {
+    /// Default report format when none is given on the command line (html, md, pdf, edges)
+    #[serde(default)]
+    pub format: String,
+}
+
 /// Main configuration structure
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Config {
@@ -66,6 +110,45 @@ pub struct Config {
     /// Path settings
     #[serde(default)]
     pub paths: PathsConfig,
+    /// Report settings
+    #[serde(default)]
+    pub report: ReportConfig,
+    /// Audit entries recorded since the last [`append_audit_log()`](Config::append_audit_log)
+    #[serde(skip)]
+    pending_audit: Vec<AuditEntry>,
+}
+
+/// A single recorded configuration change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    /// Seconds since the Unix epoch when the change was made
+    pub timestamp: u64,
+    /// Operation that made the change (`set`, `unset`, or `reset`)
+    pub operation: String,
+    /// Configuration key that changed (`*` for a full reset)
+    pub key: String,
+    /// Value before the change
+    pub old: Option<String>,
+    /// Value after the change
+    pub new: Option<String>,
+}
+
+impl fmt::Display for AuditEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let old = self
+            .old
+            .as_deref()
+            .map_or_else(|| "-".to_string(), |v| format!("\"{v}\""));
+        let new = self
+            .new
+            .as_deref()
+            .map_or_else(|| "-".to_string(), |v| format!("\"{v}\""));
+        write!(
+            f,
+            "{} {} {}: {old} -> {new}",
+            self.timestamp, self.operation, self.key
+        )
+    }
 }
 
 /// Optional CLI overrides for configuration values
@@ -161,6 +244,12 @@ impl Config {
             changed = true;
         }
 
+        // Merge report fields
+        if self.report.format.is_empty() && !defaults.report.format.is_empty() {
+            self.report.format.clone_from(&defaults.report.format);
+            changed = true;
+        }
+
         changed
     }
 
@@ -225,6 +314,14 @@ impl Config {
         Self::get_nuanalytics_dir().join(CONFIG_FILE_NAME)
     }
 
+    /// Get the path to the config audit log, stored next to the config file
+    ///
+    /// Returns `$NU_ANALYTICS/config.audit.log` (`dconfig.audit.log` in debug builds).
+    #[must_use]
+    pub fn get_audit_file_path() -> PathBuf {
+        Self::get_nuanalytics_dir().join(AUDIT_FILE_NAME)
+    }
+
     /// Expand `$NU_ANALYTICS` variable in a string
     ///
     /// Replaces occurrences of `$NU_ANALYTICS` with the actual nuanalytics
@@ -291,12 +388,11 @@ impl Config {
         Ok(config)
     }
 
-    /// Load configuration from embedded defaults
+    /// Load the default configuration
     ///
-    /// Loads the compiled-in default configuration that is bundled with the binary.
-    /// The defaults differ between debug and release builds:
-    /// - Debug: Uses `DefaultCLIConfigDebug.toml`
-    /// - Release: Uses `DefaultCLIConfigRelease.toml`
+    /// Uses `$NU_ANALYTICS/defaults.toml` when it exists, so deployers can ship
+    /// organization-specific defaults without rebuilding; otherwise falls back to the
+    /// compiled-in defaults (see [`from_defaults_in()`](Config::from_defaults_in)).
     ///
     /// # Returns
     /// A `Config` instance with all values set to their defaults.
@@ -312,6 +408,49 @@ impl Config {
     /// ```
     #[must_use]
     pub fn from_defaults() -> Self {
+        Self::from_defaults_in(&Self::get_nuanalytics_dir())
+    }
+
+    /// Load the default configuration, preferring a `defaults.toml` in `dir`
+    ///
+    /// Fields missing from the external file are filled from the compiled-in defaults.
+    /// An unreadable or invalid external file is reported and ignored.
+    ///
+    /// # Panics
+    /// Panics if the embedded default configuration is invalid TOML or cannot be parsed.
+    #[must_use]
+    pub fn from_defaults_in(dir: &Path) -> Self {
+        let compiled = Self::compiled_defaults();
+        let defaults_file = dir.join(DEFAULTS_FILE_NAME);
+        if !defaults_file.exists() {
+            return compiled;
+        }
+
+        match fs::read_to_string(&defaults_file)
+            .map_err(|e| e.to_string())
+            .and_then(|content| Self::from_toml(&content).map_err(|e| e.to_string()))
+        {
+            Ok(mut external) => {
+                external.merge_defaults(&compiled);
+                external
+            }
+            Err(e) => {
+                crate::warn!("Ignoring defaults file {}: {e}", defaults_file.display());
+                compiled
+            }
+        }
+    }
+
+    /// Load the defaults compiled into the binary
+    ///
+    /// The defaults differ between debug and release builds:
+    /// - Debug: Uses `DefaultCLIConfigDebug.toml`
+    /// - Release: Uses `DefaultCLIConfigRelease.toml`
+    ///
+    /// # Panics
+    /// Panics if the embedded default configuration is invalid TOML or cannot be parsed.
+    #[must_use]
+    pub fn compiled_defaults() -> Self {
         Self::from_toml(CONFIG_DEFAULTS).expect("Failed to parse compiled-in default configuration")
     }
 
@@ -401,12 +540,40 @@ impl Config {
     /// config.save()?;
     /// ```
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let config_file = Self::get_config_file_path();
-        if let Some(parent) = config_file.parent() {
+        self.save_to(&Self::get_config_file_path())
+    }
+
+    /// Save configuration to the given file, replacing it atomically
+    ///
+    /// The TOML is written to a temporary file next to `path`, flushed to disk,
+    /// and then renamed over `path`, so a crash mid-save leaves either the old
+    /// or the new config, never a truncated one. The temporary file is removed
+    /// if any step fails.
+    ///
+    /// # Errors
+    /// Returns an error if the config cannot be serialized, the directory cannot
+    /// be created, or the temporary file cannot be written or renamed
+    pub fn save_to(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
         let toml_str = toml::to_string_pretty(self)?;
-        fs::write(&config_file, toml_str)?;
+
+        let file_name = path.file_name().map_or_else(
+            || "config".into(),
+            |name| name.to_string_lossy().into_owned(),
+        );
+        let temp_path = path.with_file_name(format!(".{file_name}.tmp"));
+        let result = fs::File::create(&temp_path)
+            .and_then(|mut file| {
+                file.write_all(toml_str.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&temp_path, path));
+        if let Err(e) = result {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e.into());
+        }
         Ok(())
     }
 
@@ -416,13 +583,15 @@ impl Config {
     /// Supports all config fields in the format `section.field` or just `field` for top-level fields.
     ///
     /// Supported keys:
-    /// - `level`: Logging level ("debug", "info", "warn", "error")
+    /// - `level`: Logging level ("trace", "debug", "info", "warn", "error", "off")
     /// - `file`: Log file path
     /// - `verbose`: Verbose logging boolean
+    /// - `audit`: Config change auditing boolean
     /// - `token`: Database authentication token
     /// - `endpoint`: Database API endpoint
     /// - `metrics_dir`: Metrics output directory path
     /// - `reports_dir`: Reports output directory path
+    /// - `report_format`: Default report format
     ///
     /// # Arguments
     /// - `key`: The configuration key to retrieve
@@ -444,10 +613,12 @@ impl Config {
             "level" => Some(self.logging.level.clone()),
             "file" => Some(self.logging.file.clone()),
             "verbose" => Some(self.logging.verbose.to_string()),
+            "audit" => Some(self.logging.audit.to_string()),
             "token" => Some(self.database.token.clone()),
             "endpoint" => Some(self.database.endpoint.clone()),
             "metrics_dir" | "metrics-dir" => Some(self.paths.metrics_dir.clone()),
             "reports_dir" | "reports-dir" => Some(self.paths.reports_dir.clone()),
+            "report_format" | "report-format" => Some(self.report.format.clone()),
             _ => None,
         }
     }
@@ -461,12 +632,17 @@ impl Config {
     /// - `level`: String ("debug", "info", "warn", "error", "trace", "off")
     /// - `file`: String (file path, can include `$NU_ANALYTICS`)
     /// - `verbose`: Boolean ("true" or "false")
+    /// - `audit`: Boolean ("true" or "false")
     /// - `token`: String (any value)
     /// - `endpoint`: String (typically a URL)
     /// - `metrics_dir`: String (directory path for metrics CSV files)
     /// - `reports_dir`: String (directory path for report files)
+    /// - `report_format`: String ("html", "md", "pdf", "edges"; checked by [`validate()`](Config::validate))
     ///
     /// Note: This method updates the in-memory config. Call [`save()`](Config::save) to persist changes.
+    /// When `audit` is enabled (before or after the change), the previous and new values are
+    /// recorded for [`append_audit_log()`](Config::append_audit_log); `token` values are
+    /// recorded as `***`.
     ///
     /// # Arguments
     /// - `key`: The configuration key to set
@@ -485,20 +661,29 @@ impl Config {
     /// config.save()?;
     /// ```
     pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let old = self.get(key);
+        let was_auditing = self.logging.audit;
         match key {
             "level" => self.logging.level = value.to_string(),
             "file" => self.logging.file = value.to_string(),
             "verbose" => {
-                self.logging.verbose = value
-                    .parse::<bool>()
-                    .map_err(|_| format!("Invalid boolean value for 'verbose': '{value}'"))?;
+                self.logging.verbose = crate::logger::parse_verbose(value)
+                    .ok_or_else(|| format!("Invalid boolean value for 'verbose': '{value}'"))?;
+            }
+            "audit" => {
+                self.logging.audit = crate::logger::parse_verbose(value)
+                    .ok_or_else(|| format!("Invalid boolean value for 'audit': '{value}'"))?;
             }
             "token" => self.database.token = value.to_string(),
             "endpoint" => self.database.endpoint = value.to_string(),
             "metrics_dir" | "metrics-dir" => self.paths.metrics_dir = value.to_string(),
             "reports_dir" | "reports-dir" => self.paths.reports_dir = value.to_string(),
+            "report_format" | "report-format" => self.report.format = value.to_string(),
             _ => return Err(format!("Unknown config key: '{key}'")),
         }
+        if was_auditing || self.logging.audit {
+            self.record_audit("set", key, old);
+        }
         Ok(())
     }
 
@@ -511,6 +696,7 @@ impl Config {
     /// [`from_defaults()`](Config::from_defaults)).
     ///
     /// Note: This method updates the in-memory config. Call [`save()`](Config::save) to persist changes.
+    /// Changes are recorded for auditing the same way as [`set()`](Config::set).
     ///
     /// # Arguments
     /// - `key`: The configuration key to reset
@@ -529,10 +715,13 @@ impl Config {
     /// config.save()?;
     /// ```
     pub fn unset(&mut self, key: &str, defaults: &Self) -> Result<(), String> {
+        let old = self.get(key);
+        let was_auditing = self.logging.audit;
         match key {
             "level" => self.logging.level.clone_from(&defaults.logging.level),
             "file" => self.logging.file.clone_from(&defaults.logging.file),
             "verbose" => self.logging.verbose = defaults.logging.verbose,
+            "audit" => self.logging.audit = defaults.logging.audit,
             "token" => self.database.token.clone_from(&defaults.database.token),
             "endpoint" => self
                 .database
@@ -546,11 +735,195 @@ impl Config {
                 .paths
                 .reports_dir
                 .clone_from(&defaults.paths.reports_dir),
+            "report_format" | "report-format" => {
+                self.report.format.clone_from(&defaults.report.format);
+            }
             _ => return Err(format!("Unknown config key: '{key}'")),
         }
+        if was_auditing || self.logging.audit {
+            self.record_audit("unset", key, old);
+        }
+        Ok(())
+    }
+
+    /// List the settings that differ from the shipped defaults
+    ///
+    /// The read-side counterpart to [`unset()`](Config::unset): compares every key
+    /// against [`from_defaults()`](Config::from_defaults) and returns only those that
+    /// changed.
+    ///
+    /// # Returns
+    /// `(key, default, current)` for each changed key, in [`CONFIG_KEYS`] order
+    #[must_use]
+    pub fn diff_from_defaults(&self) -> Vec<(String, String, String)> {
+        self.diff_from(&Self::from_defaults())
+    }
+
+    /// List the settings that differ from `defaults`
+    ///
+    /// # Returns
+    /// `(key, default, current)` for each changed key, in [`CONFIG_KEYS`] order
+    #[must_use]
+    pub fn diff_from(&self, defaults: &Self) -> Vec<(String, String, String)> {
+        CONFIG_KEYS
+            .iter()
+            .filter_map(|key| {
+                let default = defaults.get(key)?;
+                let current = self.get(key)?;
+                (default != current).then(|| ((*key).to_string(), default, current))
+            })
+            .collect()
+    }
+
+    /// Record a full reset for auditing
+    ///
+    /// [`reset()`](Config::reset) only deletes the config file, so callers that hold the
+    /// config being discarded use this to note the reset before appending the audit log.
+    /// Does nothing unless `audit` is enabled.
+    pub fn record_reset(&mut self) {
+        if self.logging.audit {
+            self.pending_audit.push(AuditEntry {
+                timestamp: unix_timestamp(),
+                operation: "reset".to_string(),
+                key: "*".to_string(),
+                old: None,
+                new: None,
+            });
+        }
+    }
+
+    /// Audit entries recorded since the last [`append_audit_log()`](Config::append_audit_log)
+    #[must_use]
+    pub fn pending_audit(&self) -> &[AuditEntry] {
+        &self.pending_audit
+    }
+
+    /// Append recorded audit entries to an audit log file
+    ///
+    /// Entries are written one per line as `<timestamp> <operation> <key>: "<old>" -> "<new>"`
+    /// and cleared once written. The file and its parent directory are created if needed;
+    /// nothing is touched when no entries are pending.
+    ///
+    /// # Errors
+    /// Returns an error if the directory cannot be created or the file cannot be written.
+    ///
+    /// # Examples
+    /// ```ignore
+    /// config.set("endpoint", "https://example.edu")?;
+    /// config.save()?;
+    /// config.append_audit_log(&Config::get_audit_file_path())?;
+    /// ```
+    pub fn append_audit_log(&mut self, path: &Path) -> Result<(), std::io::Error> {
+        if self.pending_audit.is_empty() {
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        for entry in &self.pending_audit {
+            writeln!(file, "{entry}")?;
+        }
+        self.pending_audit.clear();
         Ok(())
     }
 
+    /// Record a change to `key` whose value before the change was `old`
+    ///
+    /// Values of [`SECRET_KEYS`] are replaced with `***` so secrets never reach the log.
+    fn record_audit(&mut self, operation: &str, key: &str, old: Option<String>) {
+        let mut old = old;
+        let mut new = self.get(key);
+        if SECRET_KEYS.contains(&key) {
+            for value in [&mut old, &mut new].into_iter().flatten() {
+                *value = REDACTED.to_string();
+            }
+        }
+        self.pending_audit.push(AuditEntry {
+            timestamp: unix_timestamp(),
+            operation: operation.to_string(),
+            key: key.to_string(),
+            old,
+            new,
+        });
+    }
+
+    /// Check the configuration for inconsistent or unusable settings
+    ///
+    /// Verifies that:
+    /// - `logging.level` is a recognized log level
+    /// - `report.format` is a supported report format
+    /// - `logging.file`, `paths.metrics_dir`, and `paths.reports_dir` (after
+    ///   `$NU_ANALYTICS` expansion) exist or could be created, i.e. their nearest
+    ///   existing ancestor is a writable directory. An empty log file is allowed.
+    ///
+    /// # Errors
+    /// Returns a list of human-readable problems if any check fails.
+    ///
+    /// # Examples
+    /// ```ignore
+    /// let config = Config::load();
+    /// if let Err(problems) = config.validate() {
+    ///     for p in problems {
+    ///         eprintln!("Config problem: {p}");
+    ///     }
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        if let Err(e) = self.logging.level.parse::<Level>() {
+            problems.push(format!("logging.level: {e}"));
+        }
+        if let Err(e) = self.report.format.parse::<ReportFormat>() {
+            problems.push(format!("report.format: {e}"));
+        }
+
+        if !self.logging.file.is_empty() {
+            let file = Self::expand_variables(&self.logging.file);
+            let parent = Path::new(&file).parent().unwrap_or_else(|| Path::new(""));
+            Self::check_creatable("logging.file", parent, &mut problems);
+        }
+        for (label, dir) in [
+            ("paths.metrics_dir", &self.paths.metrics_dir),
+            ("paths.reports_dir", &self.paths.reports_dir),
+        ] {
+            let dir = Self::expand_variables(dir);
+            Self::check_creatable(label, Path::new(&dir), &mut problems);
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Record a problem unless `dir` exists or could be created
+    ///
+    /// Walks up to the nearest existing ancestor (the current directory for
+    /// relative paths) and requires it to be a writable directory.
+    fn check_creatable(label: &str, dir: &Path, problems: &mut Vec<String>) {
+        let existing = dir
+            .ancestors()
+            .find(|p| p.as_os_str().is_empty() || p.exists())
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+
+        let problem = match fs::metadata(existing) {
+            Ok(meta) if !meta.is_dir() => Some("is not a directory".to_string()),
+            Ok(meta) if meta.permissions().readonly() => Some("is not writable".to_string()),
+            Ok(_) => None,
+            Err(e) => Some(format!("cannot be accessed: {e}")),
+        };
+        if let Some(problem) = problem {
+            problems.push(format!("{label}: '{}' {problem}", existing.display()));
+        }
+    }
+
     /// Reset all configuration to defaults
     ///
     /// Deletes the configuration file, causing the next [`load()`](Config::load) call to
@@ -585,12 +958,20 @@ impl Config {
     }
 }
 
+/// Current time in seconds since the Unix epoch (0 if the clock is before it)
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
 impl fmt::Display for Config {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "[logging]")?;
         writeln!(f, "  level = \"{}\"", self.logging.level)?;
         writeln!(f, "  file = \"{}\"", self.logging.file)?;
         writeln!(f, "  verbose = {}", self.logging.verbose)?;
+        writeln!(f, "  audit = {}", self.logging.audit)?;
 
         writeln!(f, "\n[database]")?;
         writeln!(f, "  token = \"{}\"", self.database.token)?;
@@ -600,6 +981,9 @@ impl fmt::Display for Config {
         writeln!(f, "  metrics_dir = \"{}\"", self.paths.metrics_dir)?;
         writeln!(f, "  reports_dir = \"{}\"", self.paths.reports_dir)?;
 
+        writeln!(f, "\n[report]")?;
+        writeln!(f, "  format = \"{}\"", self.report.format)?;
+
         Ok(())
     }
 }
@@ -0,0 +1,28 @@
+//! Stable hashing shared by the cache and change detection
+//!
+//! `DefaultHasher` output is not guaranteed across Rust releases, so anything
+//! that is persisted or compared between runs hashes with [`Fnv1a`] instead.
+
+use std::hash::Hasher;
+
+/// 64-bit FNV-1a hasher
+pub struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
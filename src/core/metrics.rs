@@ -1,6 +1,7 @@
 //! Complexity and curriculum metrics
 
-use crate::core::models::DAG;
+use crate::core::models::{Plan, School, DAG};
+use serde::Serialize;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Delay factor per course keyed by course code (e.g., "CS2510").
@@ -16,7 +17,7 @@ pub type ComplexityByCourse = HashMap<String, usize>;
 pub type CentralityByCourse = HashMap<String, usize>;
 
 /// Metrics for a single course
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
 pub struct CourseMetrics {
     /// Delay factor (longest requisite path length in vertices)
     pub delay: usize,
@@ -26,6 +27,10 @@ pub struct CourseMetrics {
     pub complexity: usize,
     /// Centrality (sum of path lengths through this course)
     pub centrality: usize,
+    /// Source-to-sink paths with this course as an intermediate step
+    pub paths_through: u64,
+    /// Source-to-sink paths in the whole curriculum
+    pub total_paths: u64,
 }
 
 impl CourseMetrics {
@@ -37,21 +42,149 @@ impl CourseMetrics {
     pub const fn as_export_tuple(&self) -> (usize, usize, usize, usize) {
         (self.complexity, self.blocking, self.delay, self.centrality)
     }
+
+    /// Percentage (0–100) of all source-to-sink paths this course lies on
+    ///
+    /// Gives raw centrality some context: a course on 40% of paths is central
+    /// whatever the curriculum's size. Sources and sinks are not intermediate
+    /// steps, so like centrality they score 0; so does every course when the
+    /// curriculum has no paths.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    pub fn path_percentage(&self) -> f32 {
+        if self.total_paths == 0 {
+            return 0.0;
+        }
+        (self.paths_through as f64 / self.total_paths as f64 * 100.0) as f32
+    }
+
+    /// Band this course's structural complexity into a [`ComplexityTier`]
+    #[must_use]
+    pub const fn tier(&self, thresholds: &ComplexityThresholds) -> ComplexityTier {
+        ComplexityTier::for_complexity(self.complexity, thresholds)
+    }
+}
+
+/// Lower bounds for the medium and high complexity tiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComplexityThresholds {
+    /// Smallest complexity considered medium
+    pub medium: usize,
+    /// Smallest complexity considered high
+    pub high: usize,
+}
+
+impl ComplexityThresholds {
+    /// Create thresholds from the medium and high lower bounds
+    #[must_use]
+    pub const fn new(medium: usize, high: usize) -> Self {
+        Self { medium, high }
+    }
+}
+
+impl Default for ComplexityThresholds {
+    /// Low is 0-5, medium 6-15, high 16 and above
+    fn default() -> Self {
+        Self::new(6, 16)
+    }
+}
+
+/// Complexity band used for color coding and labels in reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComplexityTier {
+    /// Below the medium threshold
+    Low,
+    /// At or above the medium threshold but below high
+    Medium,
+    /// At or above the high threshold
+    High,
+}
+
+impl ComplexityTier {
+    /// Band a raw complexity value
+    #[must_use]
+    pub const fn for_complexity(complexity: usize, thresholds: &ComplexityThresholds) -> Self {
+        if complexity >= thresholds.high {
+            Self::High
+        } else if complexity >= thresholds.medium {
+            Self::Medium
+        } else {
+            Self::Low
+        }
+    }
+
+    /// CSS class used by the HTML report (e.g., `complexity-low`)
+    #[must_use]
+    pub const fn as_css_class(self) -> &'static str {
+        match self {
+            Self::Low => "complexity-low",
+            Self::Medium => "complexity-medium",
+            Self::High => "complexity-high",
+        }
+    }
+
+    /// Human-readable label (e.g., `Low`)
+    #[must_use]
+    pub const fn as_label(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+        }
+    }
 }
 
 /// All metrics for a curriculum, keyed by course code
 pub type CurriculumMetrics = HashMap<String, CourseMetrics>;
 
+/// Options controlling how metrics are computed
+#[derive(Debug, Clone)]
+pub struct MetricsOptions {
+    /// Whether strict corequisite edges lengthen delay paths like prerequisites
+    /// (default `true`). When `false`, strict corequisites are treated as purely
+    /// same-term requirements and do not contribute to delay.
+    pub strict_coreqs_count_toward_delay: bool,
+}
+
+impl Default for MetricsOptions {
+    fn default() -> Self {
+        Self {
+            strict_coreqs_count_toward_delay: true,
+        }
+    }
+}
+
+impl MetricsOptions {
+    /// Set whether strict corequisites count toward delay
+    #[must_use]
+    pub const fn with_strict_coreqs_count_toward_delay(mut self, enabled: bool) -> Self {
+        self.strict_coreqs_count_toward_delay = enabled;
+        self
+    }
+}
+
 /// Compute all metrics for every course in the requisite graph.
 ///
 /// # Errors
 ///
 /// Returns an error if the graph contains a cycle.
 pub fn compute_all_metrics(dag: &DAG) -> Result<CurriculumMetrics, String> {
-    let delay = compute_delay(dag)?;
+    compute_all_metrics_with_options(dag, &MetricsOptions::default())
+}
+
+/// Compute all metrics for every course using the given options.
+///
+/// # Errors
+///
+/// Returns an error if the graph contains a cycle.
+pub fn compute_all_metrics_with_options(
+    dag: &DAG,
+    options: &MetricsOptions,
+) -> Result<CurriculumMetrics, String> {
+    let delay = compute_delay_with_options(dag, options)?;
     let blocking = compute_blocking(dag)?;
     let complexity = compute_complexity(&delay, &blocking)?;
-    let centrality = compute_centrality(dag)?;
+    let centrality = compute_centrality_with_paths(dag)?;
 
     let mut metrics = CurriculumMetrics::new();
 
@@ -59,7 +192,8 @@ pub fn compute_all_metrics(dag: &DAG) -> Result<CurriculumMetrics, String> {
         let delay_val = delay.get(course).copied().unwrap_or(0);
         let blocking_val = blocking.get(course).copied().unwrap_or(0);
         let complexity_val = complexity.get(course).copied().unwrap_or(0);
-        let centrality_val = centrality.get(course).copied().unwrap_or(0);
+        let centrality_val = centrality.totals.get(course).copied().unwrap_or(0);
+        let paths_through = centrality.paths_through.get(course).copied().unwrap_or(0);
 
         metrics.insert(
             course.clone(),
@@ -68,6 +202,8 @@ pub fn compute_all_metrics(dag: &DAG) -> Result<CurriculumMetrics, String> {
                 blocking: blocking_val,
                 complexity: complexity_val,
                 centrality: centrality_val,
+                paths_through,
+                total_paths: centrality.total_paths,
             },
         );
     }
@@ -75,6 +211,221 @@ pub fn compute_all_metrics(dag: &DAG) -> Result<CurriculumMetrics, String> {
     Ok(metrics)
 }
 
+/// One course's row in a plan's metrics table
+///
+/// Renderers format these rows; the selection and ordering live in
+/// [`summary_rows`] so every report lists the same courses in the same order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsRow<'a> {
+    /// Course key as listed in the plan
+    pub key: &'a str,
+    /// Course name, or `None` if the key is not in the school
+    pub name: Option<&'a str>,
+    /// Credit hours (0 for unknown courses)
+    pub credits: f32,
+    /// Free-text advising notes, if any
+    pub notes: Option<&'a str>,
+    /// Course metrics (all zero when none were computed)
+    pub metrics: CourseMetrics,
+}
+
+/// Column that orders metrics table rows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MetricsSort {
+    /// Highest structural complexity first
+    #[default]
+    Complexity,
+    /// Highest blocking factor first (gateway courses)
+    Blocking,
+    /// Highest delay factor first
+    Delay,
+    /// Highest centrality first
+    Centrality,
+    /// Course key, ascending
+    Key,
+}
+
+impl MetricsSort {
+    /// Order two courses by this column
+    ///
+    /// Metric columns sort descending and the key ascending; ties compare equal
+    /// so a stable sort keeps the existing order.
+    #[must_use]
+    pub fn compare(
+        self,
+        (a_key, a): (&str, &CourseMetrics),
+        (b_key, b): (&str, &CourseMetrics),
+    ) -> std::cmp::Ordering {
+        match self {
+            Self::Complexity => b.complexity.cmp(&a.complexity),
+            Self::Blocking => b.blocking.cmp(&a.blocking),
+            Self::Delay => b.delay.cmp(&a.delay),
+            Self::Centrality => b.centrality.cmp(&a.centrality),
+            Self::Key => a_key.cmp(b_key),
+        }
+    }
+}
+
+/// Build the metrics table rows for a plan, ordered by `sort`
+///
+/// Courses that tie on the sort column keep their plan order.
+#[must_use]
+pub fn summary_rows<'a>(
+    plan: &'a Plan,
+    school: &'a School,
+    metrics: &CurriculumMetrics,
+    sort: MetricsSort,
+) -> Vec<MetricsRow<'a>> {
+    let mut rows: Vec<MetricsRow<'a>> = plan
+        .courses
+        .iter()
+        .map(|key| {
+            let course = school.get_course(key);
+            MetricsRow {
+                key,
+                name: course.map(|c| c.name.as_str()),
+                credits: course.map_or(0.0, |c| c.credit_hours),
+                notes: course.and_then(|c| c.notes.as_deref()),
+                metrics: school
+                    .course_metrics(metrics, key)
+                    .cloned()
+                    .unwrap_or_default(),
+            }
+        })
+        .collect();
+    rows.sort_by(|a, b| sort.compare((a.key, &a.metrics), (b.key, &b.metrics)));
+    rows
+}
+
+/// Default delay at or above which a course with no dependents is flagged as a dead-end
+pub const DEFAULT_DEAD_END_DELAY_THRESHOLD: usize = 4;
+
+/// Find "dead-end" courses: high-investment courses that lead nowhere.
+///
+/// A course is a dead-end when its delay is at least `delay_threshold` but
+/// its blocking factor is zero, i.e. it sits on a long prerequisite chain yet
+/// no other course depends on it. This often indicates a missing capstone link.
+///
+/// # Returns
+/// The flagged course keys, sorted
+#[must_use]
+pub fn find_dead_end_courses(metrics: &CurriculumMetrics, delay_threshold: usize) -> Vec<String> {
+    let mut dead_ends: Vec<String> = metrics
+        .iter()
+        .filter(|(_, m)| m.delay >= delay_threshold && m.blocking == 0)
+        .map(|(course, _)| course.clone())
+        .collect();
+    dead_ends.sort();
+    dead_ends
+}
+
+/// Metrics for one course before and after a curriculum change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseMetricsChange {
+    /// Course key
+    pub course: String,
+    /// Baseline key, when the course was matched to a renumbered predecessor
+    pub previous: Option<String>,
+    /// Metrics in the baseline curriculum
+    pub before: CourseMetrics,
+    /// Metrics in the changed curriculum
+    pub after: CourseMetrics,
+}
+
+/// Per-course differences between two sets of curriculum metrics
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetricsDiff {
+    /// Courses only present in the changed curriculum, sorted
+    pub added: Vec<String>,
+    /// Courses only present in the baseline curriculum, sorted
+    pub removed: Vec<String>,
+    /// Courses present in both whose structural metrics differ, sorted by course key
+    ///
+    /// Only delay, blocking, complexity and centrality are compared; the path
+    /// counts depend on the whole curriculum and shift with any edit.
+    pub changed: Vec<CourseMetricsChange>,
+}
+
+impl MetricsDiff {
+    /// Diff `after` against the `before` baseline
+    #[must_use]
+    pub fn between(before: &CurriculumMetrics, after: &CurriculumMetrics) -> Self {
+        Self::between_with_equivalences(before, after, &HashMap::new())
+    }
+
+    /// Diff `after` against `before`, matching renumbered courses
+    ///
+    /// `equivalences` maps a baseline course key to its key in `after`. When
+    /// both sides of an entry exist (and the new key is not itself a baseline
+    /// course), the pair is reported as a single change with
+    /// [`previous`](CourseMetricsChange::previous) set, even if the metrics are
+    /// identical, rather than as a removal plus an addition.
+    #[must_use]
+    pub fn between_with_equivalences(
+        before: &CurriculumMetrics,
+        after: &CurriculumMetrics,
+        equivalences: &HashMap<String, String>,
+    ) -> Self {
+        // New key -> baseline key for every usable equivalence
+        let renumbered: HashMap<&str, &str> = equivalences
+            .iter()
+            .filter(|(old, new)| {
+                old != new
+                    && before.contains_key(*old)
+                    && !after.contains_key(*old)
+                    && after.contains_key(*new)
+                    && !before.contains_key(*new)
+            })
+            .map(|(old, new)| (new.as_str(), old.as_str()))
+            .collect();
+
+        let mut diff = Self::default();
+
+        for (course, after_metrics) in after {
+            let previous = renumbered.get(course.as_str()).copied();
+            let baseline_key = previous.unwrap_or(course);
+            match before.get(baseline_key) {
+                None => diff.added.push(course.clone()),
+                Some(before_metrics)
+                    if previous.is_some()
+                        || before_metrics.as_export_tuple() != after_metrics.as_export_tuple() =>
+                {
+                    diff.changed.push(CourseMetricsChange {
+                        course: course.clone(),
+                        previous: previous.map(str::to_string),
+                        before: before_metrics.clone(),
+                        after: after_metrics.clone(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        let matched: HashSet<&str> = renumbered.values().copied().collect();
+        diff.removed = before
+            .keys()
+            .filter(|course| !after.contains_key(*course) && !matched.contains(course.as_str()))
+            .cloned()
+            .collect();
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff.changed.sort_by(|a, b| a.course.cmp(&b.course));
+        diff
+    }
+
+    /// Look up the change recorded for a course, if its metrics changed
+    #[must_use]
+    pub fn change_for(&self, course: &str) -> Option<&CourseMetricsChange> {
+        self.changed.iter().find(|c| c.course == course)
+    }
+
+    /// Whether the two curricula have identical metrics
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
 /// Compute the delay factor for every course in the requisite graph.
 ///
 /// The delay factor of a course is the length (in vertices) of the longest
@@ -106,6 +457,25 @@ pub fn compute_delay(dag: &DAG) -> Result<DelayByCourse, String> {
     Ok(delays)
 }
 
+/// Compute the delay factor for every course using the given options.
+///
+/// With `strict_coreqs_count_toward_delay` disabled, strict corequisite edges
+/// are dropped before computing longest paths.
+///
+/// # Errors
+///
+/// Returns an error if the graph contains a cycle.
+pub fn compute_delay_with_options(
+    dag: &DAG,
+    options: &MetricsOptions,
+) -> Result<DelayByCourse, String> {
+    if options.strict_coreqs_count_toward_delay {
+        compute_delay(dag)
+    } else {
+        compute_delay(&dag.without_strict_corequisites())
+    }
+}
+
 /// Compute the blocking factor for every course in the requisite graph.
 ///
 /// The blocking factor of a course is the number of other courses in the
@@ -129,7 +499,7 @@ pub fn compute_blocking(dag: &DAG) -> Result<BlockingByCourse, String> {
         .courses
         .iter()
         .map(|course| {
-            let reachable_count = count_reachable(course, &outgoing);
+            let reachable_count = dag.descendants(course).len();
             (course.clone(), reachable_count)
         })
         .collect();
@@ -137,6 +507,23 @@ pub fn compute_blocking(dag: &DAG) -> Result<BlockingByCourse, String> {
     Ok(blocking)
 }
 
+/// Compute the transitive prerequisites of every course.
+///
+/// For each course, lists every course upstream of it in the requisite graph
+/// (prerequisites and corequisites, followed recursively), sorted by key. This
+/// is the reverse of the reachability used for the blocking factor.
+#[must_use]
+pub fn compute_transitive_prerequisites(dag: &DAG) -> HashMap<String, Vec<String>> {
+    dag.courses
+        .iter()
+        .map(|course| {
+            let mut upstream: Vec<String> = dag.ancestors(course).into_iter().collect();
+            upstream.sort();
+            (course.clone(), upstream)
+        })
+        .collect()
+}
+
 /// Compute the structural complexity for every course.
 ///
 /// Structural complexity is defined as the sum of delay factor and blocking
@@ -176,18 +563,49 @@ pub fn compute_complexity(
 /// - **Worst-case complexity**: $O(2^E)$ for highly connected DAGs with many diamonds
 /// - **Note**: For curricula with highly parallel course sequences (many independent prerequisites
 ///   leading to the same courses), the number of paths can grow exponentially. This can make
-///   computation slow for large, complex curricula.
+///   computation slow for large, complex curricula. A warning is logged up front when the
+///   path count reaches [`CENTRALITY_PATH_WARNING_THRESHOLD`].
 ///
 /// # Errors
 ///
 /// Returns an error if the graph contains a cycle.
 pub fn compute_centrality(dag: &DAG) -> Result<CentralityByCourse, String> {
+    compute_centrality_with_paths(dag).map(|centrality| centrality.totals)
+}
+
+/// Centrality totals together with the path counts gathered while computing them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CentralityPaths {
+    /// Centrality per course, as returned by [`compute_centrality`]
+    pub totals: CentralityByCourse,
+    /// Number of source-to-sink paths each course is an intermediate step on
+    pub paths_through: HashMap<String, u64>,
+    /// Number of source-to-sink paths enumerated
+    pub total_paths: u64,
+}
+
+/// Compute centrality while also counting the paths behind it.
+///
+/// Same enumeration as [`compute_centrality`]; each path found also bumps
+/// the counts of its intermediate courses, so `paths_through / total_paths`
+/// is the share of paths a course lies on. The total is counted once up
+/// front and also drives the slow-computation warning.
+///
+/// # Errors
+///
+/// Returns an error if the graph contains a cycle.
+pub fn compute_centrality_with_paths(dag: &DAG) -> Result<CentralityPaths, String> {
     let outgoing = build_outgoing_edges(dag);
     let incoming = build_incoming_edges(dag);
     let indegree = build_indegree_counts(dag);
 
     // Verify DAG is acyclic
-    let _ = topological_order(&dag.courses, &outgoing, &indegree)?;
+    let order = topological_order(&dag.courses, &outgoing, &indegree)?;
+
+    let total_paths = count_source_sink_paths(&order, &outgoing, &incoming);
+    if let Some(warning) = centrality_cost_warning(dag, total_paths) {
+        crate::warn!("{warning}");
+    }
 
     // Find sources (no incoming edges) and sinks (no outgoing edges)
     let sources: Vec<String> = dag
@@ -205,8 +623,7 @@ pub fn compute_centrality(dag: &DAG) -> Result<CentralityByCourse, String> {
         .collect();
 
     // Initialize centrality to 0 for all courses
-    let mut centrality: HashMap<String, usize> =
-        dag.courses.iter().map(|c| (c.clone(), 0)).collect();
+    let mut centrality = CentralityAccumulator::new(&dag.courses);
 
     // For each source, enumerate all paths to all sinks
     for source in &sources {
@@ -217,7 +634,124 @@ pub fn compute_centrality(dag: &DAG) -> Result<CentralityByCourse, String> {
         }
     }
 
-    Ok(centrality)
+    if centrality.saturated {
+        crate::error!(
+            "Centrality overflowed usize while summing path lengths; affected values are saturated at {} and unreliable",
+            usize::MAX
+        );
+    }
+
+    Ok(CentralityPaths {
+        totals: centrality.totals,
+        paths_through: centrality.paths_through,
+        total_paths,
+    })
+}
+
+/// Number of source-to-sink paths at which [`compute_centrality`] warns that it may be slow
+pub const CENTRALITY_PATH_WARNING_THRESHOLD: u64 = 1_000_000;
+
+/// Count source-to-sink paths, the work [`compute_centrality`] enumerates
+///
+/// Counted by dynamic programming over `order` in linear time; saturates at
+/// `u64::MAX`. Isolated courses (both source and sink) contribute no paths.
+fn count_source_sink_paths(
+    order: &[String],
+    outgoing: &HashMap<String, Vec<String>>,
+    incoming: &HashMap<String, Vec<String>>,
+) -> u64 {
+    let mut paths_to: HashMap<&str, u64> = HashMap::new();
+    let mut total: u64 = 0;
+
+    for course in order {
+        let is_source = incoming.get(course).is_none_or(Vec::is_empty);
+        let is_sink = outgoing.get(course).is_none_or(Vec::is_empty);
+        let paths = if is_source {
+            1
+        } else {
+            paths_to.get(course.as_str()).copied().unwrap_or(0)
+        };
+
+        if is_sink && !is_source {
+            total = total.saturating_add(paths);
+        }
+        for next in outgoing.get(course).into_iter().flatten() {
+            let entry = paths_to.entry(next.as_str()).or_insert(0);
+            *entry = entry.saturating_add(paths);
+        }
+    }
+
+    total
+}
+
+/// Explain why centrality may be slow, if the graph has too many paths to enumerate
+///
+/// `paths` is the source-to-sink path count from [`count_source_sink_paths`].
+fn centrality_cost_warning(dag: &DAG, paths: u64) -> Option<String> {
+    if paths < CENTRALITY_PATH_WARNING_THRESHOLD {
+        return None;
+    }
+
+    let stats = dag.stats();
+    Some(format!(
+        "Centrality must enumerate {paths} source-to-sink paths ({} courses, {} edges, max in-degree {}); this may take a long time",
+        stats.nodes, stats.edges, stats.max_in_degree
+    ))
+}
+
+/// Running centrality totals with overflow tracking.
+///
+/// Dense curricula can have exponentially many source-to-sink paths, so the
+/// per-course sums are accumulated with saturating arithmetic. If any sum
+/// saturates, `saturated` is set so the caller can report the result as unreliable
+/// instead of silently returning a wrapped value. Per-course path counts
+/// saturate at `u64::MAX`, far beyond anything enumerable.
+struct CentralityAccumulator {
+    /// Centrality total per course
+    totals: CentralityByCourse,
+    /// Paths each course is an intermediate step on
+    paths_through: HashMap<String, u64>,
+    /// Whether any total hit `usize::MAX`
+    saturated: bool,
+}
+
+impl CentralityAccumulator {
+    /// Create an accumulator with every course starting at 0
+    fn new(courses: &[String]) -> Self {
+        Self {
+            totals: courses.iter().map(|c| (c.clone(), 0)).collect(),
+            paths_through: courses.iter().map(|c| (c.clone(), 0)).collect(),
+            saturated: false,
+        }
+    }
+
+    /// Record one complete source-to-sink path
+    fn add_path(&mut self, path: &[String]) {
+        if path.len() <= 2 {
+            // Only source and sink, no intermediate nodes
+            return;
+        }
+
+        let path_length = path.len();
+        for course in &path[1..path_length - 1] {
+            self.add(course, path_length);
+            if let Some(count) = self.paths_through.get_mut(course) {
+                *count = count.saturating_add(1);
+            }
+        }
+    }
+
+    /// Add a path length to a course's total, saturating on overflow
+    fn add(&mut self, course: &str, path_length: usize) {
+        if let Some(count) = self.totals.get_mut(course) {
+            if let Some(sum) = count.checked_add(path_length) {
+                *count = sum;
+            } else {
+                *count = usize::MAX;
+                self.saturated = true;
+            }
+        }
+    }
 }
 
 /// Enumerate all paths from source to sink and update centrality counts.
@@ -230,7 +764,7 @@ pub fn compute_centrality(dag: &DAG) -> Result<CentralityByCourse, String> {
 /// * `source` - Starting node for the path search
 /// * `sink` - Target node to reach
 /// * `outgoing` - Map of outgoing edges from each course
-/// * `centrality` - Mutable map to accumulate centrality counts
+/// * `centrality` - Accumulator for centrality counts
 ///
 /// # Behavior
 /// Only intermediate nodes (not source or sink) receive centrality updates.
@@ -239,7 +773,7 @@ fn enumerate_paths_and_update_centrality(
     source: &str,
     sink: &str,
     outgoing: &HashMap<String, Vec<String>>,
-    centrality: &mut HashMap<String, usize>,
+    centrality: &mut CentralityAccumulator,
 ) {
     let mut path = Vec::new();
     let mut visited = HashSet::new();
@@ -262,36 +796,26 @@ fn enumerate_paths_and_update_centrality(
 /// * `path` - The current path being built (includes nodes visited so far)
 /// * `visited` - Set of nodes already in the current path (prevents cycles)
 /// * `outgoing` - Map of outgoing edges from each course
-/// * `centrality` - Mutable map to accumulate centrality counts
+/// * `centrality` - Accumulator for centrality counts
 ///
 /// # Algorithm
 /// Uses backtracking to explore all neighbors of the current node. When the target
 /// is reached, intermediate nodes (all except first and last) have their centrality
-/// incremented by the path length. The visited set prevents revisiting nodes within
-/// a single path (necessary for correctness even though DAG shouldn't have cycles).
+/// incremented by the path length using saturating arithmetic. The visited set
+/// prevents revisiting nodes within a single path (necessary for correctness even
+/// though DAG shouldn't have cycles).
 fn dfs_paths(
     current: &str,
     target: &str,
     path: &mut Vec<String>,
     visited: &mut HashSet<String>,
     outgoing: &HashMap<String, Vec<String>>,
-    centrality: &mut HashMap<String, usize>,
+    centrality: &mut CentralityAccumulator,
 ) {
     if current == target {
         // Found a complete path - add its length to centrality of intermediate nodes only
         // (exclude the source at path[0] and sink at path[len-1])
-        if path.len() <= 2 {
-            // If path has only source and sink, no intermediate nodes
-            return;
-        }
-
-        let path_length = path.len();
-        // Skip first (source) and last (sink) elements
-        for course in path.iter().skip(1).take(path.len() - 2) {
-            if let Some(count) = centrality.get_mut(course) {
-                *count += path_length;
-            }
-        }
+        centrality.add_path(path);
         return;
     }
 
@@ -343,35 +867,6 @@ fn collect_and_sort_related_courses(
     sorted
 }
 
-/// Count the number of courses reachable from a given course via breadth-first search
-///
-/// # Arguments
-/// * `start` - The course key to start from
-/// * `outgoing` - Map of outgoing edges from each course
-///
-/// # Returns
-/// The count of reachable courses (excluding the start course itself)
-fn count_reachable(start: &str, outgoing: &HashMap<String, Vec<String>>) -> usize {
-    let mut visited = HashSet::new();
-    let mut queue = VecDeque::new();
-
-    queue.push_back(start.to_string());
-    visited.insert(start.to_string());
-
-    while let Some(course) = queue.pop_front() {
-        if let Some(neighbors) = outgoing.get(&course) {
-            for neighbor in neighbors {
-                if visited.insert(neighbor.clone()) {
-                    queue.push_back(neighbor.clone());
-                }
-            }
-        }
-    }
-
-    // Subtract 1 because we don't count the starting course itself
-    visited.len() - 1
-}
-
 /// Build a map of incoming edges (prerequisites and corequisites) for each course
 ///
 /// # Arguments
@@ -565,6 +1060,7 @@ fn longest_paths_from(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::approx::{approx_eq, credits_eq};
     use crate::core::planner::parse_curriculum_csv;
 
     #[test]
@@ -656,6 +1152,43 @@ mod tests {
         assert_eq!(blocking.get("CS220"), Some(&2));
     }
 
+    #[test]
+    fn summary_rows_sorted_by_complexity_with_credits() {
+        let school = parse_curriculum_csv("samples/correct/Colostate_CSDegree_w_metrics.csv")
+            .expect("parse sample curriculum");
+        let metrics = compute_all_metrics(&school.build_dag()).expect("metrics");
+        let plan = &school.plans[0];
+
+        let rows = summary_rows(plan, &school, &metrics, MetricsSort::Complexity);
+
+        assert_eq!(rows.len(), plan.courses.len());
+        assert!(rows
+            .windows(2)
+            .all(|w| w[0].metrics.complexity >= w[1].metrics.complexity));
+        for row in &rows {
+            let course = school.get_course(row.key).expect("plan course");
+            assert!(credits_eq(row.credits, course.credit_hours));
+            assert_eq!(row.name, Some(course.name.as_str()));
+            assert_eq!(Some(&row.metrics), metrics.get(row.key));
+        }
+    }
+
+    #[test]
+    fn summary_rows_sorted_by_blocking() {
+        let school = parse_curriculum_csv("samples/correct/Colostate_CSDegree_w_metrics.csv")
+            .expect("parse sample curriculum");
+        let metrics = compute_all_metrics(&school.build_dag()).expect("metrics");
+        let plan = &school.plans[0];
+
+        let rows = summary_rows(plan, &school, &metrics, MetricsSort::Blocking);
+
+        let max_blocking = rows.iter().map(|r| r.metrics.blocking).max();
+        assert_eq!(rows.first().map(|r| r.metrics.blocking), max_blocking);
+        assert!(rows
+            .windows(2)
+            .all(|w| w[0].metrics.blocking >= w[1].metrics.blocking));
+    }
+
     #[test]
     fn computes_complexity_from_delay_and_blocking() {
         let mut dag = DAG::new();
@@ -731,6 +1264,47 @@ mod tests {
         assert_eq!(centrality.get("D"), Some(&0));
     }
 
+    #[test]
+    fn path_percentage_on_fork() {
+        let mut dag = DAG::new();
+        dag.add_prerequisite("B".to_string(), "A");
+        dag.add_prerequisite("C".to_string(), "A");
+        dag.add_prerequisite("D".to_string(), "B");
+
+        let centrality = compute_centrality_with_paths(&dag).expect("centrality");
+        assert_eq!(
+            centrality.totals,
+            compute_centrality(&dag).expect("centrality")
+        );
+        // Paths: A->B->D and A->C
+        assert_eq!(centrality.total_paths, 2);
+
+        let metrics = compute_all_metrics(&dag).expect("metrics");
+        for course in metrics.values() {
+            let percentage = course.path_percentage();
+            assert!((0.0..=100.0).contains(&percentage), "{percentage}");
+        }
+        // B lies on one of the two paths; the source and sinks are not intermediate
+        assert!(approx_eq(
+            f64::from(metrics["B"].path_percentage()),
+            50.0,
+            1e-6
+        ));
+        for course in ["A", "C", "D"] {
+            assert!(approx_eq(
+                f64::from(metrics[course].path_percentage()),
+                0.0,
+                1e-6
+            ));
+        }
+
+        assert!(approx_eq(
+            f64::from(CourseMetrics::default().path_percentage()),
+            0.0,
+            1e-6
+        ));
+    }
+
     #[test]
     fn matches_sample_centrality_values() {
         let school = parse_curriculum_csv("samples/correct/Colostate_CSDegree_w_metrics.csv")
@@ -839,6 +1413,165 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dead_end_courses_require_long_delay_and_no_dependents() {
+        let mut dag = DAG::new();
+        dag.add_prerequisite("B".to_string(), "A");
+        dag.add_prerequisite("C".to_string(), "B");
+        dag.add_prerequisite("D".to_string(), "C");
+        dag.add_course("LEAF".to_string());
+
+        let metrics = compute_all_metrics(&dag).expect("metrics");
+        let dead_ends = find_dead_end_courses(&metrics, DEFAULT_DEAD_END_DELAY_THRESHOLD);
+
+        // D closes a 4-course chain with nothing after it; LEAF is a plain
+        // standalone leaf and A starts the chain (it blocks others)
+        assert_eq!(dead_ends, vec!["D".to_string()]);
+        assert!(find_dead_end_courses(&metrics, 5).is_empty());
+    }
+
+    #[test]
+    fn complexity_tier_boundaries() {
+        let thresholds = ComplexityThresholds::default();
+        let tier = |complexity| ComplexityTier::for_complexity(complexity, &thresholds);
+
+        assert_eq!(tier(0), ComplexityTier::Low);
+        assert_eq!(tier(5), ComplexityTier::Low);
+        assert_eq!(tier(6), ComplexityTier::Medium);
+        assert_eq!(tier(15), ComplexityTier::Medium);
+        assert_eq!(tier(16), ComplexityTier::High);
+
+        let metrics = CourseMetrics {
+            delay: 4,
+            blocking: 2,
+            complexity: 6,
+            centrality: 0,
+            ..CourseMetrics::default()
+        };
+        assert_eq!(metrics.tier(&thresholds), ComplexityTier::Medium);
+        assert_eq!(
+            metrics.tier(&ComplexityThresholds::new(3, 6)),
+            ComplexityTier::High
+        );
+        assert_eq!(ComplexityTier::Medium.as_css_class(), "complexity-medium");
+        assert_eq!(ComplexityTier::High.as_label(), "High");
+    }
+
+    #[test]
+    fn transitive_prerequisites_follow_chain() {
+        let mut dag = DAG::new();
+        dag.add_prerequisite("B".to_string(), "A");
+        dag.add_prerequisite("C".to_string(), "B");
+
+        let transitive = compute_transitive_prerequisites(&dag);
+        assert_eq!(
+            transitive.get("C"),
+            Some(&vec!["A".to_string(), "B".to_string()])
+        );
+        assert_eq!(transitive.get("B"), Some(&vec!["A".to_string()]));
+        assert_eq!(transitive.get("A"), Some(&Vec::new()));
+    }
+
+    #[test]
+    fn strict_coreq_delay_follows_policy() {
+        let mut dag = DAG::new();
+        dag.add_strict_corequisite("CS101L".to_string(), "CS101");
+
+        let counted = compute_delay_with_options(&dag, &MetricsOptions::default())
+            .expect("delay computation");
+        assert_eq!(counted.get("CS101"), Some(&2));
+        assert_eq!(counted.get("CS101L"), Some(&2));
+
+        let options = MetricsOptions::default().with_strict_coreqs_count_toward_delay(false);
+        let same_term = compute_delay_with_options(&dag, &options).expect("delay computation");
+        assert_eq!(same_term.get("CS101"), Some(&1));
+        assert_eq!(same_term.get("CS101L"), Some(&1));
+
+        let all = compute_all_metrics_with_options(&dag, &options).expect("metrics");
+        assert_eq!(all.get("CS101L").map(|m| m.delay), Some(1));
+    }
+
+    #[test]
+    fn centrality_saturates_instead_of_overflowing() {
+        // Dense layered graph: every course in one layer is a prerequisite of every
+        // course in the next, giving many distinct source-to-sink paths.
+        let mut dag = DAG::new();
+        for layer in 1..4 {
+            for to in 0..3 {
+                for from in 0..3 {
+                    dag.add_prerequisite(
+                        format!("L{layer}_{to}"),
+                        &format!("L{}_{from}", layer - 1),
+                    );
+                }
+            }
+        }
+        let outgoing = build_outgoing_edges(&dag);
+
+        // Seed a middle course near the limit so the next path overflows
+        let mut centrality = CentralityAccumulator::new(&dag.courses);
+        centrality.totals.insert("L1_0".to_string(), usize::MAX - 1);
+
+        for source in ["L0_0", "L0_1", "L0_2"] {
+            for sink in ["L3_0", "L3_1", "L3_2"] {
+                enumerate_paths_and_update_centrality(source, sink, &outgoing, &mut centrality);
+            }
+        }
+
+        assert!(centrality.saturated, "Overflow should be detected");
+        assert_eq!(centrality.totals.get("L1_0"), Some(&usize::MAX));
+        // Unaffected courses keep their exact totals
+        assert!(centrality
+            .totals
+            .get("L1_1")
+            .is_some_and(|&c| c < usize::MAX));
+    }
+
+    #[test]
+    fn centrality_warns_on_dense_graph() {
+        fn warning_for(dag: &DAG) -> Option<String> {
+            let outgoing = build_outgoing_edges(dag);
+            let incoming = build_incoming_edges(dag);
+            let order = topological_order(&dag.courses, &outgoing, &build_indegree_counts(dag))
+                .expect("acyclic");
+            centrality_cost_warning(dag, count_source_sink_paths(&order, &outgoing, &incoming))
+        }
+
+        // Eight fully connected layers of six courses: 6^8 source-to-sink paths
+        let mut dense = DAG::new();
+        for layer in 1..8 {
+            for to in 0..6 {
+                for from in 0..6 {
+                    dense.add_prerequisite(
+                        format!("L{layer}_{to}"),
+                        &format!("L{}_{from}", layer - 1),
+                    );
+                }
+            }
+        }
+        let warning = warning_for(&dense).expect("dense graph should warn");
+        assert!(warning.contains("1679616 source-to-sink paths"));
+        assert!(warning.contains("48 courses, 252 edges"));
+
+        let school = parse_curriculum_csv("samples/correct/Colostate_CSDegree_w_metrics.csv")
+            .expect("parse sample curriculum");
+        assert_eq!(warning_for(&school.build_dag()), None);
+    }
+
+    #[test]
+    fn centrality_not_saturated_on_normal_graph() {
+        let mut dag = DAG::new();
+        dag.add_prerequisite("B".to_string(), "A");
+        dag.add_prerequisite("C".to_string(), "B");
+        let outgoing = build_outgoing_edges(&dag);
+
+        let mut centrality = CentralityAccumulator::new(&dag.courses);
+        enumerate_paths_and_update_centrality("A", "C", &outgoing, &mut centrality);
+
+        assert!(!centrality.saturated);
+        assert_eq!(centrality.totals.get("B"), Some(&3));
+    }
+
     #[test]
     fn test_course_metrics_export_tuple() {
         let metrics = CourseMetrics {
@@ -846,6 +1579,7 @@ mod tests {
             blocking: 3,
             complexity: 8,
             centrality: 10,
+            ..CourseMetrics::default()
         };
 
         let (complexity, blocking, delay, centrality) = metrics.as_export_tuple();
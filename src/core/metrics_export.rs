@@ -1,11 +1,47 @@
 //! Export metrics to various formats
 
-use super::metrics::CurriculumMetrics;
+use super::metrics::{
+    find_dead_end_courses, CourseMetrics, CurriculumMetrics, MetricsSort,
+    DEFAULT_DEAD_END_DELAY_THRESHOLD,
+};
 use super::models::DAG;
+use super::report::TermPlan;
 use crate::core::models::{Course, Degree, Plan, School};
+use crate::json::json_string;
 use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
 use std::path::Path;
 
+/// Errors produced while exporting metrics
+#[derive(Debug)]
+pub enum ExportError {
+    /// The output file could not be created or written
+    Io(io::Error),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+        }
+    }
+}
+
+impl Error for ExportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for ExportError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
 /// Trait for exporting curriculum metrics in different formats
 pub trait MetricsExporter {
     /// Export metrics for a curriculum plan
@@ -18,7 +54,7 @@ pub trait MetricsExporter {
         plan: &Plan,
         metrics: &CurriculumMetrics,
         output_path: &Path,
-    ) -> Result<(), Box<dyn Error>>;
+    ) -> Result<(), ExportError>;
 }
 
 /// Summary statistics for a curriculum
@@ -36,12 +72,16 @@ pub struct CurriculumSummary {
     pub longest_delay_course: String,
     /// Path of courses that make up the longest delay
     pub longest_delay_path: Vec<String>,
+    /// Share of total complexity per course prefix, as percentages sorted descending
+    pub complexity_by_prefix_pct: Vec<(String, f32)>,
+    /// Plan courses with high delay but no dependents (see [`find_dead_end_courses`])
+    pub dead_end_courses: Vec<String>,
 }
 
 impl CurriculumSummary {
     /// Compute summary statistics from curriculum metrics
     #[must_use]
-    pub fn from_metrics(plan: &Plan, _school: &School, metrics: &CurriculumMetrics) -> Self {
+    pub fn from_metrics(plan: &Plan, school: &School, metrics: &CurriculumMetrics) -> Self {
         let mut total_complexity = 0;
         let mut highest_centrality = 0;
         let mut highest_centrality_course = String::new();
@@ -49,7 +89,7 @@ impl CurriculumSummary {
         let mut longest_delay_course = String::new();
 
         for course_key in &plan.courses {
-            if let Some(m) = metrics.get(course_key) {
+            if let Some(m) = school.course_metrics(metrics, course_key) {
                 total_complexity += m.complexity;
 
                 if m.centrality > highest_centrality {
@@ -71,6 +111,15 @@ impl CurriculumSummary {
             longest_delay,
             longest_delay_course,
             longest_delay_path: Vec::new(), // Will be computed separately when DAG is available
+            complexity_by_prefix_pct: complexity_by_prefix_pct(plan, school, metrics),
+            dead_end_courses: find_dead_end_courses(metrics, DEFAULT_DEAD_END_DELAY_THRESHOLD)
+                .into_iter()
+                .filter(|course| {
+                    plan.courses
+                        .iter()
+                        .any(|key| school.canonical_key(key) == course)
+                })
+                .collect(),
         }
     }
 
@@ -82,6 +131,40 @@ impl CurriculumSummary {
     }
 }
 
+/// Compute each course prefix's share of the plan's total complexity.
+///
+/// Returns `(prefix, percent)` pairs sorted by percent descending (ties broken
+/// by prefix). Percentages are unrounded and sum to 100 unless the plan has no
+/// complexity, in which case the list is empty.
+#[allow(clippy::cast_precision_loss)]
+fn complexity_by_prefix_pct(
+    plan: &Plan,
+    school: &School,
+    metrics: &CurriculumMetrics,
+) -> Vec<(String, f32)> {
+    let mut by_prefix: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    for course_key in &plan.courses {
+        if let (Some(course), Some(m)) = (
+            school.get_course(course_key),
+            school.course_metrics(metrics, course_key),
+        ) {
+            *by_prefix.entry(course.prefix.as_str()).or_insert(0) += m.complexity;
+        }
+    }
+
+    let total: usize = by_prefix.values().sum();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut pct: Vec<(String, f32)> = by_prefix
+        .into_iter()
+        .map(|(prefix, complexity)| (prefix.to_string(), complexity as f32 * 100.0 / total as f32))
+        .collect();
+    pct.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    pct
+}
+
 /// Compute the longest path through the curriculum DAG by tracing back prerequisites
 ///
 /// Finds the course with the maximum delay value, then traces back through its
@@ -239,7 +322,7 @@ impl MetricsExporter for CsvExporter {
         plan: &Plan,
         metrics: &CurriculumMetrics,
         output_path: &Path,
-    ) -> Result<(), Box<dyn Error>> {
+    ) -> Result<(), ExportError> {
         let dag = school.build_dag();
         let summary =
             CurriculumSummary::from_metrics(plan, school, metrics).with_delay_path(&dag, metrics);
@@ -257,22 +340,126 @@ impl MetricsExporter for CsvExporter {
 /// * `output_path` - Path to write the CSV file to
 ///
 /// # Errors
-/// Returns an error if file writing fails
-#[allow(clippy::too_many_lines)]
+/// Returns [`ExportError::Io`] if the file cannot be created or written
 pub fn export_metrics_csv_with_summary(
     school: &School,
     plan: &Plan,
     metrics: &CurriculumMetrics,
     summary: &CurriculumSummary,
     output_path: &Path,
-) -> Result<(), Box<dyn Error>> {
-    use std::fs::File;
-    use std::io::Write;
+) -> Result<(), ExportError> {
+    export_metrics_csv_with_options(
+        school,
+        plan,
+        metrics,
+        summary,
+        &ExportOptions::default(),
+        output_path,
+    )
+}
+
+/// Options controlling optional parts of the CSV export
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExportOptions<'a> {
+    /// Schedule used to order rows term-major and add a `Term` column
+    pub term_plan: Option<&'a TermPlan>,
+    /// Add a `Transitive Prerequisites` column listing all upstream courses
+    pub transitive_prerequisites: bool,
+    /// Order rows by a metric column instead of CSV ID (ignored with a term plan)
+    pub sort: Option<MetricsSort>,
+}
+
+impl<'a> ExportOptions<'a> {
+    /// Order rows by the given term schedule
+    #[must_use]
+    pub const fn with_term_plan(mut self, term_plan: &'a TermPlan) -> Self {
+        self.term_plan = Some(term_plan);
+        self
+    }
 
-    let mut file = File::create(output_path)?;
+    /// Include the transitive prerequisites column
+    #[must_use]
+    pub const fn with_transitive_prerequisites(mut self, enabled: bool) -> Self {
+        self.transitive_prerequisites = enabled;
+        self
+    }
+
+    /// Order rows by the given metric column
+    #[must_use]
+    pub const fn with_sort(mut self, sort: MetricsSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// Export curriculum metrics to CSV with optional extra columns and ordering
+///
+/// Creates `output_path` and writes to it with [`write_metrics_csv`].
+///
+/// # Arguments
+/// * `school` - The school with courses and degrees
+/// * `plan` - The plan to export metrics for
+/// * `metrics` - The computed metrics for all courses
+/// * `summary` - Summary statistics
+/// * `options` - Optional ordering and columns
+/// * `output_path` - Path to write the CSV file to
+///
+/// # Errors
+/// Returns [`ExportError::Io`] if the file cannot be created or written
+pub fn export_metrics_csv_with_options(
+    school: &School,
+    plan: &Plan,
+    metrics: &CurriculumMetrics,
+    summary: &CurriculumSummary,
+    options: &ExportOptions<'_>,
+    output_path: &Path,
+) -> Result<(), ExportError> {
+    let file = std::fs::File::create(output_path)?;
+    write_metrics_csv(file, school, plan, metrics, summary, options)
+}
 
+/// Write curriculum metrics CSV to any writer (e.g., an in-memory buffer)
+///
+/// With a term plan, course rows are ordered term-major (then by course key
+/// within a term) and a trailing `Term` column is added; courses missing from
+/// the schedule come last with an empty term. Without one, rows are ordered by
+/// `sort` if set (ties keep CSV ID order), otherwise by CSV ID as in
+/// [`export_metrics_csv_with_summary`].
+///
+/// With `transitive_prerequisites`, a `Transitive Prerequisites` column lists
+/// every upstream course (prerequisites and corequisites, recursively) as CSV IDs.
+///
+/// A plan whose degree is not in the school is exported as a semester `BS`
+/// with an empty CIP code, and a warning is logged.
+///
+/// # Arguments
+/// * `writer` - Destination of the CSV text
+/// * `school` - The school with courses and degrees
+/// * `plan` - The plan to export metrics for
+/// * `metrics` - The computed metrics for all courses
+/// * `summary` - Summary statistics
+/// * `options` - Optional ordering and columns
+///
+/// # Errors
+/// Returns [`ExportError::Io`] if the writer fails
+#[allow(clippy::too_many_lines)]
+pub fn write_metrics_csv<W: Write>(
+    mut writer: W,
+    school: &School,
+    plan: &Plan,
+    metrics: &CurriculumMetrics,
+    summary: &CurriculumSummary,
+    options: &ExportOptions<'_>,
+) -> Result<(), ExportError> {
     // Try to find the degree to get degree type and system type
     let degree = school.degrees.iter().find(|d| d.id() == plan.degree_id);
+    if degree.is_none() {
+        crate::warn!(
+            "Degree '{}' for plan '{}' not found; exporting as a semester BS",
+            plan.degree_id,
+            plan.name
+        );
+    }
 
     let degree_type = degree.map_or_else(|| "BS".to_string(), |d| d.degree_type.clone());
     let cip_code = degree.map_or_else(String::new, |d| d.cip_code.clone());
@@ -297,12 +484,38 @@ pub fn export_metrics_csv_with_summary(
         })
         .collect();
 
-    // Sort by CSV ID (numerically if possible)
+    // Sort by CSV ID (numerically if possible), then by storage key so courses
+    // with non-numeric or missing IDs still come out in a stable order
     courses_by_csv_id.sort_by(|a, b| {
         let a_num = a.0.parse::<usize>().unwrap_or(0);
         let b_num = b.0.parse::<usize>().unwrap_or(0);
-        a_num.cmp(&b_num)
+        a_num.cmp(&b_num).then_with(|| a.1.cmp(&b.1))
+    });
+
+    if let Some(sort) = options.sort {
+        let zero = CourseMetrics::default();
+        courses_by_csv_id.sort_by(|a, b| {
+            sort.compare(
+                (&a.1, school.course_metrics(metrics, &a.1).unwrap_or(&zero)),
+                (&b.1, school.course_metrics(metrics, &b.1).unwrap_or(&zero)),
+            )
+        });
+    }
+
+    // Map each scheduled course to its term number when ordering by schedule
+    let term_of: Option<std::collections::HashMap<&str, usize>> = options.term_plan.map(|tp| {
+        tp.terms
+            .iter()
+            .flat_map(|term| term.courses.iter().map(|key| (key.as_str(), term.number)))
+            .collect()
     });
+    if let Some(term_of) = &term_of {
+        courses_by_csv_id.sort_by(|a, b| {
+            let a_term = term_of.get(a.1.as_str()).copied().unwrap_or(usize::MAX);
+            let b_term = term_of.get(b.1.as_str()).copied().unwrap_or(usize::MAX);
+            a_term.cmp(&b_term).then_with(|| a.1.cmp(&b.1))
+        });
+    }
 
     // Compute scaled complexity for each course, then sum for total
     // This matches the reference tool which rounds per-course before summing
@@ -310,7 +523,9 @@ pub fn export_metrics_csv_with_summary(
     let scaled_total_complexity: f64 = courses_by_csv_id
         .iter()
         .map(|(_, storage_key, _)| {
-            let complexity = metrics.get(storage_key).map_or(0, |m| m.complexity);
+            let complexity = school
+                .course_metrics(metrics, storage_key)
+                .map_or(0, |m| m.complexity);
             // Round to 1 decimal place per course (matches reference tool)
             ((complexity as f64 * scale_factor) * 10.0).round() / 10.0
         })
@@ -318,49 +533,61 @@ pub fn export_metrics_csv_with_summary(
 
     // Write header section with summary statistics - one item per row
     // Row 1: Curriculum name
-    writeln!(file, "Curriculum,{}", plan.name)?;
+    writeln!(writer, "Curriculum,{}", plan.name)?;
 
     // Row 2: Institution
-    writeln!(file, "Institution,{institution}")?;
+    writeln!(writer, "Institution,{institution}")?;
 
     // Row 3: Degree Type
-    writeln!(file, "Degree Type,\"{degree_type}\"")?;
+    writeln!(writer, "Degree Type,\"{degree_type}\"")?;
 
     // Row 4: System Type
-    writeln!(file, "System Type,{system_type}")?;
+    writeln!(writer, "System Type,{system_type}")?;
 
     // Row 5: CIP code
-    writeln!(file, "CIP,\"{cip_code}\"")?;
+    writeln!(writer, "CIP,\"{cip_code}\"")?;
 
     // Row 6: Total Structural Complexity (sum of scaled per-course values)
     writeln!(
-        file,
+        writer,
         "Total Structural Complexity,{scaled_total_complexity:.1}"
     )?;
 
     // Row 7: Longest Delay with path
-    write!(file, "Longest Delay,{}", summary.longest_delay)?;
+    write!(writer, "Longest Delay,{}", summary.longest_delay)?;
     if !summary.longest_delay_path.is_empty() {
-        write!(file, ",{}", summary.longest_delay_path.join("->"))?;
+        write!(writer, ",{}", summary.longest_delay_path.join("->"))?;
     }
-    writeln!(file)?;
+    writeln!(writer)?;
 
     // Row 8: Highest Centrality Course
     writeln!(
-        file,
+        writer,
         "Highest Centrality Course,\"{}\",{}",
         summary.highest_centrality_course, summary.highest_centrality
     )?;
 
     // Write courses section
-    writeln!(file, "Courses")?;
-    writeln!(
-        file,
+    writeln!(writer, "Courses")?;
+    write!(
+        writer,
         "Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours,Institution,Canonical Name,Complexity,Blocking,Delay,Centrality"
     )?;
+    if options.transitive_prerequisites {
+        write!(writer, ",Transitive Prerequisites")?;
+    }
+    if term_of.is_some() {
+        write!(writer, ",Term")?;
+    }
+    writeln!(writer)?;
+
+    let transitive = options
+        .transitive_prerequisites
+        .then(|| super::metrics::compute_transitive_prerequisites(&school.build_dag()));
 
     // Write course data
     crate::debug!("Exporting {} courses from plan", plan.courses.len());
+    crate::logger::group("Course export");
 
     for (csv_id, storage_key, course) in &courses_by_csv_id {
         crate::debug!(
@@ -369,7 +596,7 @@ pub fn export_metrics_csv_with_summary(
             course.name,
             storage_key
         );
-        let metrics_data = metrics.get(storage_key);
+        let metrics_data = school.course_metrics(metrics, storage_key);
 
         // Convert stored keys back to CSV IDs for export
         let prereqs = format_course_keys_as_csv(course.prerequisites.iter(), school);
@@ -384,8 +611,8 @@ pub fn export_metrics_csv_with_summary(
         #[allow(clippy::cast_precision_loss)]
         let scaled_complexity = (complexity as f64) * scale_factor;
 
-        writeln!(
-            file,
+        write!(
+            writer,
             "{},{},\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",{},\"{}\",\"{}\",{:.1},{},{},{}",
             csv_id,
             course.name,
@@ -394,7 +621,7 @@ pub fn export_metrics_csv_with_summary(
             prereqs,
             coreqs,
             strict_coreqs,
-            course.credit_hours,
+            csv_field(&course.credit_hours_for_export()),
             institution,
             course.canonical_name.as_deref().unwrap_or(""),
             scaled_complexity,
@@ -402,7 +629,23 @@ pub fn export_metrics_csv_with_summary(
             delay,
             centrality
         )?;
+        if let Some(transitive) = &transitive {
+            let upstream = transitive
+                .get(storage_key)
+                .map_or_else(String::new, |keys| {
+                    format_course_keys_as_csv(keys.iter(), school)
+                });
+            write!(writer, ",\"{upstream}\"")?;
+        }
+        if let Some(term_of) = &term_of {
+            let term = term_of
+                .get(storage_key.as_str())
+                .map_or_else(String::new, ToString::to_string);
+            write!(writer, ",{term}")?;
+        }
+        writeln!(writer)?;
     }
+    crate::logger::group_end();
 
     Ok(())
 }
@@ -412,13 +655,13 @@ pub fn export_metrics_csv_with_summary(
 /// Returns the computed summary statistics for further use
 ///
 /// # Errors
-/// Returns an error if file writing fails
+/// Returns [`ExportError::Io`] if the file cannot be created or written
 pub fn export_metrics_csv<P: AsRef<Path>>(
     school: &School,
     plan: &Plan,
     metrics: &CurriculumMetrics,
     output_path: P,
-) -> Result<CurriculumSummary, Box<dyn Error>> {
+) -> Result<CurriculumSummary, ExportError> {
     let dag = school.build_dag();
     let summary =
         CurriculumSummary::from_metrics(plan, school, metrics).with_delay_path(&dag, metrics);
@@ -426,6 +669,105 @@ pub fn export_metrics_csv<P: AsRef<Path>>(
     Ok(summary)
 }
 
+/// Split one entry of [`CurriculumSummary::longest_delay_path`] into course keys
+///
+/// Corequisite groups such as `(CS101+CS101L)` yield each member in order; a
+/// plain entry yields itself.
+#[must_use]
+pub fn path_step_courses(entry: &str) -> Vec<&str> {
+    let trimmed = entry.trim();
+    trimmed
+        .strip_prefix('(')
+        .and_then(|inner| inner.strip_suffix(')'))
+        .map_or_else(
+            || vec![trimmed],
+            |inner| inner.split('+').map(str::trim).collect(),
+        )
+}
+
+/// Version of the metrics JSON wire format (the `schema_version` field)
+///
+/// Bumped whenever a field is renamed, removed, or changes meaning, so
+/// consumers can reject output they do not understand. Adding a field does not
+/// bump the version. The format is documented in `docs/planner.md`.
+pub const METRICS_JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Render the longest delay path as a standalone JSON array
+///
+/// Each element is one step of the path, in order from the first prerequisite
+/// to the final course, with its `courses` listed individually (corequisite
+/// groups are expanded) alongside their names and metrics:
+///
+/// ```json
+/// [
+///   {"step": 1, "courses": [{"key": "CS101", "name": "Intro", "complexity": 9, "blocking": 5, "delay": 4, "centrality": 0}]}
+/// ]
+/// ```
+///
+/// Unknown courses get an empty name and zeroed metrics.
+#[must_use]
+pub fn delay_path_json(
+    summary: &CurriculumSummary,
+    school: &School,
+    metrics: &CurriculumMetrics,
+) -> String {
+    let steps: Vec<String> = summary
+        .longest_delay_path
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let courses: Vec<String> = path_step_courses(entry)
+                .into_iter()
+                .map(|key| {
+                    let name = school.get_course(key).map_or("", |c| c.name.as_str());
+                    let (complexity, blocking, delay, centrality) = school
+                        .course_metrics(metrics, key)
+                        .map_or((0, 0, 0, 0), CourseMetrics::as_export_tuple);
+                    format!(
+                        "{{\"key\": {}, \"name\": {}, \"complexity\": {complexity}, \"blocking\": {blocking}, \"delay\": {delay}, \"centrality\": {centrality}}}",
+                        json_string(key),
+                        json_string(name)
+                    )
+                })
+                .collect();
+            format!(
+                "  {{\"step\": {}, \"courses\": [{}]}}",
+                i + 1,
+                courses.join(", ")
+            )
+        })
+        .collect();
+
+    if steps.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", steps.join(",\n"))
+    }
+}
+
+/// Write the longest delay path JSON (see [`delay_path_json`]) to a file
+///
+/// # Errors
+/// Returns [`ExportError::Io`] if the file cannot be written
+pub fn export_delay_path_json<P: AsRef<Path>>(
+    summary: &CurriculumSummary,
+    school: &School,
+    metrics: &CurriculumMetrics,
+    output_path: P,
+) -> Result<(), ExportError> {
+    std::fs::write(output_path, delay_path_json(summary, school, metrics))?;
+    Ok(())
+}
+
+/// Quote a CSV field if it contains a comma, quote, or line break
+pub(crate) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 /// Format course storage keys as CSV with semicolons.
 ///
 /// # Arguments
@@ -451,10 +793,322 @@ fn format_course_keys_as_csv<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::approx::approx_eq;
     use crate::core::metrics;
     use crate::core::planner::parse_curriculum_csv;
     use std::fs;
 
+    #[test]
+    fn complexity_by_prefix_percentages_sum_and_sort() {
+        let school =
+            parse_curriculum_csv("samples/plans/Colostate_CSDegree.csv").expect("parse curriculum");
+        let plan = school.plans.first().expect("has at least one plan").clone();
+        let dag = school.build_dag();
+        let metrics_data = metrics::compute_all_metrics(&dag).expect("compute metrics");
+
+        let summary = CurriculumSummary::from_metrics(&plan, &school, &metrics_data);
+        let pct = &summary.complexity_by_prefix_pct;
+
+        assert!(!pct.is_empty());
+        let total: f32 = pct.iter().map(|(_, p)| p).sum();
+        assert!(
+            approx_eq(f64::from(total), 100.0, 0.01),
+            "percentages sum to {total}"
+        );
+        assert!(pct.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert_eq!(pct[0].0, "CS");
+    }
+
+    #[test]
+    fn variable_credit_string_round_trips() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let input = dir.path().join("variable.csv");
+        fs::write(
+            &input,
+            "Curriculum,Variable Credits\nInstitution,Test U\nDegree Type,BS\nSystem Type,semester\nCIP,11.0701\nCourses\n\
+Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours,Institution,Canonical Name\n\
+1,Research,CS,4990,,,,1-3,,\n2,Intro,CS,1010,,,,3,,\n",
+        )
+        .expect("write csv");
+
+        let school = parse_curriculum_csv(&input).expect("parse curriculum");
+        let course = school.get_course("CS4990").expect("course exists");
+        assert_eq!(course.raw_credit_hours.as_deref(), Some("1-3"));
+
+        let plan = school.plans.first().expect("plan").clone();
+        let dag = school.build_dag();
+        let metrics_data = metrics::compute_all_metrics(&dag).expect("compute metrics");
+        let output = dir.path().join("out.csv");
+        export_metrics_csv(&school, &plan, &metrics_data, &output).expect("export");
+
+        let contents = fs::read_to_string(&output).expect("read export");
+        assert!(contents.contains("\"CS\",\"4990\",\"\",\"\",\"\",1-3,"));
+        assert!(contents.contains("\"CS\",\"1010\",\"\",\"\",\"\",3,"));
+    }
+
+    #[test]
+    fn credit_string_with_comma_is_quoted() {
+        let mut school = School::new("Test U".to_string());
+        let mut course = Course::new(
+            "Research".to_string(),
+            "CS".to_string(),
+            "4990".to_string(),
+            0.0,
+        );
+        course.raw_credit_hours = Some("1,3".to_string());
+        course.csv_id = Some("1".to_string());
+        school.add_course(course);
+        let mut plan = Plan::new("Plan".to_string(), "BS CS".to_string());
+        plan.add_course("CS4990".to_string());
+        let metrics_data = metrics::compute_all_metrics(&school.build_dag()).expect("metrics");
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let output = dir.path().join("out.csv");
+        export_metrics_csv(&school, &plan, &metrics_data, &output).expect("export");
+
+        let contents = fs::read_to_string(&output).expect("read export");
+        let row = contents
+            .lines()
+            .find(|l| l.starts_with("1,"))
+            .expect("course row");
+        assert!(row.contains("\"\",\"1,3\",\"Test U\""));
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn exports_in_term_order_with_schedule() {
+        let school =
+            parse_curriculum_csv("samples/plans/Colostate_CSDegree.csv").expect("parse curriculum");
+        let plan = school.plans.first().expect("has at least one plan").clone();
+        let dag = school.build_dag();
+        let metrics_data = metrics::compute_all_metrics(&dag).expect("compute metrics");
+        let summary = CurriculumSummary::from_metrics(&plan, &school, &metrics_data);
+
+        // Schedule in reverse key order so term order differs from CSV ID order
+        let mut keys = plan.courses.clone();
+        keys.sort();
+        keys.reverse();
+        let mut term_plan = TermPlan::new(2, false, 15.0);
+        let (late, early) = keys.split_at(keys.len() / 2);
+        for key in early {
+            term_plan.terms[0].add_course(key.clone(), 3.0);
+        }
+        for key in late {
+            term_plan.terms[1].add_course(key.clone(), 3.0);
+        }
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let output = dir.path().join("by_term.csv");
+        export_metrics_csv_with_options(
+            &school,
+            &plan,
+            &metrics_data,
+            &summary,
+            &ExportOptions::default().with_term_plan(&term_plan),
+            &output,
+        )
+        .expect("export");
+
+        let contents = fs::read_to_string(&output).expect("read export");
+        let mut lines = contents.lines().skip_while(|l| *l != "Courses").skip(1);
+        assert!(lines.next().expect("header").ends_with(",Term"));
+
+        let terms: Vec<usize> = lines
+            .map(|l| l.rsplit(',').next().unwrap().parse().expect("term number"))
+            .collect();
+        assert_eq!(terms.len(), plan.courses.len());
+        assert!(terms.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(terms.first(), Some(&1));
+        assert_eq!(terms.last(), Some(&2));
+    }
+
+    #[test]
+    fn exports_transitive_prerequisites_column() {
+        let mut school = School::new("Test U".to_string());
+        for (id, number, prereq) in [
+            ("1", "101", None),
+            ("2", "201", Some("CS101")),
+            ("3", "301", Some("CS201")),
+        ] {
+            let mut course = Course::new(
+                format!("Course {number}"),
+                "CS".to_string(),
+                number.to_string(),
+                3.0,
+            );
+            course.csv_id = Some(id.to_string());
+            if let Some(prereq) = prereq {
+                course.add_prerequisite(prereq.to_string());
+            }
+            school.add_course(course);
+        }
+        let mut plan = Plan::new("Chain".to_string(), "BS CS".to_string());
+        for key in ["CS101", "CS201", "CS301"] {
+            plan.add_course(key.to_string());
+        }
+
+        let metrics_data = metrics::compute_all_metrics(&school.build_dag()).expect("metrics");
+        let summary = CurriculumSummary::from_metrics(&plan, &school, &metrics_data);
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let output = dir.path().join("transitive.csv");
+        export_metrics_csv_with_options(
+            &school,
+            &plan,
+            &metrics_data,
+            &summary,
+            &ExportOptions::default().with_transitive_prerequisites(true),
+            &output,
+        )
+        .expect("export");
+
+        let contents = fs::read_to_string(&output).expect("read export");
+        let mut lines = contents.lines().skip_while(|l| *l != "Courses").skip(1);
+        assert!(lines
+            .next()
+            .expect("header")
+            .ends_with(",Transitive Prerequisites"));
+        let last = lines.find(|l| l.starts_with("3,")).expect("CS301 row");
+        assert!(last.ends_with(",\"1;2\""), "unexpected row: {last}");
+
+        // Default export keeps the original format
+        export_metrics_csv_with_summary(&school, &plan, &metrics_data, &summary, &output)
+            .expect("export");
+        let contents = fs::read_to_string(&output).expect("read export");
+        assert!(!contents.contains("Transitive Prerequisites"));
+    }
+
+    #[test]
+    fn orders_non_numeric_csv_ids_by_key() {
+        let mut school = School::new("Test U".to_string());
+        for (id, prefix, number) in [("b", "MATH", "101"), ("c", "CS", "301"), ("a", "CS", "101")] {
+            let mut course = Course::new(
+                format!("{prefix} {number}"),
+                prefix.to_string(),
+                number.to_string(),
+                3.0,
+            );
+            course.csv_id = Some(id.to_string());
+            school.add_course(course);
+        }
+        let metrics_data = metrics::compute_all_metrics(&school.build_dag()).expect("metrics");
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let output = dir.path().join("keys.csv");
+        let mut orders = Vec::new();
+        for plan_order in [["MATH101", "CS301", "CS101"], ["CS101", "MATH101", "CS301"]] {
+            let mut plan = Plan::new("Keys".to_string(), "BS CS".to_string());
+            for key in plan_order {
+                plan.add_course(key.to_string());
+            }
+            export_metrics_csv(&school, &plan, &metrics_data, &output).expect("export");
+            let contents = fs::read_to_string(&output).expect("read export");
+            let ids: Vec<String> = contents
+                .lines()
+                .skip_while(|l| *l != "Courses")
+                .skip(2)
+                .map(|l| l.split(',').next().unwrap().to_string())
+                .collect();
+            orders.push(ids);
+        }
+
+        assert_eq!(orders[0], vec!["a", "c", "b"]);
+        assert_eq!(orders[0], orders[1]);
+    }
+
+    #[test]
+    fn plan_alias_keys_get_canonical_metrics() {
+        let mut school = School::new("Test U".to_string());
+        let mut cs150 = Course::new(
+            "Discrete".to_string(),
+            "CS".to_string(),
+            "150".to_string(),
+            4.0,
+        );
+        cs150.add_prerequisite("MATH100".to_string());
+        let mut math150 = Course::new(
+            "Discrete".to_string(),
+            "MATH".to_string(),
+            "150".to_string(),
+            4.0,
+        );
+        math150.set_equivalent("CS150".to_string());
+        let mut cs250 = Course::new(
+            "Algorithms".to_string(),
+            "CS".to_string(),
+            "250".to_string(),
+            3.0,
+        );
+        cs250.add_prerequisite("MATH150".to_string());
+        let math100 = Course::new(
+            "Precalc".to_string(),
+            "MATH".to_string(),
+            "100".to_string(),
+            3.0,
+        );
+        for (id, course) in [("1", math100), ("2", cs150), ("3", math150), ("4", cs250)] {
+            let mut course = course;
+            course.csv_id = Some(id.to_string());
+            school.add_course(course);
+        }
+
+        // The plan names the cross-listed alias, which has no DAG node of its own
+        let mut plan = Plan::new("Alias".to_string(), "BS CS".to_string());
+        for key in ["MATH100", "MATH150", "CS250"] {
+            plan.add_course(key.to_string());
+        }
+        let dag = school.build_dag();
+        let metrics_data = metrics::compute_all_metrics(&dag).expect("metrics");
+        let canonical = metrics_data
+            .get("CS150")
+            .expect("canonical metrics")
+            .clone();
+        assert!(!metrics_data.contains_key("MATH150"));
+        assert!(canonical.complexity > 0);
+
+        let rows = metrics::summary_rows(&plan, &school, &metrics_data, MetricsSort::default());
+        let alias_row = rows.iter().find(|r| r.key == "MATH150").expect("alias row");
+        assert_eq!(alias_row.metrics, canonical);
+
+        let summary = CurriculumSummary::from_metrics(&plan, &school, &metrics_data);
+        let expected: usize = metrics_data.values().map(|m| m.complexity).sum();
+        assert_eq!(summary.total_complexity, expected);
+
+        let mut buffer = Vec::new();
+        write_metrics_csv(
+            &mut buffer,
+            &school,
+            &plan,
+            &metrics_data,
+            &summary,
+            &ExportOptions::default(),
+        )
+        .expect("write csv");
+        let csv = String::from_utf8(buffer).expect("utf-8 csv");
+        let alias_line = csv
+            .lines()
+            .find(|l| l.starts_with("3,"))
+            .expect("alias course row");
+        assert!(alias_line.ends_with(&format!(
+            ",{}.0,{},{},{}",
+            canonical.complexity, canonical.blocking, canonical.delay, canonical.centrality
+        )));
+    }
+
+    #[test]
+    fn export_to_unwritable_path_is_io_error() {
+        let school =
+            parse_curriculum_csv("samples/plans/Colostate_CSDegree.csv").expect("parse curriculum");
+        let plan = school.plans.first().expect("has at least one plan").clone();
+        let dag = school.build_dag();
+        let metrics_data = metrics::compute_all_metrics(&dag).expect("compute metrics");
+
+        let output_path = "/nonexistent_dir_for_export_test/metrics.csv";
+        let result = export_metrics_csv(&school, &plan, &metrics_data, output_path);
+
+        assert!(matches!(result, Err(ExportError::Io(_))));
+    }
+
     #[test]
     fn exports_metrics_csv() {
         let school =
@@ -482,6 +1136,36 @@ mod tests {
         fs::remove_file(output_path).ok();
     }
 
+    #[test]
+    fn writes_metrics_csv_to_buffer_like_file() {
+        let school =
+            parse_curriculum_csv("samples/plans/Colostate_CSDegree.csv").expect("parse curriculum");
+        let plan = school.plans.first().expect("has at least one plan").clone();
+        let dag = school.build_dag();
+        let metrics_data = metrics::compute_all_metrics(&dag).expect("compute metrics");
+        let summary = CurriculumSummary::from_metrics(&plan, &school, &metrics_data)
+            .with_delay_path(&dag, &metrics_data);
+
+        let mut buffer = Vec::new();
+        write_metrics_csv(
+            &mut buffer,
+            &school,
+            &plan,
+            &metrics_data,
+            &summary,
+            &ExportOptions::default(),
+        )
+        .expect("write to buffer");
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let output_path = dir.path().join("metrics.csv");
+        export_metrics_csv_with_summary(&school, &plan, &metrics_data, &summary, &output_path)
+            .expect("export metrics");
+
+        let from_file = fs::read_to_string(&output_path).expect("read file");
+        assert_eq!(String::from_utf8(buffer).expect("utf-8 csv"), from_file);
+    }
+
     #[test]
     fn computes_curriculum_summary() {
         let school =
@@ -575,6 +1259,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn delay_path_json_expands_corequisite_steps() {
+        let mut school = School::new("Test".to_string());
+        let mut add = |number: &str, name: &str, prereq: Option<&str>, coreq: Option<&str>| {
+            let mut course =
+                Course::new(name.to_string(), "CS".to_string(), number.to_string(), 3.0);
+            if let Some(p) = prereq {
+                course.add_prerequisite(p.to_string());
+            }
+            if let Some(c) = coreq {
+                course.add_corequisite(c.to_string());
+            }
+            school.add_course(course);
+        };
+        add("101", "Intro", None, Some("CS101L"));
+        add("101L", "Intro \"Lab\"", None, None);
+        add("201", "Data Structures", Some("CS101"), None);
+        add("301", "Algorithms", Some("CS201"), None);
+        let mut plan = Plan::new("Plan".to_string(), String::new());
+        for key in ["CS101", "CS101L", "CS201", "CS301"] {
+            plan.add_course(key.to_string());
+        }
+        let dag = school.build_dag();
+        let metrics_data = metrics::compute_all_metrics(&dag).expect("compute metrics");
+        let summary = CurriculumSummary::from_metrics(&plan, &school, &metrics_data)
+            .with_delay_path(&dag, &metrics_data);
+        assert_eq!(
+            summary.longest_delay_path,
+            vec!["(CS101+CS101L)", "CS201", "CS301"]
+        );
+
+        let json = delay_path_json(&summary, &school, &metrics_data);
+        let lines: Vec<&str> = json.lines().collect();
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines[1]
+            .starts_with("  {\"step\": 1, \"courses\": [{\"key\": \"CS101\", \"name\": \"Intro\""));
+        assert!(lines[1].contains("{\"key\": \"CS101L\", \"name\": \"Intro \\\"Lab\\\"\""));
+        assert!(
+            lines[2].contains("\"step\": 2") && lines[2].contains("\"name\": \"Data Structures\"")
+        );
+        let cs301 = &metrics_data["CS301"];
+        assert!(lines[3].contains(&format!(
+            "{{\"key\": \"CS301\", \"name\": \"Algorithms\", \"complexity\": {}, \"blocking\": {}, \"delay\": {}, \"centrality\": {}}}",
+            cs301.complexity, cs301.blocking, cs301.delay, cs301.centrality
+        )));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("delay_path.json");
+        export_delay_path_json(&summary, &school, &metrics_data, &path).expect("export");
+        assert_eq!(fs::read_to_string(&path).unwrap(), json);
+    }
+
     #[test]
     fn csv_contains_delay_path() {
         let school =
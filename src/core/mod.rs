@@ -1,11 +1,18 @@
 //! Core module for common functionality across all targets
 
+pub mod approx;
+#[cfg(feature = "cache")]
+pub mod cache;
 pub mod config;
+mod hash;
 pub mod metrics;
 pub mod metrics_export;
 pub mod models;
 pub mod planner;
 pub mod report;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod synthetic;
 
 // Add core domain modules here as they're developed:
 // pub mod degree;
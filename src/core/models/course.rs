@@ -2,6 +2,106 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Policy for joining a course prefix and number into a course key
+///
+/// Institutions differ in how they write course identifiers. The policy is chosen
+/// at parse time so stored keys match the institution's own convention, which keeps
+/// cross-referencing against external catalogs straightforward.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyPolicy {
+    /// Prefix and number joined directly (e.g., "CS1800")
+    #[default]
+    Concatenated,
+    /// Prefix and number separated by a space (e.g., "CS 1800")
+    Spaced,
+    /// Prefix and number separated by a hyphen (e.g., "CS-1800")
+    Hyphenated,
+}
+
+impl KeyPolicy {
+    /// Get the separator placed between prefix and number
+    #[must_use]
+    pub const fn separator(self) -> &'static str {
+        match self {
+            Self::Concatenated => "",
+            Self::Spaced => " ",
+            Self::Hyphenated => "-",
+        }
+    }
+
+    /// Format a prefix and number into a key following this policy
+    ///
+    /// # Arguments
+    /// * `prefix` - Course prefix (e.g., "CS")
+    /// * `number` - Course number (e.g., "1800")
+    #[must_use]
+    pub fn format(self, prefix: &str, number: &str) -> String {
+        format!("{prefix}{}{number}", self.separator())
+    }
+}
+
+/// Half of the academic year a term falls in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TermParity {
+    /// Fall term
+    Fall,
+    /// Spring term
+    Spring,
+}
+
+impl TermParity {
+    /// The other parity
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Fall => Self::Spring,
+            Self::Spring => Self::Fall,
+        }
+    }
+
+    /// Parity of the 0-based term `index` for a student starting in `self`
+    ///
+    /// Terms alternate fall and spring; summer terms are not modelled.
+    #[must_use]
+    pub const fn at(self, index: usize) -> Self {
+        if index.is_multiple_of(2) {
+            self
+        } else {
+            self.next()
+        }
+    }
+}
+
+/// Terms in which a course is offered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TermOffering {
+    /// Offered every term
+    #[default]
+    Every,
+    /// Offered in fall terms only
+    Fall,
+    /// Offered in spring terms only
+    Spring,
+}
+
+impl TermOffering {
+    /// Whether the course runs in a term of the given parity
+    #[must_use]
+    pub const fn is_offered_in(self, parity: TermParity) -> bool {
+        matches!(
+            (self, parity),
+            (Self::Every, _) | (Self::Fall, TermParity::Fall) | (Self::Spring, TermParity::Spring)
+        )
+    }
+
+    /// Whether some term offers both courses
+    #[must_use]
+    pub const fn overlaps(self, other: Self) -> bool {
+        (self.is_offered_in(TermParity::Fall) && other.is_offered_in(TermParity::Fall))
+            || (self.is_offered_in(TermParity::Spring) && other.is_offered_in(TermParity::Spring))
+    }
+}
+
 /// Represents a course in a curriculum
 ///
 /// # Note on Complex Prerequisites
@@ -60,9 +160,17 @@ pub struct Course {
     pub number: String,
 
     /// Prerequisites - stored as "PREFIX NUMBER" keys (e.g., "MATH 1341")
-    /// Currently assumes ALL prerequisites must be satisfied (AND semantics)
+    /// All of these must be satisfied (AND semantics); see `prerequisite_groups` for alternatives
     pub prerequisites: Vec<String>,
 
+    /// Alternative prerequisite groups (OR semantics within a group)
+    ///
+    /// Each group is satisfied by any one of its course keys, and every group must
+    /// be satisfied alongside `prerequisites` (e.g., `[["MATH101", "MATH110"]]`
+    /// means "MATH101 or MATH110").
+    #[serde(default)]
+    pub prerequisite_groups: Vec<Vec<String>>,
+
     /// Co-requisites - stored as "PREFIX NUMBER" keys
     pub corequisites: Vec<String>,
 
@@ -72,8 +180,51 @@ pub struct Course {
     /// Credit hours (can be fractional)
     pub credit_hours: f32,
 
+    /// Credit hours exactly as written in the source file (e.g., "1-3")
+    ///
+    /// Kept so exports can reproduce the original value and so a parse failure
+    /// can be told apart from a genuine 0.
+    #[serde(default)]
+    pub raw_credit_hours: Option<String>,
+
     /// Canonical name for cross-institution lookup (e.g., "Calculus I")
     pub canonical_name: Option<String>,
+
+    /// Free-text advising notes (e.g., "Offered alternate years")
+    #[serde(default)]
+    pub notes: Option<String>,
+
+    /// Requirement category the course counts toward (e.g., "Core", "Math")
+    #[serde(default)]
+    pub category: Option<String>,
+
+    /// Key of the course this one is cross-listed with (e.g., `MATH150` → `CS150`)
+    ///
+    /// Cross-listed courses are collapsed onto the referenced course when
+    /// building the DAG and summing credits.
+    #[serde(default)]
+    pub equivalent_to: Option<String>,
+
+    /// Policy used to build this course's key
+    #[serde(default)]
+    pub key_policy: KeyPolicy,
+
+    /// Terms in which the course is offered
+    #[serde(default)]
+    pub offered: TermOffering,
+
+    /// Term (1-indexed) the source plan places the course in, if it has a `Term` column
+    #[serde(default)]
+    pub term: Option<usize>,
+
+    /// Whether the course is offered this catalog cycle (false while suspended)
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+/// Serde default for [`Course::active`]: courses are offered unless marked otherwise
+const fn default_active() -> bool {
+    true
 }
 
 impl Course {
@@ -93,20 +244,48 @@ impl Course {
             prefix,
             number,
             prerequisites: Vec::new(),
+            prerequisite_groups: Vec::new(),
             corequisites: Vec::new(),
             strict_corequisites: Vec::new(),
             credit_hours,
+            raw_credit_hours: None,
             canonical_name: None,
+            notes: None,
+            category: None,
+            equivalent_to: None,
+            key_policy: KeyPolicy::Concatenated,
+            offered: TermOffering::Every,
+            term: None,
+            active: true,
         }
     }
 
     /// Get the course key for lookups (prefix + number)
     ///
     /// # Returns
-    /// A string in the format "PREFIXNUMBER" (e.g., "CS2510")
+    /// A string formatted according to the course's [`KeyPolicy`]
+    /// (e.g., "CS2510" for the default concatenated policy)
     #[must_use]
     pub fn key(&self) -> String {
-        format!("{}{}", self.prefix, self.number)
+        self.key_policy.format(&self.prefix, &self.number)
+    }
+
+    /// Whether `query` appears in the course's key, name, or canonical name
+    ///
+    /// Case-insensitive substring match; the key is also tried as
+    /// `PREFIX NUMBER` so "cs 25" finds CS2510.
+    #[must_use]
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        [
+            Some(self.key()),
+            Some(format!("{} {}", self.prefix, self.number)),
+            Some(self.name.clone()),
+            self.canonical_name.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|field| field.to_lowercase().contains(&query))
     }
 
     /// Add a prerequisite by course key
@@ -116,6 +295,19 @@ impl Course {
         }
     }
 
+    /// Add a group of alternative prerequisites, any one of which satisfies it
+    ///
+    /// A single-member group is stored as a plain prerequisite instead.
+    pub fn add_prerequisite_group(&mut self, mut alternatives: Vec<String>) {
+        if alternatives.len() == 1 {
+            if let Some(only) = alternatives.pop() {
+                self.add_prerequisite(only);
+            }
+        } else if !alternatives.is_empty() && !self.prerequisite_groups.contains(&alternatives) {
+            self.prerequisite_groups.push(alternatives);
+        }
+    }
+
     /// Add a co-requisite by course key
     pub fn add_corequisite(&mut self, coreq_key: String) {
         if !self.corequisites.contains(&coreq_key) {
@@ -130,15 +322,85 @@ impl Course {
         }
     }
 
+    /// Restrict the terms in which the course is offered
+    pub const fn set_offered(&mut self, offered: TermOffering) {
+        self.offered = offered;
+    }
+
+    /// Set the term (1-indexed) the source plan places the course in
+    pub const fn set_term(&mut self, term: usize) {
+        self.term = Some(term);
+    }
+
+    /// Mark whether the course is offered this catalog cycle
+    pub const fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+
     /// Set the canonical name
     pub fn set_canonical_name(&mut self, name: String) {
         self.canonical_name = Some(name);
     }
+
+    /// Set the advising notes
+    pub fn set_notes(&mut self, notes: String) {
+        self.notes = Some(notes);
+    }
+
+    /// Set the requirement category the course counts toward
+    pub fn set_category(&mut self, category: String) {
+        self.category = Some(category);
+    }
+
+    /// Mark this course as cross-listed with (equivalent to) another course key
+    pub fn set_equivalent(&mut self, course_key: String) {
+        self.equivalent_to = Some(course_key);
+    }
+
+    /// Course level, bucketed by the leading digit of the number
+    ///
+    /// The leading digit is scaled by the length of the leading digit run, so
+    /// `"101"` is 100-level, `"1800"` is 1000-level, and `"2510"` is 2000-level.
+    /// Suffixes after the digits are ignored (`"101L"` is 100-level).
+    ///
+    /// # Returns
+    /// `None` when the number does not start with a digit (or is too long to bucket)
+    #[must_use]
+    pub fn level(&self) -> Option<u32> {
+        let digits: Vec<u32> = self
+            .number
+            .trim()
+            .chars()
+            .map_while(|c| c.to_digit(10))
+            .collect();
+        let (&first, rest) = digits.split_first()?;
+        let scale = u32::try_from(rest.len())
+            .ok()
+            .and_then(|n| 10u32.checked_pow(n))?;
+        Some(first * scale)
+    }
+
+    /// Credit hours formatted for export
+    ///
+    /// Returns the original source string when `credit_hours` still matches what
+    /// was parsed from it, otherwise the numeric value.
+    #[must_use]
+    pub fn credit_hours_for_export(&self) -> String {
+        match &self.raw_credit_hours {
+            Some(raw)
+                if raw.parse::<f32>().unwrap_or(0.0).to_bits() == self.credit_hours.to_bits() =>
+            {
+                raw.clone()
+            }
+            _ => self.credit_hours.to_string(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::approx::credits_eq;
 
     #[test]
     fn test_course_creation() {
@@ -152,12 +414,30 @@ mod tests {
         assert_eq!(course.name, "Discrete Structures");
         assert_eq!(course.prefix, "CS");
         assert_eq!(course.number, "1800");
-        assert!((course.credit_hours - 4.0).abs() < f32::EPSILON);
+        assert!(credits_eq(course.credit_hours, 4.0));
         assert!(course.prerequisites.is_empty());
         assert!(course.corequisites.is_empty());
         assert!(course.canonical_name.is_none());
     }
 
+    #[test]
+    fn test_credit_hours_for_export() {
+        let mut course = Course::new(
+            "Seminar".to_string(),
+            "CS".to_string(),
+            "4990".to_string(),
+            0.0,
+        );
+        assert_eq!(course.credit_hours_for_export(), "0");
+
+        course.raw_credit_hours = Some("1-3".to_string());
+        assert_eq!(course.credit_hours_for_export(), "1-3");
+
+        // Once the value is changed, the raw string no longer applies
+        course.credit_hours = 2.0;
+        assert_eq!(course.credit_hours_for_export(), "2");
+    }
+
     #[test]
     fn test_course_key() {
         let course = Course::new(
@@ -170,6 +450,40 @@ mod tests {
         assert_eq!(course.key(), "CS2510");
     }
 
+    #[test]
+    fn test_course_key_policies() {
+        let mut course = Course::new(
+            "Data Structures".to_string(),
+            "CS".to_string(),
+            "2510".to_string(),
+            4.0,
+        );
+
+        assert_eq!(course.key_policy, KeyPolicy::Concatenated);
+
+        course.key_policy = KeyPolicy::Spaced;
+        assert_eq!(course.key(), "CS 2510");
+
+        course.key_policy = KeyPolicy::Hyphenated;
+        assert_eq!(course.key(), "CS-2510");
+    }
+
+    #[test]
+    fn test_course_level() {
+        let level = |number: &str| {
+            Course::new(String::new(), "CS".to_string(), number.to_string(), 3.0).level()
+        };
+
+        assert_eq!(level("101"), Some(100));
+        assert_eq!(level("350"), Some(300));
+        assert_eq!(level("1800"), Some(1000));
+        assert_eq!(level("2510"), Some(2000));
+        assert_eq!(level("101L"), Some(100));
+        assert_eq!(level("150B"), Some(100));
+        assert_eq!(level("L101"), None);
+        assert_eq!(level(""), None);
+    }
+
     #[test]
     fn test_fractional_credits() {
         let course = Course::new(
@@ -179,7 +493,7 @@ mod tests {
             1.5,
         );
 
-        assert!((course.credit_hours - 1.5).abs() < f32::EPSILON);
+        assert!(credits_eq(course.credit_hours, 1.5));
     }
 
     #[test]
@@ -228,4 +542,21 @@ mod tests {
         course.set_canonical_name("Calculus I".to_string());
         assert_eq!(course.canonical_name, Some("Calculus I".to_string()));
     }
+
+    #[test]
+    fn test_matches_key_and_names_case_insensitively() {
+        let mut course = Course::new(
+            "Calculus for Physical Scientists I".to_string(),
+            "MATH".to_string(),
+            "1342".to_string(),
+            4.0,
+        );
+        course.set_canonical_name("Calculus I".to_string());
+
+        assert!(course.matches("physical sci"));
+        assert!(course.matches("math13"));
+        assert!(course.matches("Math 1342"));
+        assert!(course.matches("calculus i"));
+        assert!(!course.matches("CS1342"));
+    }
 }
@@ -1,6 +1,6 @@
 //! Directed Acyclic Graph for course prerequisites
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Represents a directed acyclic graph of course prerequisites
 ///
@@ -91,6 +91,12 @@ pub struct DAG {
     /// Maps course key -> list of courses that list it as a corequisite
     pub coreq_dependents: HashMap<String, Vec<String>>,
 
+    /// Maps course key -> list of its corequisites that are strict
+    ///
+    /// Strict corequisite edges also appear in `corequisites`; this map records
+    /// which of them are strict so metrics can treat them differently.
+    pub strict_corequisites: HashMap<String, Vec<String>>,
+
     /// All course keys in the DAG
     pub courses: Vec<String>,
 }
@@ -104,6 +110,7 @@ impl DAG {
             corequisites: HashMap::new(),
             dependents: HashMap::new(),
             coreq_dependents: HashMap::new(),
+            strict_corequisites: HashMap::new(),
             courses: Vec::new(),
         }
     }
@@ -172,6 +179,97 @@ impl DAG {
         }
     }
 
+    /// Add a strict corequisite relationship
+    ///
+    /// The edge is added as a regular corequisite and also recorded as strict.
+    ///
+    /// # Arguments
+    /// * `course_key` - Course that lists the strict corequisite
+    /// * `coreq_key` - Course that must be taken in the same term
+    pub fn add_strict_corequisite(&mut self, course_key: String, coreq_key: &str) {
+        self.add_corequisite(course_key.clone(), coreq_key);
+
+        let strict = self.strict_corequisites.entry(course_key).or_default();
+        if !strict.iter().any(|c| c == coreq_key) {
+            strict.push(coreq_key.to_string());
+        }
+    }
+
+    /// Check whether `coreq_key` is a strict corequisite of `course_key`
+    #[must_use]
+    pub fn is_strict_corequisite(&self, course_key: &str, coreq_key: &str) -> bool {
+        self.strict_corequisites
+            .get(course_key)
+            .is_some_and(|strict| strict.iter().any(|c| c == coreq_key))
+    }
+
+    /// Return a copy of this DAG with all strict corequisite edges removed
+    #[must_use]
+    pub fn without_strict_corequisites(&self) -> Self {
+        let mut dag = self.clone();
+        for (course, strict) in &self.strict_corequisites {
+            if let Some(coreqs) = dag.corequisites.get_mut(course) {
+                coreqs.retain(|c| !strict.contains(c));
+            }
+            for coreq in strict {
+                if let Some(parents) = dag.coreq_dependents.get_mut(coreq) {
+                    parents.retain(|p| p != course);
+                }
+            }
+        }
+        dag.strict_corequisites.clear();
+        dag
+    }
+
+    /// Return the subgraph induced by `keep`
+    ///
+    /// Only courses in `keep` are retained, along with the edges whose both
+    /// endpoints are retained. Course order follows this DAG.
+    #[must_use]
+    pub fn induced_subgraph(&self, keep: &HashSet<&str>) -> Self {
+        let mut dag = Self::new();
+        let kept = self.courses.iter().filter(|c| keep.contains(c.as_str()));
+        for course in kept.clone() {
+            dag.add_course(course.clone());
+        }
+        for course in kept {
+            for prereq in self.dependencies.get(course).into_iter().flatten() {
+                if keep.contains(prereq.as_str()) {
+                    dag.add_prerequisite(course.clone(), prereq);
+                }
+            }
+            for coreq in self.corequisites.get(course).into_iter().flatten() {
+                if !keep.contains(coreq.as_str()) {
+                    continue;
+                }
+                if self.is_strict_corequisite(course, coreq) {
+                    dag.add_strict_corequisite(course.clone(), coreq);
+                } else {
+                    dag.add_corequisite(course.clone(), coreq);
+                }
+            }
+        }
+        dag
+    }
+
+    /// Every course that has `course_key` somewhere in its requisite chain
+    ///
+    /// Follows dependent and corequisite-dependent edges. The course itself is
+    /// excluded; an unknown course has no descendants.
+    #[must_use]
+    pub fn descendants(&self, course_key: &str) -> HashSet<String> {
+        reachable(course_key, &self.dependents, &self.coreq_dependents)
+    }
+
+    /// Every course upstream of `course_key` in its requisite chain
+    ///
+    /// Follows prerequisite and corequisite edges. The course itself is
+    /// excluded; an unknown course has no ancestors.
+    #[must_use]
+    pub fn ancestors(&self, course_key: &str) -> HashSet<String> {
+        reachable(course_key, &self.dependencies, &self.corequisites)
+    }
+
     /// Get all prerequisites for a course
     ///
     /// # Arguments
@@ -219,6 +317,55 @@ impl DAG {
     pub fn contains_course(&self, course_key: &str) -> bool {
         self.courses.contains(&course_key.to_string())
     }
+
+    /// Get the number of nodes (courses) in the DAG
+    #[must_use]
+    pub const fn node_count(&self) -> usize {
+        self.courses.len()
+    }
+
+    /// Get the number of edges (prerequisite plus corequisite links)
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.dependencies.values().map(Vec::len).sum::<usize>()
+            + self.corequisites.values().map(Vec::len).sum::<usize>()
+    }
+
+    /// Summarize the graph's size and densest nodes
+    ///
+    /// Edges point from a requisite to the course that requires it, so a
+    /// course's in-degree counts its requisites and its out-degree counts the
+    /// courses it unlocks.
+    #[must_use]
+    pub fn stats(&self) -> DagStats {
+        let degree = |map: &HashMap<String, Vec<String>>, other: &HashMap<String, Vec<String>>| {
+            self.courses
+                .iter()
+                .map(|c| map.get(c).map_or(0, Vec::len) + other.get(c).map_or(0, Vec::len))
+                .max()
+                .unwrap_or(0)
+        };
+
+        DagStats {
+            nodes: self.node_count(),
+            edges: self.edge_count(),
+            max_in_degree: degree(&self.dependencies, &self.corequisites),
+            max_out_degree: degree(&self.dependents, &self.coreq_dependents),
+        }
+    }
+}
+
+/// Graph-level counts for a [`DAG`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DagStats {
+    /// Number of courses
+    pub nodes: usize,
+    /// Number of prerequisite and corequisite edges
+    pub edges: usize,
+    /// Most requisites listed by any one course
+    pub max_in_degree: usize,
+    /// Most courses directly unlocked by any one course
+    pub max_out_degree: usize,
 }
 
 impl Default for DAG {
@@ -263,6 +410,38 @@ impl std::fmt::Display for DAG {
     }
 }
 
+/// Breadth-first search from `start` over the union of two edge maps
+///
+/// # Returns
+/// The set of reachable courses (excluding `start` itself)
+fn reachable(
+    start: &str,
+    primary: &HashMap<String, Vec<String>>,
+    secondary: &HashMap<String, Vec<String>>,
+) -> HashSet<String> {
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    visited.insert(start.to_string());
+    queue.push_back(start.to_string());
+
+    while let Some(course) = queue.pop_front() {
+        let neighbors = primary
+            .get(&course)
+            .into_iter()
+            .chain(secondary.get(&course))
+            .flatten();
+        for neighbor in neighbors {
+            if visited.insert(neighbor.clone()) {
+                queue.push_back(neighbor.clone());
+            }
+        }
+    }
+
+    visited.remove(start);
+    visited
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,6 +452,29 @@ mod tests {
         assert_eq!(dag.course_count(), 0);
     }
 
+    #[test]
+    fn test_edge_count_and_stats() {
+        let mut dag = DAG::new();
+        dag.add_prerequisite("CS201".to_string(), "CS101");
+        dag.add_prerequisite("CS202".to_string(), "CS101");
+        dag.add_prerequisite("CS301".to_string(), "CS201");
+        dag.add_prerequisite("CS301".to_string(), "MATH101");
+        dag.add_corequisite("CS301".to_string(), "CS301L");
+
+        assert_eq!(dag.node_count(), 6);
+        assert_eq!(dag.edge_count(), 5);
+        assert_eq!(
+            dag.stats(),
+            DagStats {
+                nodes: 6,
+                edges: 5,
+                max_in_degree: 3,
+                max_out_degree: 2,
+            }
+        );
+        assert_eq!(DAG::new().stats().max_in_degree, 0);
+    }
+
     #[test]
     fn test_add_course() {
         let mut dag = DAG::new();
@@ -321,6 +523,44 @@ mod tests {
         assert_eq!(cs220_deps.len(), 1); // Should not duplicate
     }
 
+    #[test]
+    fn test_strict_corequisites_tracked_separately() {
+        let mut dag = DAG::new();
+        dag.add_corequisite("CHEM108".to_string(), "CHEM107");
+        dag.add_strict_corequisite("CS101L".to_string(), "CS101");
+
+        assert!(dag.is_strict_corequisite("CS101L", "CS101"));
+        assert!(!dag.is_strict_corequisite("CHEM108", "CHEM107"));
+        assert!(dag
+            .get_corequisites("CS101L")
+            .unwrap()
+            .contains(&"CS101".to_string()));
+
+        let relaxed = dag.without_strict_corequisites();
+        assert!(relaxed.get_corequisites("CS101L").unwrap().is_empty());
+        assert!(relaxed.get_coreq_dependents("CS101").unwrap().is_empty());
+        assert_eq!(relaxed.get_corequisites("CHEM108").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_descendants_and_ancestors_on_chain() {
+        let mut dag = DAG::new();
+        dag.add_prerequisite("CS201".to_string(), "CS101");
+        dag.add_prerequisite("CS301".to_string(), "CS201");
+        dag.add_corequisite("CS301".to_string(), "CS301L");
+        dag.add_course("MATH101".to_string());
+
+        let set = |keys: &[&str]| keys.iter().map(ToString::to_string).collect::<HashSet<_>>();
+
+        assert_eq!(dag.descendants("CS101"), set(&["CS201", "CS301"]));
+        assert_eq!(dag.descendants("CS301L"), set(&["CS301"]));
+        assert!(dag.descendants("CS301").is_empty());
+        assert_eq!(dag.ancestors("CS301"), set(&["CS101", "CS201", "CS301L"]));
+        assert_eq!(dag.ancestors("CS201"), set(&["CS101"]));
+        assert!(dag.ancestors("MATH101").is_empty());
+        assert!(dag.ancestors("UNKNOWN").is_empty());
+    }
+
     #[test]
     fn test_dag_display() {
         let mut dag = DAG::new();
@@ -1,9 +1,13 @@
 //! Degree model
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Represents a degree program
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Equality compares the credit fields by bit pattern (as the school content
+/// hash does), so `Degree` is `Eq` despite holding `f32` values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Degree {
     /// Degree name (e.g., "Computer Science")
     pub name: String,
@@ -16,6 +20,26 @@ pub struct Degree {
 
     /// System type ("semester" or "quarter")
     pub system_type: String,
+
+    /// Multiplier converting this degree's credits to semester hours
+    ///
+    /// `None` leaves credits unchanged (factor 1.0). Quarter systems typically use 2/3.
+    #[serde(default)]
+    pub credit_conversion_factor: Option<f32>,
+
+    /// Minimum credits a plan for this degree must total, if any
+    #[serde(default)]
+    pub required_credits: Option<f32>,
+
+    /// Maximum credits a plan for this degree may total (e.g., a financial-aid limit), if any
+    #[serde(default)]
+    pub max_credits: Option<f32>,
+
+    /// Minimum plan credits per requirement category (see [`Course::category`])
+    ///
+    /// [`Course::category`]: super::Course::category
+    #[serde(default)]
+    pub category_minimums: HashMap<String, f32>,
 }
 
 impl Degree {
@@ -27,20 +51,47 @@ impl Degree {
     /// * `cip_code` - CIP code
     /// * `system_type` - System type ("semester" or "quarter")
     #[must_use]
-    pub const fn new(
-        name: String,
-        degree_type: String,
-        cip_code: String,
-        system_type: String,
-    ) -> Self {
+    pub fn new(name: String, degree_type: String, cip_code: String, system_type: String) -> Self {
         Self {
             name,
             degree_type,
             cip_code,
             system_type,
+            credit_conversion_factor: None,
+            required_credits: None,
+            max_credits: None,
+            category_minimums: HashMap::new(),
         }
     }
 
+    /// Set the multiplier converting this degree's credits to semester hours
+    #[must_use]
+    pub const fn with_credit_conversion_factor(mut self, factor: f32) -> Self {
+        self.credit_conversion_factor = Some(factor);
+        self
+    }
+
+    /// Set the minimum credits a plan for this degree must total
+    #[must_use]
+    pub const fn with_required_credits(mut self, credits: f32) -> Self {
+        self.required_credits = Some(credits);
+        self
+    }
+
+    /// Set the maximum credits a plan for this degree may total
+    #[must_use]
+    pub const fn with_max_credits(mut self, credits: f32) -> Self {
+        self.max_credits = Some(credits);
+        self
+    }
+
+    /// Require a plan to include at least `credits` in `category`
+    #[must_use]
+    pub fn with_category_minimum(mut self, category: impl Into<String>, credits: f32) -> Self {
+        self.category_minimums.insert(category.into(), credits);
+        self
+    }
+
     /// Check if this degree uses a quarter system
     #[must_use]
     pub fn is_quarter_system(&self) -> bool {
@@ -59,6 +110,12 @@ impl Degree {
         }
     }
 
+    /// Get the credit conversion factor, defaulting to 1.0 when unset
+    #[must_use]
+    pub fn credit_conversion_factor(&self) -> f32 {
+        self.credit_conversion_factor.unwrap_or(1.0)
+    }
+
     /// Get a unique identifier for this degree
     ///
     /// # Returns
@@ -69,9 +126,32 @@ impl Degree {
     }
 }
 
+impl PartialEq for Degree {
+    fn eq(&self, other: &Self) -> bool {
+        let bits = |value: Option<f32>| value.map(f32::to_bits);
+        self.name == other.name
+            && self.degree_type == other.degree_type
+            && self.cip_code == other.cip_code
+            && self.system_type == other.system_type
+            && bits(self.credit_conversion_factor) == bits(other.credit_conversion_factor)
+            && bits(self.required_credits) == bits(other.required_credits)
+            && bits(self.max_credits) == bits(other.max_credits)
+            && self.category_minimums.len() == other.category_minimums.len()
+            && self.category_minimums.iter().all(|(category, credits)| {
+                other
+                    .category_minimums
+                    .get(category)
+                    .is_some_and(|c| c.to_bits() == credits.to_bits())
+            })
+    }
+}
+
+impl Eq for Degree {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::approx::{approx_eq, credits_eq};
 
     #[test]
     fn test_degree_creation() {
@@ -143,7 +223,11 @@ mod tests {
         );
 
         assert!(degree.is_quarter_system());
-        assert!((degree.complexity_scale_factor() - 2.0 / 3.0).abs() < f64::EPSILON);
+        assert!(approx_eq(
+            degree.complexity_scale_factor(),
+            2.0 / 3.0,
+            f64::EPSILON
+        ));
     }
 
     #[test]
@@ -156,6 +240,32 @@ mod tests {
         );
 
         assert!(!degree.is_quarter_system());
-        assert!((degree.complexity_scale_factor() - 1.0).abs() < f64::EPSILON);
+        assert!(approx_eq(
+            degree.complexity_scale_factor(),
+            1.0,
+            f64::EPSILON
+        ));
+        assert!(credits_eq(degree.credit_conversion_factor(), 1.0));
+    }
+
+    #[test]
+    fn test_degree_equality_compares_credit_fields() {
+        fn require_eq<T: Eq>() {}
+
+        let degree = Degree::new(
+            "Computer Science".to_string(),
+            "BS".to_string(),
+            "11.0701".to_string(),
+            "quarter".to_string(),
+        )
+        .with_credit_conversion_factor(2.0 / 3.0)
+        .with_category_minimum("Core", 30.0);
+
+        assert_eq!(degree, degree.clone());
+        assert_ne!(degree, degree.clone().with_credit_conversion_factor(0.5));
+        assert_ne!(degree, degree.clone().with_category_minimum("Core", 24.0));
+
+        // Degree is part of the public API as an `Eq` type
+        require_eq::<Degree>();
     }
 }
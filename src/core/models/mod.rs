@@ -6,8 +6,8 @@ pub mod degree;
 pub mod plan;
 pub mod school;
 
-pub use course::Course;
-pub use dag::DAG;
+pub use course::{Course, KeyPolicy, TermOffering, TermParity};
+pub use dag::{DagStats, DAG};
 pub use degree::Degree;
 pub use plan::Plan;
-pub use school::School;
+pub use school::{CoverageReport, School};
@@ -70,11 +70,30 @@ impl Plan {
     pub const fn course_count(&self) -> usize {
         self.courses.len()
     }
+
+    /// Sum credit hours for the plan using a caller-supplied lookup.
+    ///
+    /// This lets credit totals be computed without a full `School`, e.g. when a
+    /// plan is deserialized on its own alongside a trimmed course table.
+    /// Courses for which the lookup returns `None` contribute nothing.
+    ///
+    /// # Arguments
+    /// * `credit_lookup` - Returns the credit hours for a course key
+    pub fn total_credits<F>(&self, mut credit_lookup: F) -> f32
+    where
+        F: FnMut(&str) -> Option<f32>,
+    {
+        self.courses
+            .iter()
+            .filter_map(|key| credit_lookup(key))
+            .sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::approx::credits_eq;
 
     #[test]
     fn test_plan_creation() {
@@ -146,6 +165,25 @@ mod tests {
         assert_eq!(plan.institution, Some("Community College".to_string()));
     }
 
+    #[test]
+    fn test_total_credits_with_lookup() {
+        let mut plan = Plan::new(
+            "Standard Track".to_string(),
+            "BS Computer Science".to_string(),
+        );
+        plan.add_course("CS1800".to_string());
+        plan.add_course("CS2510".to_string());
+        plan.add_course("UNKNOWN".to_string());
+
+        let credits = |key: &str| match key {
+            "CS1800" => Some(4.0),
+            "CS2510" => Some(3.5),
+            _ => None,
+        };
+
+        assert!(credits_eq(plan.total_credits(credits), 7.5));
+    }
+
     #[test]
     fn test_plan_with_multiple_courses() {
         let mut plan = Plan::new(
@@ -1,8 +1,12 @@
 //! School model
 
-use super::{Course, Degree, Plan};
+use super::{Course, Degree, KeyPolicy, Plan};
+use crate::core::approx::credits_eq;
+use crate::core::hash::Fnv1a;
+use crate::core::metrics::{self, MetricsDiff};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 
 /// Represents an educational institution
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +24,25 @@ pub struct School {
     pub plans: Vec<Plan>,
 }
 
+/// Catalog entries and plans that nothing else refers to
+///
+/// Built by [`School::coverage_report`] for catalog hygiene checks.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageReport {
+    /// Courses listed in no plan, sorted by storage key
+    pub uncovered_courses: Vec<String>,
+    /// Plans whose degree is not offered by the school, in plan order
+    pub orphan_plans: Vec<String>,
+}
+
+impl CoverageReport {
+    /// Whether every course is in a plan and every plan belongs to a degree
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.uncovered_courses.is_empty() && self.orphan_plans.is_empty()
+    }
+}
+
 impl School {
     /// Create a new school
     ///
@@ -122,6 +145,23 @@ impl School {
         self.courses.values().collect()
     }
 
+    /// Get the courses offered this catalog cycle (see [`Course::active`])
+    #[must_use]
+    pub fn active_courses(&self) -> Vec<&Course> {
+        self.courses.values().filter(|c| c.active).collect()
+    }
+
+    /// Find courses matching a partial name or code (see [`Course::matches`])
+    ///
+    /// # Returns
+    /// Matching courses sorted by course key
+    #[must_use]
+    pub fn search(&self, query: &str) -> Vec<&Course> {
+        let mut found: Vec<&Course> = self.courses.values().filter(|c| c.matches(query)).collect();
+        found.sort_by_key(|c| c.key());
+        found
+    }
+
     /// Get all courses with their storage keys
     ///
     /// # Returns
@@ -131,8 +171,20 @@ impl School {
     }
 
     /// Add a degree to the school
-    pub fn add_degree(&mut self, degree: Degree) {
+    ///
+    /// A degree whose [`Degree::id`] is already present is still added, but a warning
+    /// is logged because [`get_degree`](Self::get_degree) will only ever return the first.
+    ///
+    /// # Returns
+    /// true if the degree ID was new, false if a degree with the same ID already exists
+    pub fn add_degree(&mut self, degree: Degree) -> bool {
+        let id = degree.id();
+        let is_new = self.get_degree(&id).is_none();
+        if !is_new {
+            crate::warn!("Duplicate degree ID '{id}' in {}", self.name);
+        }
         self.degrees.push(degree);
+        is_new
     }
 
     /// Get a degree by its ID
@@ -148,8 +200,23 @@ impl School {
     }
 
     /// Add a plan to the school
-    pub fn add_plan(&mut self, plan: Plan) {
+    ///
+    /// A plan whose name matches an existing plan (case-insensitive) is still added,
+    /// but a warning is logged because [`plan_by_name`](Self::plan_by_name) will only
+    /// ever return the first.
+    ///
+    /// # Returns
+    /// true if the plan name was new, false if a plan with the same name already exists
+    pub fn add_plan(&mut self, plan: Plan) -> bool {
+        let is_new = !self
+            .plans
+            .iter()
+            .any(|p| p.name.eq_ignore_ascii_case(&plan.name));
+        if !is_new {
+            crate::warn!("Duplicate plan name '{}' in {}", plan.name, self.name);
+        }
         self.plans.push(plan);
+        is_new
     }
 
     /// Get plans associated with a specific degree
@@ -167,6 +234,32 @@ impl School {
             .collect()
     }
 
+    /// Find a plan by name (case-insensitive)
+    ///
+    /// If several plans share the name, the first is returned and a warning is logged.
+    ///
+    /// # Arguments
+    /// * `name` - Plan name (e.g., "Standard Track")
+    ///
+    /// # Returns
+    /// A reference to the first matching plan, or `None` if not found
+    #[must_use]
+    pub fn plan_by_name(&self, name: &str) -> Option<&Plan> {
+        let mut matches = self
+            .plans
+            .iter()
+            .filter(|p| p.name.eq_ignore_ascii_case(name));
+        let first = matches.next()?;
+        let duplicates = matches.count();
+        if duplicates > 0 {
+            crate::warn!(
+                "{} plans are named '{name}'; using the first",
+                duplicates + 1
+            );
+        }
+        Some(first)
+    }
+
     /// Validate that all courses in all plans exist in the school
     ///
     /// # Returns
@@ -195,8 +288,123 @@ impl School {
         }
     }
 
+    /// Validate that each plan's total credits fall within its degree's bounds
+    ///
+    /// Totals are computed with [`School::total_credits`] and compared against
+    /// [`Degree::required_credits`] and [`Degree::max_credits`]. Plans whose degree
+    /// is unknown or sets no bounds pass.
+    ///
+    /// # Returns
+    /// `Ok(())` if every plan is within bounds, `Err(Vec<String>)` otherwise
+    ///
+    /// # Errors
+    /// Returns `Err` with a message for each plan under the minimum or over the maximum
+    pub fn validate_plan_credits(&self) -> Result<(), Vec<String>> {
+        let mut out_of_range = Vec::new();
+
+        for plan in &self.plans {
+            let Some(degree) = self.get_degree(&plan.degree_id) else {
+                continue;
+            };
+            let total = self.total_credits(plan);
+
+            if let Some(min) = degree
+                .required_credits
+                .filter(|&min| total < min && !credits_eq(total, min))
+            {
+                out_of_range.push(format!(
+                    "Plan '{}': {total} credits is below the {min} required for {}",
+                    plan.name, plan.degree_id
+                ));
+            }
+            if let Some(max) = degree
+                .max_credits
+                .filter(|&max| total > max && !credits_eq(total, max))
+            {
+                out_of_range.push(format!(
+                    "Plan '{}': {total} credits exceeds the maximum of {max} for {}",
+                    plan.name, plan.degree_id
+                ));
+            }
+        }
+
+        if out_of_range.is_empty() {
+            Ok(())
+        } else {
+            Err(out_of_range)
+        }
+    }
+
+    /// Total a plan's credits per requirement category
+    ///
+    /// Cross-listed courses count once, as in [`School::total_credits`].
+    /// Courses without a category are left out.
+    #[must_use]
+    pub fn credits_by_category(&self, plan: &Plan) -> HashMap<String, f32> {
+        let mut seen = HashSet::new();
+        let mut totals: HashMap<String, f32> = HashMap::new();
+        for key in &plan.courses {
+            let canonical = self.canonical_key(key);
+            if !seen.insert(canonical) {
+                continue;
+            }
+            let Some(course) = self.get_course(canonical) else {
+                continue;
+            };
+            if let Some(category) = &course.category {
+                *totals.entry(category.clone()).or_default() += course.credit_hours;
+            }
+        }
+        totals
+    }
+
+    /// Validate that each plan meets its degree's per-category credit minimums
+    ///
+    /// Category totals come from [`School::credits_by_category`] and are checked
+    /// against [`Degree::category_minimums`]. Plans whose degree is unknown or
+    /// sets no minimums pass.
+    ///
+    /// # Returns
+    /// `Ok(())` if every minimum is met, `Err(Vec<String>)` otherwise
+    ///
+    /// # Errors
+    /// Returns `Err` with a message for each unmet minimum, including the deficit
+    pub fn validate_category_minimums(&self) -> Result<(), Vec<String>> {
+        let mut shortfalls = Vec::new();
+
+        for plan in &self.plans {
+            let Some(degree) = self.get_degree(&plan.degree_id) else {
+                continue;
+            };
+            let totals = self.credits_by_category(plan);
+
+            let mut minimums: Vec<(&String, &f32)> = degree.category_minimums.iter().collect();
+            minimums.sort_by(|a, b| a.0.cmp(b.0));
+            for (category, &min) in minimums {
+                let total = totals.get(category).copied().unwrap_or(0.0);
+                if total < min && !credits_eq(total, min) {
+                    shortfalls.push(format!(
+                        "Plan '{}': {total} {category} credits is {} short of the {min} required for {}",
+                        plan.name,
+                        min - total,
+                        plan.degree_id
+                    ));
+                }
+            }
+        }
+
+        if shortfalls.is_empty() {
+            Ok(())
+        } else {
+            Err(shortfalls)
+        }
+    }
+
     /// Validate that all prerequisites and corequisites exist
     ///
+    /// A prerequisite group is valid when at least one of its alternatives exists,
+    /// so an OR-group naming an external or transfer course still passes.
+    ///
     /// # Returns
     /// `Ok(())` if all references are valid, `Err(Vec<String>)` with invalid references
     ///
@@ -216,6 +424,16 @@ impl School {
                 }
             }
 
+            for group in &course.prerequisite_groups {
+                if !group.iter().any(|alt| self.get_course(alt).is_some()) {
+                    invalid.push(format!(
+                        "Course '{}': no alternative in prerequisite group '{}' found",
+                        course.key(),
+                        group.join(" or ")
+                    ));
+                }
+            }
+
             for coreq in &course.corequisites {
                 if self.get_course(coreq).is_none() {
                     invalid.push(format!(
@@ -234,71 +452,834 @@ impl School {
         }
     }
 
+    /// Validate that every strict corequisite pair can share a term
+    ///
+    /// Strict corequisites must be taken in the same term, so a pair whose
+    /// [`TermOffering`](super::TermOffering)s never overlap (e.g. one fall-only and one spring-only)
+    /// makes any plan containing them infeasible. Each pair is reported once.
+    ///
+    /// # Returns
+    /// `Ok(())` if every pair has a common term, `Err(Vec<String>)` otherwise
+    ///
+    /// # Errors
+    /// Returns `Err` with a message for each strict corequisite pair with no
+    /// term in common
+    pub fn validate_strict_corequisite_offerings(&self) -> Result<(), Vec<String>> {
+        let mut seen: HashSet<(&str, &str)> = HashSet::new();
+        let mut contradictions = Vec::new();
+
+        let mut keys: Vec<&String> = self.courses.keys().collect();
+        keys.sort();
+        for key in keys {
+            let course = &self.courses[key];
+            for coreq_key in &course.strict_corequisites {
+                let Some(coreq) = self.get_course(coreq_key) else {
+                    continue;
+                };
+                let pair = if key.as_str() < coreq_key.as_str() {
+                    (key.as_str(), coreq_key.as_str())
+                } else {
+                    (coreq_key.as_str(), key.as_str())
+                };
+                if course.offered.overlaps(coreq.offered) || !seen.insert(pair) {
+                    continue;
+                }
+                contradictions.push(format!(
+                    "Course '{key}' ({:?} only) and strict corequisite '{coreq_key}' ({:?} only) are never offered in the same term",
+                    course.offered, coreq.offered
+                ));
+            }
+        }
+
+        if contradictions.is_empty() {
+            Ok(())
+        } else {
+            Err(contradictions)
+        }
+    }
+
+    /// Group storage keys that share a natural key (PREFIX NUMBER)
+    ///
+    /// Deduplication keeps same-key rows as distinct courses (e.g. `CS101_1`
+    /// and `CS101_2`); this lists each such group.
+    ///
+    /// # Returns
+    /// `(natural_key, storage_keys)` pairs with more than one storage key,
+    /// sorted by natural key, with storage keys sorted within each group
+    #[must_use]
+    pub fn duplicate_natural_keys(&self) -> Vec<(String, Vec<String>)> {
+        let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+        for (storage_key, course) in &self.courses {
+            groups
+                .entry(course.key())
+                .or_default()
+                .push(storage_key.clone());
+        }
+
+        let mut duplicates: Vec<(String, Vec<String>)> = groups
+            .into_iter()
+            .filter(|(_, keys)| keys.len() > 1)
+            .map(|(natural_key, mut keys)| {
+                keys.sort();
+                (natural_key, keys)
+            })
+            .collect();
+        duplicates.sort();
+        duplicates
+    }
+
+    /// Validate that courses sharing a natural key agree on credits and prerequisites
+    ///
+    /// Genuine cross-listings carry the same credit hours and prerequisites, so
+    /// a mismatch within a [`duplicate_natural_keys`](Self::duplicate_natural_keys)
+    /// group usually points at a data-entry error. Each course is compared
+    /// against the first in its group; prerequisite order is ignored.
+    ///
+    /// # Returns
+    /// `Ok(())` if every group is consistent, `Err(Vec<String>)` otherwise
+    ///
+    /// # Errors
+    /// Returns `Err` with a message for each course whose credits or prerequisites
+    /// differ from the first course sharing its key
+    pub fn validate_duplicate_consistency(&self) -> Result<(), Vec<String>> {
+        let sorted_prereqs = |course: &Course| {
+            let mut prereqs = course.prerequisites.clone();
+            prereqs.sort();
+            prereqs
+        };
+        let mut inconsistent = Vec::new();
+
+        for (natural_key, storage_keys) in self.duplicate_natural_keys() {
+            let Some((first_key, first)) = storage_keys
+                .first()
+                .and_then(|key| Some((key, self.get_course(key)?)))
+            else {
+                continue;
+            };
+            let first_prereqs = sorted_prereqs(first);
+
+            for key in &storage_keys[1..] {
+                let Some(course) = self.get_course(key) else {
+                    continue;
+                };
+                if !credits_eq(course.credit_hours, first.credit_hours) {
+                    inconsistent.push(format!(
+                        "Course '{natural_key}': '{key}' has {} credits but '{first_key}' has {}",
+                        course.credit_hours, first.credit_hours
+                    ));
+                }
+                if sorted_prereqs(course) != first_prereqs {
+                    inconsistent.push(format!(
+                        "Course '{natural_key}': '{key}' requires [{}] but '{first_key}' requires [{}]",
+                        course.prerequisites.join(", "),
+                        first.prerequisites.join(", ")
+                    ));
+                }
+            }
+        }
+
+        if inconsistent.is_empty() {
+            Ok(())
+        } else {
+            Err(inconsistent)
+        }
+    }
+
+    /// Produce a copy of the school with course keys translated by a mapping
+    ///
+    /// Used for transfer articulation: each mapped course takes the target
+    /// institution's prefix and number, and every reference to it (prerequisites,
+    /// corequisites, strict corequisites, cross-listings, and plan entries) is rewritten to the
+    /// new key. Courses without a mapping are kept unchanged. A mapping whose
+    /// target key is already taken, either by an unmapped course or by another
+    /// source mapped to the same target (the first source in key order wins), is
+    /// skipped and a warning is logged.
+    ///
+    /// # Arguments
+    /// * `mapping` - Source key in concatenated form (e.g., "CS101") to target
+    ///   key (e.g., "CS 1100" or "CS1100")
+    ///
+    /// # Returns
+    /// A new `School` with translated keys
+    #[must_use]
+    pub fn apply_mapping(&self, mapping: &HashMap<String, String>) -> Self {
+        // Storage key -> (new storage key, target prefix, target number)
+        let mut renamed: BTreeMap<String, (String, String, String)> = BTreeMap::new();
+        for (storage_key, course) in &self.courses {
+            let source_key = KeyPolicy::Concatenated.format(&course.prefix, &course.number);
+            let Some(target) = mapping.get(&source_key) else {
+                crate::debug!("No mapping for course {storage_key}; keeping as-is");
+                continue;
+            };
+            let (prefix, number) = split_course_key(target);
+            let mut target_course = course.clone();
+            target_course.prefix.clone_from(&prefix);
+            target_course.number.clone_from(&number);
+
+            // Preserve any deduplication suffix on the storage key
+            let suffix = storage_key.strip_prefix(&course.key()).unwrap_or("");
+            let new_key = format!("{}{suffix}", target_course.key());
+            renamed.insert(storage_key.clone(), (new_key, prefix, number));
+        }
+
+        // Skip mappings whose target is already held by a kept course or claimed
+        // by an earlier source; repeat since skipped courses keep their old key
+        loop {
+            let mut claimed: HashSet<&String> = self
+                .courses
+                .keys()
+                .filter(|key| !renamed.contains_key(*key))
+                .collect();
+            let collisions: Vec<String> = renamed
+                .iter()
+                .filter(|(_, (new_key, _, _))| !claimed.insert(new_key))
+                .map(|(old_key, _)| old_key.clone())
+                .collect();
+            if collisions.is_empty() {
+                break;
+            }
+            for old_key in collisions {
+                if let Some((new_key, _, _)) = renamed.remove(&old_key) {
+                    crate::warn!("Not mapping {old_key}: {new_key} already exists");
+                }
+            }
+        }
+
+        let mut courses = HashMap::new();
+        for (storage_key, course) in &self.courses {
+            let mut course = course.clone();
+            match renamed.get(storage_key) {
+                Some((new_key, prefix, number)) => {
+                    course.prefix.clone_from(prefix);
+                    course.number.clone_from(number);
+                    courses.insert(new_key.clone(), course);
+                }
+                None => {
+                    courses.insert(storage_key.clone(), course);
+                }
+            }
+        }
+
+        let renamed: HashMap<String, String> = renamed
+            .into_iter()
+            .map(|(old_key, (new_key, _, _))| (old_key, new_key))
+            .collect();
+        let mut mapped = Self {
+            name: self.name.clone(),
+            courses,
+            degrees: self.degrees.clone(),
+            plans: self.plans.clone(),
+        };
+        mapped.rewrite_references(&renamed);
+        mapped
+    }
+
+    /// Move every course with prefix `old_prefix` to `new_prefix`
+    ///
+    /// Each renamed course gets a new storage key (keeping any deduplication
+    /// suffix), and every reference to it (requisites, cross-listings, and plan
+    /// entries) is rewritten. A course whose new key is already taken by a course
+    /// that is not itself being renamed is left unchanged and a warning is logged.
+    ///
+    /// # Arguments
+    /// * `old_prefix` - Prefix to replace (exact match, e.g., "IS")
+    /// * `new_prefix` - Replacement prefix (e.g., "CS")
+    ///
+    /// # Returns
+    /// The number of courses renamed
+    pub fn rename_prefix(&mut self, old_prefix: &str, new_prefix: &str) -> usize {
+        let mut renamed: HashMap<String, String> = self
+            .courses
+            .iter()
+            .filter(|(_, course)| course.prefix == old_prefix)
+            .map(|(storage_key, course)| {
+                let suffix = storage_key.strip_prefix(&course.key()).unwrap_or("");
+                let mut target = course.clone();
+                target.prefix = new_prefix.to_string();
+                (storage_key.clone(), format!("{}{suffix}", target.key()))
+            })
+            .collect();
+
+        let collisions: Vec<String> = renamed
+            .iter()
+            .filter(|(_, new_key)| {
+                self.courses.contains_key(*new_key) && !renamed.contains_key(*new_key)
+            })
+            .map(|(old_key, _)| old_key.clone())
+            .collect();
+        for old_key in collisions {
+            if let Some(new_key) = renamed.remove(&old_key) {
+                crate::warn!("Not renaming {old_key}: {new_key} already exists");
+            }
+        }
+
+        let courses = std::mem::take(&mut self.courses);
+        for (storage_key, mut course) in courses {
+            match renamed.get(&storage_key) {
+                Some(new_key) => {
+                    course.prefix = new_prefix.to_string();
+                    self.courses.insert(new_key.clone(), course);
+                }
+                None => {
+                    self.courses.insert(storage_key, course);
+                }
+            }
+        }
+        self.rewrite_references(&renamed);
+
+        renamed.len()
+    }
+
+    /// Rewrite course references in requisites, cross-listings, and plans
+    fn rewrite_references(&mut self, renamed: &HashMap<String, String>) {
+        let rename = |keys: &mut Vec<String>| {
+            for key in keys.iter_mut() {
+                if let Some(new_key) = renamed.get(key) {
+                    key.clone_from(new_key);
+                }
+            }
+        };
+
+        for course in self.courses.values_mut() {
+            rename(&mut course.prerequisites);
+            for group in &mut course.prerequisite_groups {
+                rename(group);
+            }
+            rename(&mut course.corequisites);
+            rename(&mut course.strict_corequisites);
+            if let Some(new_key) = course.equivalent_to.as_ref().and_then(|k| renamed.get(k)) {
+                course.equivalent_to = Some(new_key.clone());
+            }
+        }
+
+        for plan in &mut self.plans {
+            rename(&mut plan.courses);
+        }
+    }
+
+    /// Produce a minimal copy of the school holding a single plan
+    ///
+    /// Keeps the named plan, its degree, the plan's courses, and every course
+    /// they reach through requisites or cross-listings, even if those fall
+    /// outside the plan, so the result validates and builds the same DAG
+    /// neighbourhood on its own.
+    ///
+    /// # Arguments
+    /// * `plan_name` - Plan name (case-insensitive, as for [`Self::plan_by_name`])
+    ///
+    /// # Returns
+    /// The pruned school, or `None` if no plan has that name
+    #[must_use]
+    pub fn prune_to_plan(&self, plan_name: &str) -> Option<Self> {
+        let plan = self.plan_by_name(plan_name)?;
+
+        let mut courses = HashMap::new();
+        let mut pending: Vec<&str> = plan.courses.iter().map(String::as_str).collect();
+        while let Some(key) = pending.pop() {
+            let Some(course) = self.courses.get(key) else {
+                continue;
+            };
+            if courses.contains_key(key) {
+                continue;
+            }
+            courses.insert(key.to_string(), course.clone());
+
+            pending.extend(course.prerequisites.iter().map(String::as_str));
+            pending.extend(
+                course
+                    .prerequisite_groups
+                    .iter()
+                    .flatten()
+                    .map(String::as_str),
+            );
+            pending.extend(course.corequisites.iter().map(String::as_str));
+            pending.extend(course.strict_corequisites.iter().map(String::as_str));
+            pending.extend(course.equivalent_to.as_deref());
+        }
+
+        Some(Self {
+            name: self.name.clone(),
+            courses,
+            degrees: self
+                .degrees
+                .iter()
+                .filter(|d| d.id() == plan.degree_id)
+                .cloned()
+                .collect(),
+            plans: vec![plan.clone()],
+        })
+    }
+
+    /// Report courses listed in no plan and plans referencing no known degree
+    ///
+    /// Cross-listed courses count as covered when any of their listings appears
+    /// in a plan (see [`School::canonical_key`]). Courses only reached as a
+    /// requisite of a planned course are still reported, since no plan lists them.
+    #[must_use]
+    pub fn coverage_report(&self) -> CoverageReport {
+        let planned: HashSet<&str> = self
+            .plans
+            .iter()
+            .flat_map(|plan| &plan.courses)
+            .map(|key| self.canonical_key(key))
+            .collect();
+
+        let mut uncovered_courses: Vec<String> = self
+            .courses
+            .keys()
+            .filter(|key| !planned.contains(self.canonical_key(key)))
+            .cloned()
+            .collect();
+        uncovered_courses.sort();
+
+        let orphan_plans = self
+            .plans
+            .iter()
+            .filter(|plan| self.get_degree(&plan.degree_id).is_none())
+            .map(|plan| plan.name.clone())
+            .collect();
+
+        CoverageReport {
+            uncovered_courses,
+            orphan_plans,
+        }
+    }
+
+    /// Resolve a course key through cross-listings to the key of its canonical course
+    ///
+    /// Follows [`Course::equivalent_to`] links to a course that is not itself
+    /// cross-listed. Keys that are unknown, not cross-listed, or part of an
+    /// equivalence cycle resolve to themselves.
+    #[must_use]
+    pub fn canonical_key<'a>(&'a self, key: &'a str) -> &'a str {
+        let mut current = key;
+        for _ in 0..self.courses.len() {
+            match self
+                .courses
+                .get(current)
+                .and_then(|course| course.equivalent_to.as_deref())
+            {
+                Some(next) if self.courses.contains_key(next) && next != key => current = next,
+                Some(next) if next == key => return key,
+                _ => return current,
+            }
+        }
+        key
+    }
+
+    /// Look up a course's metrics by any of its keys
+    ///
+    /// Metrics are computed on the DAG, where cross-listed aliases are merged
+    /// into their canonical course, so `key` is resolved through
+    /// [`School::canonical_key`] first. Plan entries that name an alias get the
+    /// metrics of the course they list.
+    #[must_use]
+    pub fn course_metrics<'m>(
+        &self,
+        metrics: &'m metrics::CurriculumMetrics,
+        key: &str,
+    ) -> Option<&'m metrics::CourseMetrics> {
+        metrics.get(self.canonical_key(key))
+    }
+
+    /// Sum credit hours for a plan, counting cross-listed courses once
+    ///
+    /// Plan entries are collapsed to their canonical course (see
+    /// [`School::canonical_key`]) before summing, so listing both `CS150` and an
+    /// equivalent `MATH150` contributes the credits of a single course.
+    #[must_use]
+    pub fn total_credits(&self, plan: &Plan) -> f32 {
+        let mut seen = HashSet::new();
+        plan.total_credits(|key| {
+            let canonical = self.canonical_key(key);
+            if seen.insert(canonical.to_string()) {
+                self.get_course(canonical).map(|c| c.credit_hours)
+            } else {
+                None
+            }
+        })
+    }
+
     /// Build a directed acyclic graph (DAG) of course prerequisites
     ///
+    /// Cross-listed courses are merged into a single node under their canonical
+    /// key; requisites declared on either listing attach to that node.
+    ///
     /// # Returns
     /// A DAG with all courses and their prerequisite relationships
     #[must_use]
     pub fn build_dag(&self) -> super::DAG {
+        self.build_dag_with(true)
+    }
+
+    /// Build a DAG with prerequisite edges only, ignoring corequisites
+    ///
+    /// Corequisites are treated as concurrent with no graph edge, so metrics
+    /// computed on this DAG reflect the pure prerequisite structure. Useful for
+    /// comparing against tools that model corequisites differently.
+    ///
+    /// # Returns
+    /// A DAG with all courses and only their prerequisite relationships
+    #[must_use]
+    pub fn build_dag_prereqs_only(&self) -> super::DAG {
+        self.build_dag_with(false)
+    }
+
+    /// Build the DAG induced by a plan's courses
+    ///
+    /// Like [`build_dag`](Self::build_dag), but only the plan's courses (by
+    /// canonical key) and the edges between them are kept, so metrics computed
+    /// on it count only requisite chains a student on the plan actually takes.
+    ///
+    /// # Returns
+    /// A DAG restricted to the plan's courses
+    #[must_use]
+    pub fn build_plan_dag(&self, plan: &Plan) -> super::DAG {
+        let keep: HashSet<&str> = plan
+            .courses
+            .iter()
+            .map(|key| self.canonical_key(key))
+            .collect();
+        self.build_dag().induced_subgraph(&keep)
+    }
+
+    /// Shared body of [`build_dag`](Self::build_dag) and
+    /// [`build_dag_prereqs_only`](Self::build_dag_prereqs_only)
+    fn build_dag_with(&self, include_corequisites: bool) -> super::DAG {
         let mut dag = super::DAG::new();
 
-        // Add all courses to the DAG using the keys they're stored under
+        // Add all courses to the DAG using the keys they're stored under,
+        // skipping cross-listed aliases which share their canonical node
         for stored_key in self.courses.keys() {
-            dag.add_course(stored_key.clone());
+            if self.canonical_key(stored_key) == stored_key {
+                dag.add_course(stored_key.clone());
+            }
         }
 
         // Add prerequisite relationships
         // Note: prerequisite keys stored in course.prerequisites are already the stored keys
         // (including deduplication suffixes), so we can add them directly to the DAG
         for (stored_key, course) in &self.courses {
+            let node = self.canonical_key(stored_key);
+
             for prereq_key in &course.prerequisites {
                 // Check if this prerequisite key exists in our courses
                 if self.courses.contains_key(prereq_key) {
-                    dag.add_prerequisite(stored_key.clone(), prereq_key.as_str());
+                    let prereq = self.canonical_key(prereq_key);
+                    if prereq != node {
+                        dag.add_prerequisite(node.to_string(), prereq);
+                    }
                 }
             }
 
+            if !include_corequisites {
+                continue;
+            }
+
             for coreq_key in &course.corequisites {
                 // Check if this corequisite key exists in our courses
                 if self.courses.contains_key(coreq_key) {
-                    dag.add_corequisite(stored_key.clone(), coreq_key.as_str());
+                    let coreq = self.canonical_key(coreq_key);
+                    if coreq != node {
+                        dag.add_corequisite(node.to_string(), coreq);
+                    }
                 }
             }
 
             for coreq_key in &course.strict_corequisites {
                 if self.courses.contains_key(coreq_key) {
-                    dag.add_corequisite(stored_key.clone(), coreq_key.as_str());
+                    let coreq = self.canonical_key(coreq_key);
+                    if coreq != node {
+                        dag.add_strict_corequisite(node.to_string(), coreq);
+                    }
                 }
             }
         }
 
         dag
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Preview how adding a hypothetical course would change curriculum metrics
+    ///
+    /// Works on a copy of the school: `course` is inserted with `prereqs` added to
+    /// its prerequisites, metrics are recomputed, and the result is diffed against
+    /// the current metrics. Existing courses that already list the new course as a
+    /// prerequisite pick up the new edge. The school itself is not modified.
+    ///
+    /// # Arguments
+    /// * `course` - The hypothetical course
+    /// * `prereqs` - Course keys the hypothetical course would require
+    ///
+    /// # Errors
+    /// Returns an error if metrics cannot be computed for either curriculum
+    /// (e.g., the new edges introduce a cycle)
+    pub fn preview_add(&self, course: Course, prereqs: &[String]) -> Result<MetricsDiff, String> {
+        let baseline = metrics::compute_all_metrics(&self.build_dag())?;
+
+        let mut course = course;
+        for prereq in prereqs {
+            if self.get_course(prereq).is_none() {
+                crate::warn!("Hypothetical prerequisite '{prereq}' not found; ignoring");
+            }
+            course.add_prerequisite(prereq.clone());
+        }
 
-    #[test]
-    fn test_school_creation() {
-        let school = School::new("Northeastern University".to_string());
+        let mut preview = self.clone();
+        let key = course.key();
+        if !preview.add_course(course) {
+            crate::warn!("Hypothetical course '{key}' replaces an existing course");
+        }
+        let after = metrics::compute_all_metrics(&preview.build_dag())
+            .map_err(|e| format!("Adding '{key}' makes metrics uncomputable: {e}"))?;
 
-        assert_eq!(school.name, "Northeastern University");
-        assert!(school.courses().is_empty());
-        assert!(school.degrees.is_empty());
-        assert!(school.plans.is_empty());
+        Ok(MetricsDiff::between(&baseline, &after))
     }
 
-    #[test]
-    fn test_add_and_get_course() {
-        let mut school = School::new("Test University".to_string());
+    /// Compare curriculum metrics against a later catalog year
+    ///
+    /// `equivalences` maps course keys in this (older) catalog to their
+    /// renumbered keys in `newer`, so a renumbered course is reported as a
+    /// change rather than a removal plus an addition. Keys on both sides are
+    /// resolved through cross-listings first (see [`School::canonical_key`]).
+    ///
+    /// # Errors
+    /// Returns an error if metrics cannot be computed for either catalog
+    pub fn compare_catalog(
+        &self,
+        newer: &Self,
+        equivalences: &HashMap<String, String>,
+    ) -> Result<MetricsDiff, String> {
+        let before = metrics::compute_all_metrics(&self.build_dag())
+            .map_err(|e| format!("{}: {e}", self.name))?;
+        let after = metrics::compute_all_metrics(&newer.build_dag())
+            .map_err(|e| format!("{}: {e}", newer.name))?;
+
+        let equivalences: HashMap<String, String> = equivalences
+            .iter()
+            .map(|(old, new)| {
+                (
+                    self.canonical_key(old).to_string(),
+                    newer.canonical_key(new).to_string(),
+                )
+            })
+            .collect();
+        Ok(MetricsDiff::between_with_equivalences(
+            &before,
+            &after,
+            &equivalences,
+        ))
+    }
 
-        let course = Course::new(
-            "Discrete Structures".to_string(),
-            "CS".to_string(),
-            "1800".to_string(),
-            4.0,
+    /// Deterministic hash of the school's curriculum content
+    ///
+    /// Courses are visited in storage-key order and requisite and plan course
+    /// lists are sorted, so the hash does not depend on `HashMap` iteration or
+    /// CSV row order. Useful for detecting whether a curriculum changed between
+    /// runs without diffing it field by field.
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv1a::default();
+        self.name.hash(&mut hasher);
+
+        let mut keys: Vec<&String> = self.courses.keys().collect();
+        keys.sort();
+        keys.len().hash(&mut hasher);
+        for key in keys {
+            key.hash(&mut hasher);
+            hash_course(&self.courses[key], &mut hasher);
+        }
+
+        self.degrees.len().hash(&mut hasher);
+        for degree in &self.degrees {
+            degree.name.hash(&mut hasher);
+            degree.degree_type.hash(&mut hasher);
+            degree.cip_code.hash(&mut hasher);
+            degree.system_type.hash(&mut hasher);
+            degree
+                .credit_conversion_factor
+                .map(f32::to_bits)
+                .hash(&mut hasher);
+            degree.required_credits.map(f32::to_bits).hash(&mut hasher);
+            degree.max_credits.map(f32::to_bits).hash(&mut hasher);
+            degree
+                .category_minimums
+                .iter()
+                .map(|(category, credits)| (category, credits.to_bits()))
+                .collect::<BTreeMap<_, _>>()
+                .hash(&mut hasher);
+        }
+
+        self.plans.len().hash(&mut hasher);
+        for plan in &self.plans {
+            plan.name.hash(&mut hasher);
+            plan.degree_id.hash(&mut hasher);
+            plan.institution.hash(&mut hasher);
+            sorted(&plan.courses).hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    /// Serialize the school into the compact binary cache format
+    ///
+    /// # Errors
+    /// Returns an error if the school cannot be encoded
+    #[cfg(feature = "cache")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, bincode::Error> {
+        bincode::serialize(self)
+    }
+
+    /// Deserialize a school from the compact binary cache format
+    ///
+    /// # Errors
+    /// Returns an error if `bytes` is not a valid encoded school (e.g., written by
+    /// an incompatible version)
+    #[cfg(feature = "cache")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
+}
+
+/// Feed a course's content into `hasher`, ignoring requisite order
+fn hash_course(course: &Course, hasher: &mut impl Hasher) {
+    course.csv_id.hash(hasher);
+    course.id.hash(hasher);
+    course.name.hash(hasher);
+    course.prefix.hash(hasher);
+    course.number.hash(hasher);
+    sorted(&course.prerequisites).hash(hasher);
+    let mut groups: Vec<Vec<&String>> = course
+        .prerequisite_groups
+        .iter()
+        .map(|group| sorted(group))
+        .collect();
+    groups.sort();
+    groups.len().hash(hasher);
+    for group in &groups {
+        group.hash(hasher);
+    }
+    sorted(&course.corequisites).hash(hasher);
+    sorted(&course.strict_corequisites).hash(hasher);
+    course.credit_hours.to_bits().hash(hasher);
+    course.raw_credit_hours.hash(hasher);
+    course.canonical_name.hash(hasher);
+    course.notes.hash(hasher);
+    course.category.hash(hasher);
+    course.equivalent_to.hash(hasher);
+    course.key_policy.hash(hasher);
+    course.offered.hash(hasher);
+    course.term.hash(hasher);
+    course.active.hash(hasher);
+}
+
+/// Borrow `items` in sorted order
+fn sorted(items: &[String]) -> Vec<&String> {
+    let mut items: Vec<&String> = items.iter().collect();
+    items.sort();
+    items
+}
+
+/// Split a course key into prefix and number
+///
+/// Accepts `PREFIX NUMBER`, `PREFIX-NUMBER`, or `PREFIXNUMBER` (split at the first digit).
+fn split_course_key(key: &str) -> (String, String) {
+    let parts: Vec<&str> = key
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    if parts.len() >= 2 {
+        return (parts[0].to_string(), parts[1..].concat());
+    }
+
+    let key = key.trim();
+    key.find(|c: char| c.is_ascii_digit()).map_or_else(
+        || (key.to_string(), String::new()),
+        |idx| (key[..idx].to_string(), key[idx..].to_string()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cross_listed_courses_share_node_and_credits() {
+        let mut school = School::new("Test U".to_string());
+
+        let math100 = Course::new(
+            "Precalc".to_string(),
+            "MATH".to_string(),
+            "100".to_string(),
+            3.0,
+        );
+        let mut cs150 = Course::new(
+            "Discrete".to_string(),
+            "CS".to_string(),
+            "150".to_string(),
+            4.0,
+        );
+        cs150.add_prerequisite("MATH100".to_string());
+        let mut math150 = Course::new(
+            "Discrete".to_string(),
+            "MATH".to_string(),
+            "150".to_string(),
+            4.0,
+        );
+        math150.set_equivalent("CS150".to_string());
+        let mut cs250 = Course::new(
+            "Algorithms".to_string(),
+            "CS".to_string(),
+            "250".to_string(),
+            3.0,
+        );
+        cs250.add_prerequisite("MATH150".to_string());
+
+        for course in [math100, cs150, math150, cs250] {
+            school.add_course(course);
+        }
+
+        assert_eq!(school.canonical_key("MATH150"), "CS150");
+        assert_eq!(school.canonical_key("CS150"), "CS150");
+
+        let dag = school.build_dag();
+        assert_eq!(dag.courses.len(), 3);
+        assert!(!dag.courses.contains(&"MATH150".to_string()));
+        assert_eq!(
+            dag.dependencies.get("CS250"),
+            Some(&vec!["CS150".to_string()])
+        );
+        assert_eq!(
+            dag.dependencies.get("CS150"),
+            Some(&vec!["MATH100".to_string()])
+        );
+
+        let mut plan = Plan::new("Plan".to_string(), "BS".to_string());
+        for key in ["MATH100", "CS150", "MATH150", "CS250"] {
+            plan.add_course(key.to_string());
+        }
+        assert!(credits_eq(school.total_credits(&plan), 10.0));
+    }
+
+    #[test]
+    fn test_school_creation() {
+        let school = School::new("Northeastern University".to_string());
+
+        assert_eq!(school.name, "Northeastern University");
+        assert!(school.courses().is_empty());
+        assert!(school.degrees.is_empty());
+        assert!(school.plans.is_empty());
+    }
+
+    #[test]
+    fn test_add_and_get_course() {
+        let mut school = School::new("Test University".to_string());
+
+        let course = Course::new(
+            "Discrete Structures".to_string(),
+            "CS".to_string(),
+            "1800".to_string(),
+            4.0,
         );
 
         assert!(school.add_course(course));
@@ -350,6 +1331,415 @@ mod tests {
         assert!(school.get_course("CS9999").is_none());
     }
 
+    #[test]
+    fn test_apply_mapping_rewrites_references() {
+        let mut school = School::new("Community College".to_string());
+
+        let cs101 = Course::new(
+            "Intro".to_string(),
+            "CS".to_string(),
+            "101".to_string(),
+            3.0,
+        );
+        let mut cs102 = Course::new(
+            "Intro II".to_string(),
+            "CS".to_string(),
+            "102".to_string(),
+            3.0,
+        );
+        cs102.add_prerequisite("CS101".to_string());
+        let mut lab = Course::new(
+            "Intro Lab".to_string(),
+            "CS".to_string(),
+            "101L".to_string(),
+            1.0,
+        );
+        lab.add_strict_corequisite("CS101".to_string());
+
+        school.add_course(cs101);
+        school.add_course(cs102);
+        school.add_course(lab);
+
+        let mut plan = Plan::new("Transfer".to_string(), "AS CS".to_string());
+        plan.add_course("CS101".to_string());
+        plan.add_course("CS102".to_string());
+        school.add_plan(plan);
+
+        let mut mapping = HashMap::new();
+        mapping.insert("CS101".to_string(), "CS 1100".to_string());
+        mapping.insert("CS102".to_string(), "CS2000".to_string());
+
+        let mapped = school.apply_mapping(&mapping);
+
+        assert!(mapped.get_course("CS101").is_none());
+        let cs1100 = mapped.get_course("CS1100").unwrap();
+        assert_eq!(cs1100.prefix, "CS");
+        assert_eq!(cs1100.number, "1100");
+
+        let cs2000 = mapped.get_course("CS2000").unwrap();
+        assert_eq!(cs2000.prerequisites, vec!["CS1100".to_string()]);
+
+        // Unmapped courses are kept, with references translated
+        let lab = mapped.get_course("CS101L").unwrap();
+        assert_eq!(lab.strict_corequisites, vec!["CS1100".to_string()]);
+
+        assert_eq!(
+            mapped.plans[0].courses,
+            vec!["CS1100".to_string(), "CS2000".to_string()]
+        );
+        assert!(mapped.validate_course_dependencies().is_ok());
+
+        // Original school is untouched
+        assert!(school.get_course("CS101").is_some());
+    }
+
+    #[test]
+    fn test_apply_mapping_skips_colliding_targets() {
+        let mut school = School::new("Community College".to_string());
+        for (number, name) in [
+            ("101", "Intro"),
+            ("105", "Intro for Majors"),
+            ("200", "Data"),
+        ] {
+            school.add_course(Course::new(
+                name.to_string(),
+                "CS".to_string(),
+                number.to_string(),
+                3.0,
+            ));
+        }
+        let mut cs300 = Course::new(
+            "Systems".to_string(),
+            "CS".to_string(),
+            "300".to_string(),
+            3.0,
+        );
+        cs300.add_prerequisite("CS105".to_string());
+        school.add_course(cs300);
+
+        let mut mapping = HashMap::new();
+        mapping.insert("CS101".to_string(), "CS1100".to_string());
+        mapping.insert("CS105".to_string(), "CS1100".to_string());
+        mapping.insert("CS300".to_string(), "CS200".to_string());
+
+        for _ in 0..5 {
+            let mapped = school.apply_mapping(&mapping);
+
+            // Both sources survive; the first in key order takes the target
+            assert_eq!(mapped.courses().len(), school.courses().len());
+            assert_eq!(mapped.get_course("CS1100").unwrap().name, "Intro");
+            let cs105 = mapped.get_course("CS105").unwrap();
+            assert_eq!(cs105.name, "Intro for Majors");
+
+            // A target held by an unmapped course is not overwritten
+            assert_eq!(mapped.get_course("CS200").unwrap().name, "Data");
+            let cs300 = mapped.get_course("CS300").unwrap();
+            assert_eq!(cs300.prerequisites, vec!["CS105".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_prune_to_plan_keeps_only_reachable_courses() {
+        let mut school = School::new("Test University".to_string());
+        let course = |prefix: &str, number: &str| {
+            Course::new(
+                format!("{prefix} {number}"),
+                prefix.to_string(),
+                number.to_string(),
+                3.0,
+            )
+        };
+
+        let mut cs201 = course("CS", "201");
+        cs201.add_prerequisite("CS101".to_string());
+        let mut lab = course("CS", "201L");
+        lab.add_strict_corequisite("CS201".to_string());
+        school.add_course(course("CS", "101"));
+        school.add_course(cs201);
+        school.add_course(lab);
+        school.add_course(course("ART", "100"));
+
+        for (name, kind) in [("Computer Science", "BS"), ("Art", "BA")] {
+            school.add_degree(Degree::new(
+                name.to_string(),
+                kind.to_string(),
+                "00.0000".to_string(),
+                "semester".to_string(),
+            ));
+        }
+        let mut cs_plan = Plan::new("CS Track".to_string(), "BS Computer Science".to_string());
+        cs_plan.add_course("CS201".to_string());
+        cs_plan.add_course("CS201L".to_string());
+        school.add_plan(cs_plan);
+        let mut art_plan = Plan::new("Art Track".to_string(), "BA Art".to_string());
+        art_plan.add_course("ART100".to_string());
+        school.add_plan(art_plan);
+
+        let pruned = school.prune_to_plan("cs track").unwrap();
+
+        assert_eq!(pruned.plans.len(), 1);
+        assert_eq!(pruned.plans[0].name, "CS Track");
+        assert_eq!(pruned.degrees.len(), 1);
+        assert_eq!(pruned.degrees[0].id(), "BS Computer Science");
+        assert!(pruned.validate_plans().is_ok());
+        assert!(pruned.validate_course_dependencies().is_ok());
+
+        // CS101 is outside the plan but kept as a prerequisite
+        let mut dag_courses = pruned.build_dag().courses;
+        dag_courses.sort();
+        assert_eq!(dag_courses, vec!["CS101", "CS201", "CS201L"]);
+
+        assert!(school.prune_to_plan("Missing").is_none());
+    }
+
+    #[test]
+    fn test_coverage_report_lists_unplanned_courses_and_orphan_plans() {
+        let mut school = School::new("Test University".to_string());
+        for number in ["101", "201", "499"] {
+            school.add_course(Course::new(
+                format!("CS {number}"),
+                "CS".to_string(),
+                number.to_string(),
+                3.0,
+            ));
+        }
+        school.add_degree(Degree::new(
+            "Computer Science".to_string(),
+            "BS".to_string(),
+            "11.0701".to_string(),
+            "semester".to_string(),
+        ));
+
+        let mut plan = Plan::new("CS Track".to_string(), "BS Computer Science".to_string());
+        plan.add_course("CS101".to_string());
+        school.add_plan(plan);
+        let mut legacy = Plan::new("Legacy Track".to_string(), "BS Retired".to_string());
+        legacy.add_course("CS201".to_string());
+        school.add_plan(legacy);
+
+        let report = school.coverage_report();
+        assert_eq!(report.uncovered_courses, vec!["CS499"]);
+        assert_eq!(report.orphan_plans, vec!["Legacy Track"]);
+        assert!(!report.is_complete());
+    }
+
+    #[test]
+    fn test_rename_prefix_rewrites_references() {
+        let mut school = School::new("Test University".to_string());
+        let course = |prefix: &str, number: &str, prereq: Option<&str>| {
+            let mut c = Course::new(
+                format!("{prefix} {number}"),
+                prefix.to_string(),
+                number.to_string(),
+                3.0,
+            );
+            if let Some(p) = prereq {
+                c.add_prerequisite(p.to_string());
+            }
+            c
+        };
+        school.add_course(course("IS", "101", None));
+        school.add_course(course("IS", "201", Some("IS101")));
+        school.add_course(course("CS", "301", Some("IS201")));
+        // IS150 would collide with the existing CS150
+        school.add_course(course("IS", "150", None));
+        school.add_course(course("CS", "150", None));
+        let mut plan = Plan::new("Track".to_string(), "BS CS".to_string());
+        plan.add_course("IS101".to_string());
+        plan.add_course("CS301".to_string());
+        school.add_plan(plan);
+
+        let renamed = school.rename_prefix("IS", "CS");
+
+        assert_eq!(renamed, 2);
+        assert!(school.get_course("IS101").is_none());
+        assert_eq!(school.get_course("CS101").unwrap().prefix, "CS");
+        assert_eq!(
+            school.get_course("CS201").unwrap().prerequisites,
+            vec!["CS101".to_string()]
+        );
+        assert_eq!(
+            school.get_course("CS301").unwrap().prerequisites,
+            vec!["CS201".to_string()]
+        );
+        assert_eq!(school.plans[0].courses, vec!["CS101", "CS301"]);
+
+        // The colliding course is left in place
+        assert_eq!(school.get_course("IS150").unwrap().prefix, "IS");
+        assert_eq!(school.get_course("CS150").unwrap().name, "CS 150");
+
+        assert!(school.validate_plans().is_ok());
+        assert!(school.validate_course_dependencies().is_ok());
+    }
+
+    #[test]
+    fn test_preview_add_raises_dependent_delay() {
+        let mut school = School::new("Test University".to_string());
+        school.add_course(Course::new(
+            "Programming II".to_string(),
+            "CS".to_string(),
+            "250".to_string(),
+            4.0,
+        ));
+        // CS400 already names CS350, which the school does not offer yet
+        let mut capstone = Course::new(
+            "Capstone".to_string(),
+            "CS".to_string(),
+            "400".to_string(),
+            4.0,
+        );
+        capstone.add_prerequisite("CS350".to_string());
+        school.add_course(capstone);
+
+        let cs350 = Course::new(
+            "Systems".to_string(),
+            "CS".to_string(),
+            "350".to_string(),
+            4.0,
+        );
+        let diff = school.preview_add(cs350, &["CS250".to_string()]).unwrap();
+
+        assert_eq!(diff.added, vec!["CS350".to_string()]);
+        assert!(diff.removed.is_empty());
+
+        let capstone = diff.change_for("CS400").unwrap();
+        assert_eq!(capstone.before.delay, 1);
+        assert_eq!(capstone.after.delay, 3);
+        let cs250 = diff.change_for("CS250").unwrap();
+        assert_eq!(cs250.after.blocking, 2);
+
+        // The school itself is unchanged
+        assert!(school.get_course("CS350").is_none());
+        assert_eq!(school.courses().len(), 2);
+    }
+
+    #[test]
+    fn test_preview_add_leaves_unrelated_courses_unchanged() {
+        let mut school = School::new("Test University".to_string());
+        for (prefix, number, prereq) in [
+            ("CS", "101", None),
+            ("CS", "201", Some("CS101")),
+            ("MATH", "101", None),
+            ("MATH", "201", Some("MATH101")),
+        ] {
+            let mut course = Course::new(
+                format!("{prefix} {number}"),
+                prefix.to_string(),
+                number.to_string(),
+                4.0,
+            );
+            if let Some(prereq) = prereq {
+                course.add_prerequisite(prereq.to_string());
+            }
+            school.add_course(course);
+        }
+
+        let phys101 = Course::new(
+            "Physics I".to_string(),
+            "PHYS".to_string(),
+            "101".to_string(),
+            4.0,
+        );
+        let diff = school
+            .preview_add(phys101, &["MATH101".to_string()])
+            .unwrap();
+
+        assert_eq!(diff.added, vec!["PHYS101".to_string()]);
+        // Only MATH101 gains a dependent; the total path count changing is
+        // not a structural change for the other courses
+        let changed: Vec<&str> = diff.changed.iter().map(|c| c.course.as_str()).collect();
+        assert_eq!(changed, vec!["MATH101"]);
+    }
+
+    #[test]
+    fn test_compare_catalog_matches_renumbered_course() {
+        let catalog = |intro_number: &str| {
+            let mut school = School::new(format!("Catalog {intro_number}"));
+            school.add_course(Course::new(
+                "Intro to Programming".to_string(),
+                "CS".to_string(),
+                intro_number.to_string(),
+                4.0,
+            ));
+            let mut data = Course::new(
+                "Data Structures".to_string(),
+                "CS".to_string(),
+                "200".to_string(),
+                4.0,
+            );
+            data.add_prerequisite(format!("CS{intro_number}"));
+            school.add_course(data);
+            school
+        };
+        let old = catalog("150");
+        let new = catalog("160");
+
+        let plain = old.compare_catalog(&new, &HashMap::new()).unwrap();
+        assert_eq!(plain.added, vec!["CS160".to_string()]);
+        assert_eq!(plain.removed, vec!["CS150".to_string()]);
+
+        let equivalences = HashMap::from([("CS150".to_string(), "CS160".to_string())]);
+        let diff = old.compare_catalog(&new, &equivalences).unwrap();
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        let renumbered = diff.change_for("CS160").unwrap();
+        assert_eq!(renumbered.previous.as_deref(), Some("CS150"));
+        assert_eq!(renumbered.before, renumbered.after);
+        assert!(diff.change_for("CS200").is_none());
+    }
+
+    #[test]
+    fn test_content_hash_tracks_content_not_order() {
+        let sample = "samples/plans/Colostate_CSDegree.csv";
+        let first = crate::core::planner::parse_curriculum_csv(sample).unwrap();
+        let second = crate::core::planner::parse_curriculum_csv(sample).unwrap();
+        assert_eq!(first.content_hash(), second.content_hash());
+
+        // Rebuilding with courses inserted in reverse order does not change the hash
+        let mut reordered = School::new(first.name.clone());
+        let mut entries: Vec<(&String, &Course)> = first.courses_with_keys().collect();
+        entries.sort_by(|a, b| b.0.cmp(a.0));
+        for (key, course) in entries {
+            reordered.add_course_with_key(key.clone(), course.clone());
+        }
+        reordered.degrees.clone_from(&first.degrees);
+        reordered.plans.clone_from(&first.plans);
+        assert_eq!(reordered.content_hash(), first.content_hash());
+
+        let mut changed = second;
+        let key = changed.courses_with_keys().next().unwrap().0.clone();
+        changed.get_course_mut(&key).unwrap().credit_hours += 1.0;
+        assert_ne!(changed.content_hash(), first.content_hash());
+    }
+
+    #[test]
+    fn test_prereqs_only_dag_ignores_corequisites() {
+        let mut school = School::new("Test University".to_string());
+        let mut lecture = Course::new(
+            "Chemistry".to_string(),
+            "CHEM".to_string(),
+            "101".to_string(),
+            3.0,
+        );
+        lecture.add_corequisite("CHEM101L".to_string());
+        school.add_course(lecture);
+        school.add_course(Course::new(
+            "Chemistry Lab".to_string(),
+            "CHEM".to_string(),
+            "101L".to_string(),
+            1.0,
+        ));
+
+        let full = metrics::compute_delay(&school.build_dag()).unwrap();
+        assert_eq!(full["CHEM101"], 2);
+
+        let dag = school.build_dag_prereqs_only();
+        assert!(dag.corequisites.values().all(Vec::is_empty));
+        let delay = metrics::compute_delay(&dag).unwrap();
+        assert_eq!(delay["CHEM101"], 1);
+        assert_eq!(delay["CHEM101L"], 1);
+    }
+
     #[test]
     fn test_add_degree() {
         let mut school = School::new("Test University".to_string());
@@ -384,6 +1774,45 @@ mod tests {
         assert_eq!(school.plans.len(), 1);
     }
 
+    #[test]
+    fn test_add_degree_detects_duplicate_id() {
+        let mut school = School::new("Test University".to_string());
+        let degree = Degree::new(
+            "Computer Science".to_string(),
+            "BS".to_string(),
+            "11.0701".to_string(),
+            "semester".to_string(),
+        );
+
+        assert!(school.add_degree(degree.clone()));
+        assert!(!school.add_degree(degree));
+        assert_eq!(school.degrees.len(), 2);
+
+        // Same name under a different type is a distinct ID
+        assert!(school.add_degree(Degree::new(
+            "Computer Science".to_string(),
+            "BA".to_string(),
+            "11.0701".to_string(),
+            "semester".to_string(),
+        )));
+    }
+
+    #[test]
+    fn test_add_plan_warns_on_duplicate_name() {
+        let mut school = School::new("Test University".to_string());
+
+        assert!(school.add_plan(Plan::new("Standard Track".to_string(), "BS CS".to_string())));
+        assert!(!school.add_plan(Plan::new("STANDARD TRACK".to_string(), "BS CS".to_string())));
+        assert!(school.add_plan(Plan::new("Co-op Track".to_string(), "BS CS".to_string())));
+
+        // Duplicates are kept, and lookup by name resolves to the first
+        assert_eq!(school.plans.len(), 3);
+        assert_eq!(
+            school.plan_by_name("standard track").unwrap().name,
+            "Standard Track"
+        );
+    }
+
     #[test]
     fn test_get_plans_for_degree() {
         let mut school = School::new("Test University".to_string());
@@ -403,6 +1832,27 @@ mod tests {
         assert_eq!(ds_plans.len(), 1);
     }
 
+    #[test]
+    fn test_plan_by_name() {
+        let mut school = School::new("Test University".to_string());
+
+        let mut first = Plan::new("Standard Track".to_string(), "BS CS".to_string());
+        first.add_course("CS1800".to_string());
+        school.add_plan(first);
+        school.add_plan(Plan::new("Co-op Track".to_string(), "BS CS".to_string()));
+        school.add_plan(Plan::new("standard track".to_string(), "BS CS".to_string()));
+
+        let plan = school.plan_by_name("STANDARD TRACK").unwrap();
+        assert_eq!(plan.name, "Standard Track");
+        assert_eq!(plan.courses, vec!["CS1800".to_string()]);
+
+        assert_eq!(
+            school.plan_by_name("co-op track").map(|p| p.name.as_str()),
+            Some("Co-op Track")
+        );
+        assert!(school.plan_by_name("Honors Track").is_none());
+    }
+
     #[test]
     fn test_validate_plans_success() {
         let mut school = School::new("Test University".to_string());
@@ -455,6 +1905,98 @@ mod tests {
         assert!(errors[0].contains("CS9999"));
     }
 
+    #[test]
+    fn test_validate_category_minimums_reports_math_deficit() {
+        let mut school = School::new("Test University".to_string());
+        for (prefix, number, credits, category) in [
+            ("CS", "1800", 4.0, "Core"),
+            ("CS", "2510", 4.0, "Core"),
+            ("MATH", "1341", 4.0, "Math"),
+            ("ENGW", "1111", 4.0, "Gen-Ed"),
+        ] {
+            let mut course = Course::new(
+                format!("{prefix} {number}"),
+                prefix.to_string(),
+                number.to_string(),
+                credits,
+            );
+            course.set_category(category.to_string());
+            school.add_course(course);
+        }
+        school.add_degree(
+            Degree::new(
+                "CS".to_string(),
+                "BS".to_string(),
+                "11.0701".to_string(),
+                "semester".to_string(),
+            )
+            .with_category_minimum("Core", 8.0)
+            .with_category_minimum("Math", 12.0),
+        );
+
+        let mut plan = Plan::new("Standard".to_string(), "BS CS".to_string());
+        for key in ["CS1800", "CS2510", "MATH1341", "ENGW1111"] {
+            plan.add_course(key.to_string());
+        }
+        school.add_plan(plan);
+
+        let totals = school.credits_by_category(&school.plans[0]);
+        assert!(credits_eq(totals["Core"], 8.0));
+        assert!(credits_eq(totals["Gen-Ed"], 4.0));
+
+        let errors = school.validate_category_minimums().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                "Plan 'Standard': 4 Math credits is 8 short of the 12 required for BS CS"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_plan_credits_flags_over_and_under() {
+        let mut school = School::new("Test University".to_string());
+        for number in ["1800", "2510", "3500"] {
+            school.add_course(Course::new(
+                format!("Course {number}"),
+                "CS".to_string(),
+                number.to_string(),
+                4.0,
+            ));
+        }
+        school.add_degree(
+            Degree::new(
+                "CS".to_string(),
+                "BS".to_string(),
+                "11.0701".to_string(),
+                "semester".to_string(),
+            )
+            .with_required_credits(8.0)
+            .with_max_credits(10.0),
+        );
+
+        let mut within = Plan::new("Within".to_string(), "BS CS".to_string());
+        within.add_course("CS1800".to_string());
+        within.add_course("CS2510".to_string());
+        school.add_plan(within);
+        assert!(school.validate_plan_credits().is_ok());
+
+        let mut over = Plan::new("Over".to_string(), "BS CS".to_string());
+        over.add_course("CS1800".to_string());
+        over.add_course("CS2510".to_string());
+        over.add_course("CS3500".to_string());
+        school.add_plan(over);
+        let mut under = Plan::new("Under".to_string(), "BS CS".to_string());
+        under.add_course("CS1800".to_string());
+        school.add_plan(under);
+
+        let errors = school.validate_plan_credits().unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].contains("'Over'") && errors[0].contains("exceeds the maximum of 10"));
+        assert!(errors[1].contains("'Under'") && errors[1].contains("below the 8 required"));
+    }
+
     #[test]
     fn test_validate_course_dependencies_success() {
         let mut school = School::new("Test University".to_string());
@@ -479,6 +2021,159 @@ mod tests {
         assert!(school.validate_course_dependencies().is_ok());
     }
 
+    #[test]
+    fn test_plan_dag_scopes_blocking_to_plan_courses() {
+        let mut school = School::new("Test University".to_string());
+        school.add_course(Course::new(
+            "Calculus I".to_string(),
+            "MATH".to_string(),
+            "101".to_string(),
+            4.0,
+        ));
+        for (name, prefix) in [("Algorithms", "CS"), ("Signals", "EE")] {
+            let mut course =
+                Course::new(name.to_string(), prefix.to_string(), "201".to_string(), 3.0);
+            course.add_prerequisite("MATH101".to_string());
+            school.add_course(course);
+        }
+        let mut plan = Plan::new("CS Track".to_string(), "BS CS".to_string());
+        plan.add_course("MATH101".to_string());
+        plan.add_course("CS201".to_string());
+
+        let catalog = metrics::compute_all_metrics(&school.build_dag()).unwrap();
+        let plan_dag = school.build_plan_dag(&plan);
+        let scoped = metrics::compute_all_metrics(&plan_dag).unwrap();
+
+        assert!(!plan_dag.contains_course("EE201"));
+        assert_eq!(catalog["MATH101"].blocking, 2);
+        assert_eq!(scoped["MATH101"].blocking, 1);
+        assert_eq!(scoped["CS201"].delay, catalog["CS201"].delay);
+    }
+
+    #[test]
+    fn test_search_by_partial_name_and_code() {
+        let mut school = School::new("Test University".to_string());
+        for (name, prefix, number) in [
+            ("Discrete Structures", "CS", "1800"),
+            ("Fundamentals of Computer Science", "CS", "2500"),
+            ("Calculus I", "MATH", "1341"),
+        ] {
+            school.add_course(Course::new(
+                name.to_string(),
+                prefix.to_string(),
+                number.to_string(),
+                4.0,
+            ));
+        }
+
+        let by_name: Vec<String> = school.search("discrete").iter().map(|c| c.key()).collect();
+        assert_eq!(by_name, vec!["CS1800"]);
+
+        let by_code: Vec<String> = school.search("math13").iter().map(|c| c.key()).collect();
+        assert_eq!(by_code, vec!["MATH1341"]);
+
+        assert_eq!(school.search("cs").len(), 2);
+        assert!(school.search("chemistry").is_empty());
+    }
+
+    #[test]
+    fn test_fall_only_and_spring_only_strict_corequisites_are_flagged() {
+        use crate::core::models::TermOffering;
+
+        let mut school = School::new("Test University".to_string());
+        let mut lecture = Course::new(
+            "Physics I".to_string(),
+            "PHYS".to_string(),
+            "101".to_string(),
+            3.0,
+        );
+        lecture.set_offered(TermOffering::Fall);
+        lecture.add_strict_corequisite("PHYS101L".to_string());
+        let mut lab = Course::new(
+            "Physics I Lab".to_string(),
+            "PHYS".to_string(),
+            "101L".to_string(),
+            1.0,
+        );
+        lab.set_offered(TermOffering::Spring);
+        lab.add_strict_corequisite("PHYS101".to_string());
+        school.add_course(lecture);
+        school.add_course(lab);
+
+        let errors = school.validate_strict_corequisite_offerings().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("PHYS101"));
+        assert!(errors[0].contains("PHYS101L"));
+
+        school
+            .get_course_mut("PHYS101L")
+            .unwrap()
+            .set_offered(TermOffering::Every);
+        assert!(school.validate_strict_corequisite_offerings().is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_courses_with_differing_credits_are_inconsistent() {
+        let mut school = School::new("Test University".to_string());
+        let intro = |credits| {
+            Course::new(
+                "Intro to CS".to_string(),
+                "CS".to_string(),
+                "101".to_string(),
+                credits,
+            )
+        };
+        school.add_course_with_key("CS101_1".to_string(), intro(3.0));
+        school.add_course_with_key("CS101_2".to_string(), intro(4.0));
+        school.add_course_with_key("CS101_3".to_string(), intro(3.0));
+
+        assert_eq!(
+            school.duplicate_natural_keys(),
+            vec![(
+                "CS101".to_string(),
+                vec![
+                    "CS101_1".to_string(),
+                    "CS101_2".to_string(),
+                    "CS101_3".to_string()
+                ]
+            )]
+        );
+
+        let errors = school.validate_duplicate_consistency().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("'CS101_2' has 4 credits"));
+    }
+
+    #[test]
+    fn test_validate_duplicate_consistency_ignores_prerequisite_order() {
+        let mut school = School::new("Test University".to_string());
+        for key in ["CS201_1", "CS201_2"] {
+            let mut course = Course::new(
+                "Data Structures".to_string(),
+                "CS".to_string(),
+                "201".to_string(),
+                4.0,
+            );
+            if key == "CS201_1" {
+                course.add_prerequisite("CS101".to_string());
+                course.add_prerequisite("MATH101".to_string());
+            } else {
+                course.add_prerequisite("MATH101".to_string());
+                course.add_prerequisite("CS101".to_string());
+            }
+            school.add_course_with_key(key.to_string(), course);
+        }
+
+        assert!(school.validate_duplicate_consistency().is_ok());
+
+        school
+            .get_course_mut("CS201_2")
+            .unwrap()
+            .add_prerequisite("CS150".to_string());
+        let errors = school.validate_duplicate_consistency().unwrap_err();
+        assert!(errors[0].contains("'CS201_2' requires"));
+    }
+
     #[test]
     fn test_validate_course_dependencies_failure() {
         let mut school = School::new("Test University".to_string());
@@ -499,6 +2194,42 @@ mod tests {
         assert!(errors[0].contains("prerequisite"));
     }
 
+    #[test]
+    fn test_validate_prerequisite_group_needs_one_alternative() {
+        let mut school = School::new("Test University".to_string());
+        school.add_course(Course::new(
+            "Calculus I".to_string(),
+            "MATH".to_string(),
+            "101".to_string(),
+            4.0,
+        ));
+
+        // MATH110 is a transfer course not offered here; MATH101 satisfies the group
+        let mut physics = Course::new(
+            "Physics I".to_string(),
+            "PHYS".to_string(),
+            "151".to_string(),
+            4.0,
+        );
+        physics.add_prerequisite_group(vec!["MATH101".to_string(), "MATH110".to_string()]);
+        school.add_course(physics);
+        assert!(school.validate_course_dependencies().is_ok());
+
+        // No alternative resolves, so the group is reported once
+        let mut chem = Course::new(
+            "Chemistry I".to_string(),
+            "CHEM".to_string(),
+            "101".to_string(),
+            4.0,
+        );
+        chem.add_prerequisite_group(vec!["MATH110".to_string(), "MATH120".to_string()]);
+        school.add_course(chem);
+
+        let errors = school.validate_course_dependencies().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("MATH110 or MATH120"));
+    }
+
     #[test]
     fn test_get_course_mut() {
         let mut school = School::new("Test University".to_string());
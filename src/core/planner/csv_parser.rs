@@ -7,10 +7,12 @@
 //! 2. **Second pass**: Determine storage keys (handling duplicates)
 //! 3. **Third pass**: Add prerequisites, corequisites using resolved keys
 
-use crate::core::models::{Course, Degree, Plan, School};
+use crate::core::models::{Course, Degree, KeyPolicy, Plan, School};
 use std::collections::HashMap;
 use std::error::Error;
+use std::fmt;
 use std::fs;
+use std::io::Read;
 use std::path::Path;
 
 /// Represents parsed curriculum metadata from CSV header
@@ -28,6 +30,103 @@ pub struct CurriculumMetadata {
     pub cip_code: String,
 }
 
+/// Options controlling how a curriculum CSV is parsed
+#[derive(Debug, Clone, Default, Hash)]
+pub struct ParseOptions {
+    /// How course prefixes and numbers are joined into stored keys
+    pub key_policy: KeyPolicy,
+    /// Uppercase course prefixes at ingest so `cs`, `Cs`, and `CS` share keys
+    pub normalize_prefix_case: bool,
+    /// Column holding free-text course notes (`Notes` when `None`)
+    pub notes_column: Option<String>,
+    /// Fail on an unparseable `Credit Hours` value instead of reading it as 0
+    pub strict_credits: bool,
+}
+
+impl ParseOptions {
+    /// Set the course-key normalization policy
+    #[must_use]
+    pub const fn with_key_policy(mut self, key_policy: KeyPolicy) -> Self {
+        self.key_policy = key_policy;
+        self
+    }
+
+    /// Enable or disable uppercasing of course prefixes
+    #[must_use]
+    pub const fn with_normalize_prefix_case(mut self, enabled: bool) -> Self {
+        self.normalize_prefix_case = enabled;
+        self
+    }
+
+    /// Read course notes from the named column instead of `Notes`
+    #[must_use]
+    pub fn with_notes_column(mut self, column: impl Into<String>) -> Self {
+        self.notes_column = Some(column.into());
+        self
+    }
+
+    /// Enable or disable rejecting unparseable credit hours
+    #[must_use]
+    pub const fn with_strict_credits(mut self, enabled: bool) -> Self {
+        self.strict_credits = enabled;
+        self
+    }
+}
+
+/// Category of a recoverable problem found while parsing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseWarningKind {
+    /// A course lists the same prerequisite more than once
+    DuplicatePrerequisite,
+    /// A prerequisite did not match any Course ID and was read as a course key instead
+    UnresolvedPrerequisite,
+    /// A prerequisite did not match any Course ID but matched a course name
+    PrerequisiteByName,
+    /// A `Term` value was not a positive integer and was ignored
+    InvalidTerm,
+    /// A `Credit Hours` value was not a number and was read as 0
+    InvalidCreditHours,
+    /// An `Active` value was not a recognized yes/no and the course was kept active
+    InvalidActive,
+}
+
+/// A recoverable problem found while parsing
+///
+/// Parsing continues past these; they are collected so callers can report or
+/// reject them without scraping the log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWarning {
+    /// 1-indexed line in the source CSV
+    pub line: usize,
+    /// What kind of problem this is
+    pub kind: ParseWarningKind,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ParseWarning {}
+
+/// Log a parse warning and add it to `warnings`
+fn push_warning(
+    warnings: &mut Vec<ParseWarning>,
+    line: usize,
+    kind: ParseWarningKind,
+    message: String,
+) {
+    crate::warn!("line {line}: {message}");
+    warnings.push(ParseWarning {
+        line,
+        kind,
+        message,
+    });
+}
+
 /// Intermediate data structure for first-pass course parsing
 ///
 /// Tracks all the mappings needed to handle duplicate course keys
@@ -37,10 +136,14 @@ struct CourseParseContext {
     course_id_to_natural_key: HashMap<String, String>,
     /// Maps natural key to all Course IDs with that key
     natural_key_to_ids: HashMap<String, Vec<String>>,
+    /// Maps lowercased course name to all Course IDs with that name
+    name_to_ids: HashMap<String, Vec<String>>,
     /// Maps Course ID to parsed Course object
     courses_by_id: HashMap<String, Course>,
     /// Preserves original order of course IDs for deterministic output
     course_ids_in_order: Vec<String>,
+    /// Recoverable problems found so far
+    warnings: Vec<ParseWarning>,
 }
 
 impl CourseParseContext {
@@ -49,8 +152,10 @@ impl CourseParseContext {
         Self {
             course_id_to_natural_key: HashMap::new(),
             natural_key_to_ids: HashMap::new(),
+            name_to_ids: HashMap::new(),
             courses_by_id: HashMap::new(),
             course_ids_in_order: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 
@@ -69,6 +174,10 @@ impl CourseParseContext {
             .entry(natural_key)
             .or_default()
             .push(course_id.clone());
+        self.name_to_ids
+            .entry(course.name.trim().to_lowercase())
+            .or_default()
+            .push(course_id.clone());
         self.courses_by_id.insert(course_id.clone(), course);
         self.course_ids_in_order.push(course_id);
     }
@@ -119,7 +228,84 @@ impl CourseParseContext {
 /// # Errors
 /// Returns an error if file cannot be read or parsed
 pub fn parse_curriculum_csv<P: AsRef<Path>>(path: P) -> Result<School, Box<dyn Error>> {
-    let content = fs::read_to_string(path)?;
+    parse_curriculum_csv_with_options(path, &ParseOptions::default())
+}
+
+/// Parse a curriculum CSV file using custom parse options
+///
+/// # Arguments
+/// * `path` - Path to the CSV file
+/// * `options` - Options controlling key normalization and other parse behavior
+///
+/// # Returns
+/// A `School` object populated with courses and degrees from the file
+///
+/// # Errors
+/// Returns an error if file cannot be read or parsed
+pub fn parse_curriculum_csv_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &ParseOptions,
+) -> Result<School, Box<dyn Error>> {
+    parse_curriculum_reader(fs::File::open(path)?, options)
+}
+
+/// Parse curriculum CSV data from any reader (e.g., an in-memory buffer or request body)
+///
+/// UTF-16 data with a byte order mark (as written by some Excel exports) is
+/// transcoded to UTF-8 first; anything else must be UTF-8.
+///
+/// # Arguments
+/// * `reader` - Source of the CSV text
+/// * `options` - Options controlling key normalization and other parse behavior
+///
+/// # Returns
+/// A `School` object populated with courses and degrees from the data
+///
+/// # Errors
+/// Returns an error if the data cannot be read or parsed
+pub fn parse_curriculum_reader<R: Read>(
+    reader: R,
+    options: &ParseOptions,
+) -> Result<School, Box<dyn Error>> {
+    parse_curriculum_reader_with_warnings(reader, options).map(|(school, _)| school)
+}
+
+/// Parse a curriculum CSV file, also returning recoverable problems
+///
+/// # Arguments
+/// * `path` - Path to the CSV file
+/// * `options` - Options controlling key normalization and other parse behavior
+///
+/// # Returns
+/// The parsed `School` and the warnings raised while parsing it, in file order
+///
+/// # Errors
+/// Returns an error if file cannot be read or parsed
+pub fn parse_curriculum_csv_with_warnings<P: AsRef<Path>>(
+    path: P,
+    options: &ParseOptions,
+) -> Result<(School, Vec<ParseWarning>), Box<dyn Error>> {
+    parse_curriculum_reader_with_warnings(fs::File::open(path)?, options)
+}
+
+/// Parse curriculum CSV data from any reader, also returning recoverable problems
+///
+/// # Arguments
+/// * `reader` - Source of the CSV text
+/// * `options` - Options controlling key normalization and other parse behavior
+///
+/// # Returns
+/// The parsed `School` and the warnings raised while parsing it, in file order
+///
+/// # Errors
+/// Returns an error if the data cannot be read or parsed
+pub fn parse_curriculum_reader_with_warnings<R: Read>(
+    mut reader: R,
+    options: &ParseOptions,
+) -> Result<(School, Vec<ParseWarning>), Box<dyn Error>> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    let content = decode_text(bytes)?;
     let lines: Vec<&str> = content.lines().collect();
 
     // Parse metadata and create school structure
@@ -131,18 +317,116 @@ pub fn parse_curriculum_csv<P: AsRef<Path>>(path: P) -> Result<School, Box<dyn E
 
     // First pass: Load all courses and build mappings
     let mut ctx = CourseParseContext::new();
-    first_pass_load_courses(&lines, courses_start, &headers, &mut ctx);
+    first_pass_load_courses(&lines, courses_start, &headers, &mut ctx, options);
+    if options.strict_credits {
+        if let Some(warning) = ctx
+            .warnings
+            .iter()
+            .find(|w| w.kind == ParseWarningKind::InvalidCreditHours)
+        {
+            return Err(Box::new(warning.clone()));
+        }
+    }
 
     // Second pass: Compute final storage keys
     let storage_keys = ctx.compute_storage_keys()?;
 
     // Third pass: Add prerequisites and corequisites
-    third_pass_add_dependencies(&lines, courses_start, &headers, &mut ctx, &storage_keys);
+    third_pass_add_dependencies(
+        &lines,
+        courses_start,
+        &headers,
+        &mut ctx,
+        &storage_keys,
+        options,
+    );
 
     // Build the final school structure
+    let mut warnings = std::mem::take(&mut ctx.warnings);
+    warnings.sort_by_key(|w| w.line);
     finalize_school(&mut school, ctx, &storage_keys, &metadata.name)?;
 
-    Ok(school)
+    Ok((school, warnings))
+}
+
+/// A minimal curriculum CSV in the format [`parse_curriculum_reader`] expects
+///
+/// Contains the metadata rows, the `Courses` header row with every column the
+/// parser reads by default, and two example courses (the second requiring the
+/// first) for authors to replace.
+#[must_use]
+pub fn curriculum_template() -> String {
+    [
+        "Curriculum,My Curriculum",
+        "Institution,My University",
+        "Degree Type,BS",
+        "System Type,semester",
+        "CIP,11.0701",
+        "Courses",
+        "Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours,Institution,Canonical Name,Equivalent,Term,Active,Notes",
+        "1,Introduction to Programming,CS,101,,,,3,,,,1,,",
+        "2,Data Structures,CS,201,1,,,3,,,,2,,Separate multiple Course IDs with ';'",
+        "",
+    ]
+    .join("\n")
+}
+
+/// Parse a two-column course mapping CSV (source key, target key)
+///
+/// Each row maps a course at the source institution to its equivalent at the
+/// target institution, e.g. `CS 101,CS 1100`. Blank lines are skipped, as is a
+/// header row whose first column is `Source`. Source keys are normalized to the
+/// concatenated form (`CS101`); target keys are kept as written. If a source
+/// key appears on more than one row, the first row wins and the rest are
+/// ignored with a warning.
+///
+/// # Arguments
+/// * `path` - Path to the mapping CSV file
+///
+/// # Returns
+/// A map from source course key to target course key, suitable for
+/// [`School::apply_mapping`]
+///
+/// # Errors
+/// Returns an error if the file cannot be read or a row does not have two non-empty columns
+pub fn parse_course_mapping_csv<P: AsRef<Path>>(
+    path: P,
+) -> Result<HashMap<String, String>, Box<dyn Error>> {
+    let content = decode_text(fs::read(path)?)?;
+    let mut mapping = HashMap::new();
+
+    for (line_num, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let parts = parse_csv_line(line);
+        if line_num == 0 && parts[0].eq_ignore_ascii_case("source") {
+            continue;
+        }
+
+        let source = parts.first().map_or("", String::as_str);
+        let target = parts.get(1).map_or("", String::as_str);
+        if source.is_empty() || target.is_empty() {
+            return Err(format!(
+                "Invalid mapping on line {}: expected 'source,target'",
+                line_num + 1
+            )
+            .into());
+        }
+
+        let source_key = normalize_course_key(source, KeyPolicy::Concatenated);
+        if let Some(existing) = mapping.get(&source_key) {
+            crate::warn!(
+                "Ignoring duplicate mapping for {source_key} on line {}: already mapped to {existing}",
+                line_num + 1
+            );
+            continue;
+        }
+        mapping.insert(source_key, target.to_string());
+    }
+
+    Ok(mapping)
 }
 
 /// Creates a School and Degree from parsed metadata
@@ -160,6 +444,11 @@ fn create_school_from_metadata(metadata: &CurriculumMetadata) -> School {
 
 /// Finds the courses section and extracts headers
 ///
+/// The section marker is a line whose first field is exactly `Courses`
+/// (case-insensitive) with every other field empty, e.g. `Courses,,,,`.
+/// Metadata values that merely mention courses (such as a curriculum named
+/// "Courses for Success") are not mistaken for the marker.
+///
 /// # Returns
 /// Tuple of (start index, headers vector)
 ///
@@ -168,7 +457,7 @@ fn create_school_from_metadata(metadata: &CurriculumMetadata) -> School {
 fn find_courses_section(lines: &[&str]) -> Result<(usize, Vec<String>), Box<dyn Error>> {
     let courses_start = lines
         .iter()
-        .position(|line| line.to_lowercase().contains("courses"))
+        .position(|line| is_courses_marker(line))
         .ok_or("No 'Courses' section found in CSV")?;
 
     if courses_start + 1 >= lines.len() {
@@ -181,19 +470,31 @@ fn find_courses_section(lines: &[&str]) -> Result<(usize, Vec<String>), Box<dyn
     Ok((courses_start, headers))
 }
 
+/// Whether a line is the `Courses` section marker
+fn is_courses_marker(line: &str) -> bool {
+    let fields = parse_csv_line(line);
+    fields
+        .first()
+        .is_some_and(|first| first.eq_ignore_ascii_case("courses"))
+        && fields.iter().skip(1).all(String::is_empty)
+}
+
 /// First pass: Load all courses and build ID-to-key mappings
 fn first_pass_load_courses(
     lines: &[&str],
     courses_start: usize,
     headers: &[String],
     ctx: &mut CourseParseContext,
+    options: &ParseOptions,
 ) {
-    for line in lines.iter().skip(courses_start + 2) {
+    for (line_number, line) in lines.iter().enumerate().skip(courses_start + 2) {
         if line.trim().is_empty() {
             continue;
         }
 
-        if let Ok(course) = parse_course_line(line, headers) {
+        if let Ok(course) =
+            parse_course_line(line, headers, options, &mut ctx.warnings, line_number + 1)
+        {
             if let Some(course_id) = get_field(line, "Course ID", headers) {
                 ctx.add_course(course_id, course);
             }
@@ -208,8 +509,9 @@ fn third_pass_add_dependencies(
     headers: &[String],
     ctx: &mut CourseParseContext,
     storage_keys: &HashMap<String, String>,
+    options: &ParseOptions,
 ) {
-    for line in lines.iter().skip(courses_start + 2) {
+    for (line_number, line) in lines.iter().enumerate().skip(courses_start + 2) {
         if line.trim().is_empty() {
             continue;
         }
@@ -225,7 +527,15 @@ fn third_pass_add_dependencies(
         // Parse and add prerequisites
         if let Some(prereq_str) = get_field(line, "Prerequisites", headers) {
             if !prereq_str.trim().is_empty() {
-                add_prerequisites_with_mapping(course, &prereq_str, storage_keys);
+                add_prerequisites_with_mapping(
+                    course,
+                    &prereq_str,
+                    storage_keys,
+                    &ctx.name_to_ids,
+                    options,
+                    &mut ctx.warnings,
+                    line_number + 1,
+                );
             }
         }
 
@@ -236,6 +546,13 @@ fn third_pass_add_dependencies(
             }
         }
 
+        // Record a cross-listing to an equivalent course
+        if let Some(equivalent) = get_field(line, "Equivalent", headers) {
+            if let Some(key) = storage_keys.get(equivalent.trim()) {
+                course.set_equivalent(key.clone());
+            }
+        }
+
         // Parse and add strict corequisites
         if let Some(strict_coreq_str) = get_field(line, "Strict-Corequisites", headers) {
             if !strict_coreq_str.trim().is_empty() {
@@ -282,6 +599,31 @@ fn finalize_school(
     Ok(())
 }
 
+/// Decode file contents as text, transcoding UTF-16 when it starts with a BOM
+///
+/// UTF-8 input (with or without a BOM) is used as-is; the UTF-8 BOM is left for
+/// [`clean_field`] to strip.
+///
+/// # Errors
+/// Returns an error if the data is neither valid UTF-8 nor valid BOM-marked UTF-16
+fn decode_text(bytes: Vec<u8>) -> Result<String, Box<dyn Error>> {
+    let from_units: fn([u8; 2]) -> u16 = match bytes.get(..2) {
+        Some([0xFF, 0xFE]) => u16::from_le_bytes,
+        Some([0xFE, 0xFF]) => u16::from_be_bytes,
+        _ => return Ok(String::from_utf8(bytes)?),
+    };
+
+    let body = &bytes[2..];
+    if !body.len().is_multiple_of(2) {
+        return Err("UTF-16 data has an odd number of bytes".into());
+    }
+    let units: Vec<u16> = body
+        .chunks_exact(2)
+        .map(|pair| from_units([pair[0], pair[1]]))
+        .collect();
+    Ok(String::from_utf16(&units)?)
+}
+
 /// Normalizes a raw CSV field by stripping problematic characters
 ///
 /// Removes:
@@ -355,27 +697,53 @@ fn parse_csv_line(line: &str) -> Vec<String> {
 
 /// Parses a single course line from the CSV into a Course object
 ///
-/// Extracts Course Name, Prefix, Number, Credit Hours, and Canonical Name
+/// Extracts Course Name, Prefix, Number, Credit Hours, Canonical Name, Term, Active, Category, and notes
 /// from the CSV fields using the provided headers for column mapping.
+/// The course key policy is taken from `options`. An unusable `Term` or
+/// unparseable `Credit Hours` is recorded in `warnings` against `line_number`.
 ///
 /// # Errors
 /// Returns an error if required fields (Prefix, Number) are missing
-fn parse_course_line(line: &str, headers: &[String]) -> Result<Course, Box<dyn Error>> {
+fn parse_course_line(
+    line: &str,
+    headers: &[String],
+    options: &ParseOptions,
+    warnings: &mut Vec<ParseWarning>,
+    line_number: usize,
+) -> Result<Course, Box<dyn Error>> {
     let _fields = parse_csv_line(line);
 
     let name = get_field(line, "Course Name", headers).unwrap_or_default();
-    let prefix = get_field(line, "Prefix", headers).unwrap_or_default();
+    let mut prefix = get_field(line, "Prefix", headers).unwrap_or_default();
+    if options.normalize_prefix_case {
+        prefix = prefix.to_uppercase();
+    }
     let number = get_field(line, "Number", headers).unwrap_or_default();
 
     let credit_hours_str =
         get_field(line, "Credit Hours", headers).unwrap_or_else(|| "0".to_string());
-    let credit_hours = credit_hours_str.parse::<f32>().unwrap_or(0.0);
 
     if prefix.is_empty() || number.is_empty() {
         return Err("Missing prefix or number".into());
     }
 
+    let credit_hours = credit_hours_str.parse::<f32>().unwrap_or_else(|_| {
+        if !credit_hours_str.is_empty() {
+            push_warning(
+                warnings,
+                line_number,
+                ParseWarningKind::InvalidCreditHours,
+                format!(
+                    "Unparseable credit hours '{credit_hours_str}' for {prefix}{number}; using 0"
+                ),
+            );
+        }
+        0.0
+    });
+
     let mut course = Course::new(name, prefix, number, credit_hours);
+    course.key_policy = options.key_policy;
+    course.raw_credit_hours = Some(credit_hours_str);
 
     // Set optional fields
     if let Some(canonical) = get_field(line, "Canonical Name", headers) {
@@ -384,9 +752,59 @@ fn parse_course_line(line: &str, headers: &[String]) -> Result<Course, Box<dyn E
         }
     }
 
+    if let Some(term) = get_field(line, "Term", headers) {
+        match term.parse::<usize>() {
+            Ok(number) if number > 0 => course.set_term(number),
+            _ if term.is_empty() => {}
+            _ => push_warning(
+                warnings,
+                line_number,
+                ParseWarningKind::InvalidTerm,
+                format!("Ignoring invalid term '{term}' for {}", course.key()),
+            ),
+        }
+    }
+
+    if let Some(active) = get_field(line, "Active", headers) {
+        match parse_active(&active) {
+            Some(active) => course.set_active(active),
+            None => push_warning(
+                warnings,
+                line_number,
+                ParseWarningKind::InvalidActive,
+                format!(
+                    "Ignoring invalid active flag '{active}' for {}",
+                    course.key()
+                ),
+            ),
+        }
+    }
+
+    if let Some(category) = get_field(line, "Category", headers) {
+        if !category.is_empty() {
+            course.set_category(category);
+        }
+    }
+
+    let notes_column = options.notes_column.as_deref().unwrap_or("Notes");
+    if let Some(notes) = get_field(line, notes_column, headers) {
+        if !notes.is_empty() {
+            course.set_notes(notes);
+        }
+    }
+
     Ok(course)
 }
 
+/// Parse an `Active` cell; blank means active
+fn parse_active(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "" | "true" | "yes" | "y" | "1" => Some(true),
+        "false" | "no" | "n" | "0" => Some(false),
+        _ => None,
+    }
+}
+
 /// Retrieves a field value from a CSV line by header name
 ///
 /// Performs case-insensitive matching against the headers array
@@ -411,32 +829,79 @@ fn get_field(line: &str, header_name: &str, headers: &[String]) -> Option<String
 
 /// Adds prerequisites from a semicolon-separated string to a course
 ///
-/// Converts CSV Course IDs to storage keys using the provided mapping.
-/// Falls back to normalizing the string as a course key if not found in mapping.
+/// Converts CSV Course IDs to storage keys using the provided mapping. An entry
+/// that is not a Course ID but exactly matches one course's name (ignoring case),
+/// such as `Calculus I`, resolves to that course with a warning. Anything else
+/// falls back to normalizing the string as a course key.
 ///
 /// # Arguments
 /// * `course` - The course to add prerequisites to
 /// * `prereq_str` - Semicolon-separated list of prerequisite IDs (e.g., "1;2;5")
 /// * `course_id_to_key` - Mapping from CSV Course ID to storage key
+/// * `name_to_ids` - Course IDs by lowercased course name
+/// * `options` - Key policy and prefix case used when falling back to key normalization
+/// * `warnings` - Receives duplicate and fallback warnings
+/// * `line_number` - 1-indexed CSV line the prerequisites came from
 fn add_prerequisites_with_mapping(
     course: &mut Course,
     prereq_str: &str,
     course_id_to_key: &HashMap<String, String>,
+    name_to_ids: &HashMap<String, Vec<String>>,
+    options: &ParseOptions,
+    warnings: &mut Vec<ParseWarning>,
+    line_number: usize,
 ) {
     for prereq in prereq_str.split(';') {
         let trimmed = prereq.trim();
-        if !trimmed.is_empty() {
-            // Try to map course ID to key, otherwise use as-is
-            if let Some(key) = course_id_to_key.get(trimmed) {
-                course.add_prerequisite(key.clone());
-            } else {
-                // Fall back to normalizing as course key
-                let normalized = normalize_course_key(trimmed);
-                if !normalized.is_empty() {
-                    course.add_prerequisite(normalized);
-                }
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        // Try to map course ID to key, otherwise fall back to normalizing as course key
+        let by_name = match name_to_ids.get(&trimmed.to_lowercase()).map(Vec::as_slice) {
+            Some([id]) => course_id_to_key.get(id),
+            _ => None,
+        };
+
+        let key = if let Some(key) = course_id_to_key.get(trimmed) {
+            key.clone()
+        } else if let Some(key) = by_name {
+            push_warning(
+                warnings,
+                line_number,
+                ParseWarningKind::PrerequisiteByName,
+                format!(
+                    "Prerequisite '{trimmed}' of {} is a course name, not a Course ID; resolved to '{key}'",
+                    course.key()
+                ),
+            );
+            key.clone()
+        } else {
+            let normalized = normalize_course_key_with_options(trimmed, options);
+            if normalized.is_empty() {
+                continue;
             }
+            push_warning(
+                warnings,
+                line_number,
+                ParseWarningKind::UnresolvedPrerequisite,
+                format!(
+                    "Prerequisite '{trimmed}' of {} is not a Course ID; using key '{normalized}'",
+                    course.key()
+                ),
+            );
+            normalized
+        };
+
+        if course.prerequisites.contains(&key) {
+            push_warning(
+                warnings,
+                line_number,
+                ParseWarningKind::DuplicatePrerequisite,
+                format!("{} lists prerequisite {key} more than once", course.key()),
+            );
         }
+        course.add_prerequisite(key);
     }
 }
 
@@ -491,30 +956,61 @@ fn add_strict_corequisites_with_mapping(
     }
 }
 
-/// Normalizes a course key to `PREFIXNUMBER` format
+/// Normalizes a course key according to a [`KeyPolicy`]
 ///
-/// Handles various input formats:
+/// Handles various input formats (shown for [`KeyPolicy::Concatenated`]):
 /// - `"CS 1800"` → `"CS1800"`
 /// - `"CS1800"` → `"CS1800"`
+/// - `"CS-1800"` → `"CS1800"`
 /// - `"CS 1800 (or coreq)"` → `"CS1800"` (strips parenthetical notes)
 /// - `"  PHYS  1151  "` → `"PHYS1151"` (handles extra whitespace)
 ///
+/// With [`KeyPolicy::Spaced`] or [`KeyPolicy::Hyphenated`], the prefix and number
+/// are joined with a space or hyphen instead. Inputs without a separator are
+/// returned unchanged since the prefix/number boundary is unknown.
+///
 /// # Arguments
 /// * `input` - Raw course key string from CSV
+/// * `policy` - How prefix and number are joined
 ///
 /// # Returns
 /// Normalized course key, or empty string if input is empty
-fn normalize_course_key(input: &str) -> String {
+fn normalize_course_key(input: &str, policy: KeyPolicy) -> String {
+    normalize_course_key_with_options(input, &ParseOptions::default().with_key_policy(policy))
+}
+
+/// Normalizes a course key using the key policy and prefix case from `options`
+///
+/// Behaves like [`normalize_course_key`], additionally uppercasing the prefix
+/// when [`ParseOptions::normalize_prefix_case`] is set (`"cs 101"` → `"CS101"`).
+/// A single-token key has its leading letters uppercased (`"cs101"` → `"CS101"`).
+fn normalize_course_key_with_options(input: &str, options: &ParseOptions) -> String {
+    let policy = options.key_policy;
     // Remove parentheses and anything after them
     let cleaned = input.split('(').next().unwrap_or(input).trim();
 
-    // Split by space and rejoin without space
-    let parts: Vec<&str> = cleaned.split_whitespace().collect();
+    // Split on whitespace or hyphens and rejoin using the policy separator
+    let parts: Vec<&str> = cleaned
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|p| !p.is_empty())
+        .collect();
 
     if parts.len() >= 2 {
-        format!("{}{}", parts[0], parts[1])
+        if options.normalize_prefix_case {
+            policy.format(&parts[0].to_uppercase(), parts[1])
+        } else {
+            policy.format(parts[0], parts[1])
+        }
     } else if parts.len() == 1 {
-        parts[0].to_string()
+        if options.normalize_prefix_case {
+            let token = parts[0];
+            let split = token
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(token.len());
+            format!("{}{}", token[..split].to_uppercase(), &token[split..])
+        } else {
+            parts[0].to_string()
+        }
     } else {
         String::new()
     }
@@ -523,20 +1019,404 @@ fn normalize_course_key(input: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::approx::credits_eq;
+    use std::io::Write;
+
+    #[test]
+    fn test_duplicate_prerequisite_yields_warning() {
+        let csv = "Curriculum,Warnings\n\
+                   Institution,Test University\n\
+                   Degree Type,BS\n\
+                   System Type,semester\n\
+                   CIP,11.0701\n\
+                   Courses\n\
+                   Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours\n\
+                   1,Intro to CS,CS,101,,,,4\n\
+                   2,Data Structures,CS,201,1;1,,,4\n";
+
+        let (school, warnings) =
+            parse_curriculum_reader_with_warnings(csv.as_bytes(), &ParseOptions::default())
+                .unwrap();
+
+        assert_eq!(
+            school.get_course("CS201").unwrap().prerequisites,
+            vec!["CS101".to_string()]
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, ParseWarningKind::DuplicatePrerequisite);
+        assert_eq!(warnings[0].line, 9);
+        assert!(warnings[0].to_string().contains("CS101"));
+    }
+
+    #[test]
+    fn test_prerequisite_by_course_name_resolves_with_warning() {
+        let csv = "Curriculum,Names\n\
+                   Institution,Test University\n\
+                   Degree Type,BS\n\
+                   System Type,semester\n\
+                   CIP,11.0701\n\
+                   Courses\n\
+                   Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours\n\
+                   1,Calculus I,MATH,160,,,,4\n\
+                   2,Calculus II,MATH,161,calculus i,,,4\n\
+                   3,Linear Algebra,MATH,369,Calculus II;1,,,3\n";
+
+        let (school, warnings) =
+            parse_curriculum_reader_with_warnings(csv.as_bytes(), &ParseOptions::default())
+                .unwrap();
+
+        assert_eq!(
+            school.get_course("MATH161").unwrap().prerequisites,
+            vec!["MATH160".to_string()]
+        );
+        assert_eq!(
+            school.get_course("MATH369").unwrap().prerequisites,
+            vec!["MATH161".to_string(), "MATH160".to_string()]
+        );
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings
+            .iter()
+            .all(|w| w.kind == ParseWarningKind::PrerequisiteByName));
+        assert_eq!(warnings[0].line, 9);
+        assert!(warnings[0].to_string().contains("MATH160"));
+    }
+
+    #[test]
+    fn test_unparseable_credits_warn_or_fail_in_strict_mode() {
+        let csv = "Curriculum,Typo\n\
+                   Institution,Test University\n\
+                   Degree Type,BS\n\
+                   System Type,semester\n\
+                   CIP,11.0701\n\
+                   Courses\n\
+                   Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours\n\
+                   1,Intro to CS,CS,101,,,,3.o\n";
+
+        let (school, warnings) =
+            parse_curriculum_reader_with_warnings(csv.as_bytes(), &ParseOptions::default())
+                .unwrap();
+        assert!(credits_eq(
+            school.get_course("CS101").unwrap().credit_hours,
+            0.0
+        ));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, ParseWarningKind::InvalidCreditHours);
+        assert!(warnings[0].message.contains("3.o"));
+
+        let strict = ParseOptions::default().with_strict_credits(true);
+        let err = parse_curriculum_reader(csv.as_bytes(), &strict).unwrap_err();
+        let warning = err.downcast_ref::<ParseWarning>().unwrap();
+        assert_eq!(warning.kind, ParseWarningKind::InvalidCreditHours);
+        assert_eq!(warning.line, 8);
+    }
+
+    #[test]
+    fn test_active_column_marks_suspended_courses() {
+        let csv = "Curriculum,Active\n\
+                   Institution,Test University\n\
+                   Degree Type,BS\n\
+                   System Type,semester\n\
+                   CIP,11.0701\n\
+                   Courses\n\
+                   Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours,Active\n\
+                   1,Intro to CS,CS,101,,,,4,\n\
+                   2,Data Structures,CS,201,1,,,4,No\n\
+                   3,Seminar,CS,190,,,,1,maybe\n";
+
+        let (school, warnings) =
+            parse_curriculum_reader_with_warnings(csv.as_bytes(), &ParseOptions::default())
+                .unwrap();
+
+        assert!(school.get_course("CS101").unwrap().active);
+        assert!(!school.get_course("CS201").unwrap().active);
+        assert!(school.get_course("CS190").unwrap().active);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, ParseWarningKind::InvalidActive);
+    }
+
+    #[test]
+    fn test_curriculum_template_parses() {
+        let (school, warnings) = parse_curriculum_reader_with_warnings(
+            curriculum_template().as_bytes(),
+            &ParseOptions::default(),
+        )
+        .unwrap();
+
+        assert!(warnings.is_empty());
+        assert_eq!(school.courses().len(), 2);
+        assert_eq!(school.degrees.len(), 1);
+        assert_eq!(school.plans.len(), 1);
+        assert_eq!(
+            school.get_course("CS201").unwrap().prerequisites,
+            vec!["CS101".to_string()]
+        );
+        assert!(school.validate_plans().is_ok());
+        assert!(school.validate_course_dependencies().is_ok());
+    }
+
+    #[test]
+    fn test_parse_utf16_le_matches_utf8() {
+        let sample = "samples/plans/Colostate_CSDegree.csv";
+        let utf8 = parse_curriculum_csv(sample).unwrap();
+
+        let text = fs::read_to_string(sample).unwrap();
+        let mut utf16 = vec![0xFF, 0xFE];
+        utf16.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+        let decoded = parse_curriculum_reader(utf16.as_slice(), &ParseOptions::default()).unwrap();
+
+        assert_eq!(decoded.content_hash(), utf8.content_hash());
+        assert_eq!(decoded.courses().len(), utf8.courses().len());
+    }
+
+    #[test]
+    fn test_parse_course_mapping_csv() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "Source,Target").unwrap();
+        writeln!(file, "CS 101,CS 1100").unwrap();
+        writeln!(file).unwrap();
+        writeln!(file, "MATH-150,MATH 1341").unwrap();
+
+        let mapping = parse_course_mapping_csv(file.path()).unwrap();
+        assert_eq!(mapping.len(), 2);
+        assert_eq!(mapping.get("CS101"), Some(&"CS 1100".to_string()));
+        assert_eq!(mapping.get("MATH150"), Some(&"MATH 1341".to_string()));
+    }
+
+    #[test]
+    fn test_parse_course_mapping_csv_rejects_short_rows() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "CS 101").unwrap();
+
+        assert!(parse_course_mapping_csv(file.path()).is_err());
+    }
+
+    #[test]
+    fn test_parse_course_mapping_csv_keeps_first_duplicate() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "CS 101,CS 1100").unwrap();
+        writeln!(file, "CS101,CS 1200").unwrap();
+
+        let mapping = parse_course_mapping_csv(file.path()).unwrap();
+        assert_eq!(mapping.len(), 1);
+        assert_eq!(mapping.get("CS101"), Some(&"CS 1100".to_string()));
+    }
 
     #[test]
     fn test_normalize_course_key() {
-        assert_eq!(normalize_course_key("CS 1800"), "CS1800");
-        assert_eq!(normalize_course_key("CS1800"), "CS1800");
-        assert_eq!(normalize_course_key("MATH 1342"), "MATH1342");
-        assert_eq!(normalize_course_key("CS 1800 (or coreq)"), "CS1800");
-        assert_eq!(normalize_course_key("  PHYS  1151  "), "PHYS1151");
+        let policy = KeyPolicy::Concatenated;
+        assert_eq!(normalize_course_key("CS 1800", policy), "CS1800");
+        assert_eq!(normalize_course_key("CS1800", policy), "CS1800");
+        assert_eq!(normalize_course_key("MATH 1342", policy), "MATH1342");
+        assert_eq!(normalize_course_key("CS 1800 (or coreq)", policy), "CS1800");
+        assert_eq!(normalize_course_key("  PHYS  1151  ", policy), "PHYS1151");
+        assert_eq!(normalize_course_key("CS-1800", policy), "CS1800");
+    }
+
+    #[test]
+    fn test_normalize_course_key_spaced() {
+        let policy = KeyPolicy::Spaced;
+        assert_eq!(normalize_course_key("CS 1800", policy), "CS 1800");
+        assert_eq!(normalize_course_key("CS-1800", policy), "CS 1800");
+        assert_eq!(normalize_course_key("  PHYS  1151  ", policy), "PHYS 1151");
+        assert_eq!(
+            normalize_course_key("CS 1800 (or coreq)", policy),
+            "CS 1800"
+        );
+    }
+
+    #[test]
+    fn test_normalize_course_key_hyphenated() {
+        let policy = KeyPolicy::Hyphenated;
+        assert_eq!(normalize_course_key("CS 1800", policy), "CS-1800");
+        assert_eq!(normalize_course_key("CS-1800", policy), "CS-1800");
+        assert_eq!(normalize_course_key("MATH 1342", policy), "MATH-1342");
     }
 
     #[test]
     fn test_normalize_course_key_empty() {
-        assert_eq!(normalize_course_key(""), "");
-        assert_eq!(normalize_course_key("   "), "");
+        assert_eq!(normalize_course_key("", KeyPolicy::Concatenated), "");
+        assert_eq!(normalize_course_key("   ", KeyPolicy::Spaced), "");
+    }
+
+    #[test]
+    fn test_parse_with_spaced_key_policy() {
+        let options = ParseOptions::default().with_key_policy(KeyPolicy::Spaced);
+        let school =
+            parse_curriculum_csv_with_options("samples/plans/Colostate_CSDegree.csv", &options)
+                .expect("parse curriculum");
+
+        let cs220 = school
+            .get_course("CS 220")
+            .expect("CS 220 stored with spaced key");
+        assert_eq!(cs220.key(), "CS 220");
+        assert!(cs220.prerequisites.contains(&"CS 150B".to_string()));
+        assert!(school.get_course("CS220").is_none());
+    }
+
+    #[test]
+    fn test_parse_with_normalized_prefix_case() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "Curriculum,Mixed Case").unwrap();
+        writeln!(file, "Institution,Test U").unwrap();
+        writeln!(file, "Degree Type,BS").unwrap();
+        writeln!(file, "System Type,semester").unwrap();
+        writeln!(file, "CIP,11.0701").unwrap();
+        writeln!(file, "Courses").unwrap();
+        writeln!(
+            file,
+            "Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours"
+        )
+        .unwrap();
+        writeln!(file, "1,Intro,cs,101,,,,3").unwrap();
+        writeln!(file, "2,Data Structures,CS,201,Cs 101,,,3").unwrap();
+        writeln!(file, "3,Algorithms,Cs,301,2,,,3").unwrap();
+        writeln!(file, "4,Systems,CS,350,cs101,,,3").unwrap();
+
+        // Without normalization, keys keep their case and the reference dangles
+        let school = parse_curriculum_csv(file.path()).unwrap();
+        assert!(school.get_course("cs101").is_some());
+        let cs201 = school.get_course("CS201").unwrap();
+        assert_eq!(cs201.prerequisites, vec!["Cs101".to_string()]);
+        assert!(school.get_course("Cs101").is_none());
+
+        let options = ParseOptions::default().with_normalize_prefix_case(true);
+        let school = parse_curriculum_csv_with_options(file.path(), &options).unwrap();
+        assert!(school.get_course("cs101").is_none());
+        let cs201 = school.get_course("CS201").unwrap();
+        assert_eq!(cs201.prerequisites, vec!["CS101".to_string()]);
+        assert!(school.get_course("CS101").is_some());
+        let cs301 = school.get_course("CS301").unwrap();
+        assert_eq!(cs301.prerequisites, vec!["CS201".to_string()]);
+        assert_eq!(cs301.prefix, "CS");
+        // A single-token key is uppercased too
+        let cs350 = school.get_course("CS350").unwrap();
+        assert_eq!(cs350.prerequisites, vec!["CS101".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_curriculum_reader_matches_file() {
+        let path = "samples/plans/Colostate_CSDegree.csv";
+        let bytes = fs::read(path).unwrap();
+
+        let from_reader = parse_curriculum_reader(bytes.as_slice(), &ParseOptions::default())
+            .expect("parse from reader");
+        let from_file = parse_curriculum_csv(path).expect("parse from file");
+
+        assert_eq!(from_reader.courses().len(), from_file.courses().len());
+        assert_eq!(
+            from_reader.get_course("CS220").map(|c| &c.prerequisites),
+            from_file.get_course("CS220").map(|c| &c.prerequisites)
+        );
+    }
+
+    #[test]
+    fn test_parse_equivalent_column() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "Curriculum,Cross Listed").unwrap();
+        writeln!(file, "Institution,Test U").unwrap();
+        writeln!(file, "Degree Type,BS").unwrap();
+        writeln!(file, "System Type,semester").unwrap();
+        writeln!(file, "CIP,11.0701").unwrap();
+        writeln!(file, "Courses").unwrap();
+        writeln!(
+            file,
+            "Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours,Equivalent"
+        )
+        .unwrap();
+        writeln!(file, "1,Discrete,CS,150,,,,4,").unwrap();
+        writeln!(file, "2,Discrete,MATH,150,,,,4,1").unwrap();
+
+        let school = parse_curriculum_csv(file.path()).unwrap();
+        let math150 = school.get_course("MATH150").unwrap();
+        assert_eq!(math150.equivalent_to.as_deref(), Some("CS150"));
+        assert!(school.get_course("CS150").unwrap().equivalent_to.is_none());
+        assert_eq!(school.build_dag().courses, vec!["CS150".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_category_column() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "Curriculum,Categories").unwrap();
+        writeln!(file, "Institution,Test U").unwrap();
+        writeln!(file, "Degree Type,BS").unwrap();
+        writeln!(file, "System Type,semester").unwrap();
+        writeln!(file, "CIP,11.0701").unwrap();
+        writeln!(file, "Courses").unwrap();
+        writeln!(
+            file,
+            "Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours,Category"
+        )
+        .unwrap();
+        writeln!(file, "1,Calculus I,MATH,1341,,,,4,Math").unwrap();
+        writeln!(file, "2,Intro,CS,101,,,,3,").unwrap();
+
+        let school = parse_curriculum_csv(file.path()).unwrap();
+        assert_eq!(
+            school.get_course("MATH1341").unwrap().category.as_deref(),
+            Some("Math")
+        );
+        assert!(school.get_course("CS101").unwrap().category.is_none());
+    }
+
+    #[test]
+    fn test_parse_notes_from_configured_column() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "Curriculum,Notes").unwrap();
+        writeln!(file, "Institution,Test U").unwrap();
+        writeln!(file, "Degree Type,BS").unwrap();
+        writeln!(file, "System Type,semester").unwrap();
+        writeln!(file, "CIP,11.0701").unwrap();
+        writeln!(file, "Courses").unwrap();
+        writeln!(
+            file,
+            "Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours,Comments"
+        )
+        .unwrap();
+        writeln!(file, "1,Seminar,CS,490,,,,1,Requires <dept> permission").unwrap();
+        writeln!(file, "2,Intro,CS,101,,,,3,").unwrap();
+
+        // Default column name is `Notes`, so `Comments` is ignored
+        let school = parse_curriculum_csv(file.path()).unwrap();
+        assert!(school.get_course("CS490").unwrap().notes.is_none());
+
+        let options = ParseOptions::default().with_notes_column("Comments");
+        let school = parse_curriculum_csv_with_options(file.path(), &options).unwrap();
+        assert_eq!(
+            school.get_course("CS490").unwrap().notes.as_deref(),
+            Some("Requires <dept> permission")
+        );
+        assert!(school.get_course("CS101").unwrap().notes.is_none());
+    }
+
+    #[test]
+    fn test_courses_marker_ignores_metadata_mentions() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "Curriculum,Courses for Success,,,").unwrap();
+        writeln!(file, "Institution,Test U,,,").unwrap();
+        writeln!(file, "Degree Type,BS,,,").unwrap();
+        writeln!(file, "System Type,semester,,,").unwrap();
+        writeln!(file, "CIP,11.0701,,,").unwrap();
+        writeln!(file, "Courses,,,,").unwrap();
+        writeln!(
+            file,
+            "Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours"
+        )
+        .unwrap();
+        writeln!(file, "1,Intro,CS,101,,,,3").unwrap();
+        writeln!(file, "2,Data Structures,CS,201,1,,,3").unwrap();
+
+        let school = parse_curriculum_csv(file.path()).unwrap();
+        assert_eq!(school.degrees[0].name, "Courses for Success");
+        assert_eq!(school.courses().len(), 2);
+        assert_eq!(
+            school.get_course("CS201").unwrap().prerequisites,
+            vec!["CS101".to_string()]
+        );
+
+        assert!(is_courses_marker("Courses"));
+        assert!(is_courses_marker("\u{feff}COURSES,,,"));
+        assert!(!is_courses_marker("Curriculum,Courses for Success"));
+        assert!(!is_courses_marker("Courses,Extra"));
     }
 
     #[test]
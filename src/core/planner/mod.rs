@@ -2,4 +2,8 @@
 
 pub mod csv_parser;
 
-pub use csv_parser::parse_curriculum_csv;
+pub use csv_parser::{
+    curriculum_template, parse_course_mapping_csv, parse_curriculum_csv,
+    parse_curriculum_csv_with_options, parse_curriculum_csv_with_warnings, parse_curriculum_reader,
+    parse_curriculum_reader_with_warnings, ParseOptions, ParseWarning, ParseWarningKind,
+};
@@ -0,0 +1,255 @@
+//! Combined report exporter
+//!
+//! Wraps the HTML or Markdown reporter and appends the plan's metrics and
+//! schedule as JSON in a `<script type="application/json">` block, so a single
+//! file serves both readers and tools. The block is invisible when the report
+//! is rendered; read it back by parsing the contents of the element with id
+//! [`DATA_ELEMENT_ID`].
+
+use super::{HtmlReporter, MarkdownReporter, ReportFormat};
+use crate::core::metrics::summary_rows;
+use crate::core::report::{ReportContext, ReportGenerator};
+use crate::json::json_string;
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// `id` of the embedded JSON `<script>` element
+pub const DATA_ELEMENT_ID: &str = "nuanalytics-data";
+
+/// Value of the embedded JSON's `format` field
+pub const REPORT_DATA_FORMAT: &str = "nuanalytics-report-data";
+
+/// Version of the embedded JSON's shape, written as its `schema_version`
+///
+/// Independent of the server's `METRICS_JSON_SCHEMA_VERSION`; bumped whenever a
+/// field is renamed, removed, or changes meaning. Adding a field does not bump it.
+pub const REPORT_DATA_SCHEMA_VERSION: u32 = 1;
+
+/// Report exporter that embeds machine-readable data in an HTML or Markdown report
+pub struct CombinedReporter {
+    /// Format of the human-readable report
+    base: ReportFormat,
+}
+
+impl CombinedReporter {
+    /// Create a combined reporter around an HTML or Markdown report
+    ///
+    /// # Errors
+    /// Returns an error for formats that cannot carry an embedded `<script>` block
+    pub fn new(base: ReportFormat) -> Result<Self, String> {
+        match base {
+            ReportFormat::Html | ReportFormat::Markdown => Ok(Self { base }),
+            other => Err(format!(
+                "Combined reports must be html or markdown, not {other}"
+            )),
+        }
+    }
+
+    /// Render the plan's metrics and schedule as a JSON object
+    ///
+    /// The object is identified by `format` ([`REPORT_DATA_FORMAT`]) and
+    /// `schema_version` ([`REPORT_DATA_SCHEMA_VERSION`]), followed by the plan,
+    /// institution, and summary metrics, a `courses` array in metrics table order,
+    /// and the term-by-term `schedule`. Credit values that are not finite numbers
+    /// are written as `null`.
+    #[must_use]
+    pub fn data_json(ctx: &ReportContext) -> String {
+        let summary = ctx.summary;
+        let mut json = String::from("{\n");
+        let _ = writeln!(json, "  \"format\": {},", json_string(REPORT_DATA_FORMAT));
+        let _ = writeln!(json, "  \"schema_version\": {REPORT_DATA_SCHEMA_VERSION},");
+        let _ = writeln!(json, "  \"plan\": {},", json_string(&ctx.plan.name));
+        let _ = writeln!(
+            json,
+            "  \"institution\": {},",
+            json_string(ctx.institution_name())
+        );
+        let _ = writeln!(json, "  \"degree\": {},", json_string(&ctx.degree_name()));
+        let _ = writeln!(
+            json,
+            "  \"total_credits\": {},",
+            json_number(ctx.total_credits())
+        );
+        let _ = writeln!(
+            json,
+            "  \"total_complexity\": {},",
+            summary.total_complexity
+        );
+        let _ = writeln!(json, "  \"longest_delay\": {},", summary.longest_delay);
+        let _ = writeln!(
+            json,
+            "  \"longest_delay_path\": {},",
+            json_array(&summary.longest_delay_path)
+        );
+        let _ = writeln!(
+            json,
+            "  \"highest_centrality\": {},",
+            summary.highest_centrality
+        );
+        let _ = writeln!(
+            json,
+            "  \"highest_centrality_course\": {},",
+            json_string(&summary.highest_centrality_course)
+        );
+
+        let courses: Vec<String> = summary_rows(ctx.plan, ctx.school, ctx.metrics, ctx.metrics_sort)
+            .into_iter()
+            .map(|row| {
+                format!(
+                    "    {{\"key\": {}, \"name\": {}, \"credits\": {}, \"complexity\": {}, \"blocking\": {}, \"delay\": {}, \"centrality\": {}}}",
+                    json_string(row.key),
+                    json_string(row.name.unwrap_or("")),
+                    json_number(row.credits),
+                    row.metrics.complexity,
+                    row.metrics.blocking,
+                    row.metrics.delay,
+                    row.metrics.centrality
+                )
+            })
+            .collect();
+        let _ = writeln!(json, "  \"courses\": [\n{}\n  ],", courses.join(",\n"));
+
+        let terms: Vec<String> = ctx
+            .term_plan
+            .terms
+            .iter()
+            .filter(|term| !term.courses.is_empty())
+            .map(|term| {
+                format!(
+                    "    {{\"term\": {}, \"credits\": {}, \"courses\": {}}}",
+                    term.number,
+                    json_number(term.total_credits),
+                    json_array(&term.courses)
+                )
+            })
+            .collect();
+        let _ = writeln!(json, "  \"schedule\": [\n{}\n  ],", terms.join(",\n"));
+        let _ = writeln!(
+            json,
+            "  \"unscheduled\": {}",
+            json_array(&ctx.term_plan.unscheduled)
+        );
+        json.push('}');
+        json
+    }
+
+    /// The `<script>` element carrying [`data_json`](Self::data_json)
+    fn data_block(ctx: &ReportContext) -> String {
+        // Escape `</` so course names cannot close the script element early
+        let json = Self::data_json(ctx).replace("</", "<\\/");
+        format!("<script type=\"application/json\" id=\"{DATA_ELEMENT_ID}\">\n{json}\n</script>\n")
+    }
+}
+
+impl ReportGenerator for CombinedReporter {
+    fn generate(&self, ctx: &ReportContext, output_path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(output_path, self.render(ctx)?)?;
+        Ok(())
+    }
+
+    fn render(&self, ctx: &ReportContext) -> Result<String, Box<dyn Error>> {
+        let block = Self::data_block(ctx);
+        if self.base == ReportFormat::Html {
+            let mut report = HtmlReporter::new().render(ctx)?;
+            match report.rfind("</body>") {
+                Some(end) => report.insert_str(end, &block),
+                None => report.push_str(&block),
+            }
+            Ok(report)
+        } else {
+            let mut report = MarkdownReporter::new().render(ctx)?;
+            if !report.ends_with('\n') {
+                report.push('\n');
+            }
+            report.push('\n');
+            report.push_str(&block);
+            Ok(report)
+        }
+    }
+}
+
+/// Render a number as JSON, using `null` for NaN and infinities
+fn json_number(value: f32) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+/// Render string values as a JSON array
+fn json_array(values: &[String]) -> String {
+    let items: Vec<String> = values.iter().map(|v| json_string(v)).collect();
+    format!("[{}]", items.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::metrics::compute_all_metrics;
+    use crate::core::metrics_export::CurriculumSummary;
+    use crate::core::planner::parse_curriculum_csv;
+    use crate::core::report::{SchedulerConfig, TermScheduler};
+
+    #[test]
+    fn test_combined_html_embeds_parseable_data() {
+        let school = parse_curriculum_csv("samples/plans/Colostate_CSDegree.csv").unwrap();
+        let plan = school.plans[0].clone();
+        let dag = school.build_dag();
+        let metrics = compute_all_metrics(&dag).unwrap();
+        let summary = CurriculumSummary::from_metrics(&plan, &school, &metrics);
+        let term_plan = TermScheduler::new(&school, &dag, SchedulerConfig::semester(15.0))
+            .schedule(&plan.courses);
+        let ctx = ReportContext::new(
+            &school,
+            &plan,
+            school.degrees.first(),
+            &metrics,
+            &summary,
+            &dag,
+            &term_plan,
+        );
+
+        let html = CombinedReporter::new(ReportFormat::Html)
+            .unwrap()
+            .render(&ctx)
+            .unwrap();
+
+        // The visible report is intact
+        assert!(html.contains(&plan.name));
+        assert!(html.contains("course-node"));
+        assert!(html.trim_end().ends_with("</html>"));
+
+        let open = format!("<script type=\"application/json\" id=\"{DATA_ELEMENT_ID}\">");
+        let start = html.find(&open).unwrap() + open.len();
+        let end = start + html[start..].find("</script>").unwrap();
+        let data: serde_json::Value = serde_json::from_str(&html[start..end]).unwrap();
+
+        assert_eq!(data["format"], REPORT_DATA_FORMAT);
+        assert_eq!(data["schema_version"], REPORT_DATA_SCHEMA_VERSION);
+        assert_eq!(data["plan"], plan.name.as_str());
+        assert_eq!(
+            data["courses"].as_array().unwrap().len(),
+            plan.courses.len()
+        );
+        let scheduled: usize = data["schedule"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|term| term["courses"].as_array().unwrap().len())
+            .sum();
+        let expected: usize = term_plan.terms.iter().map(|t| t.courses.len()).sum();
+        assert_eq!(scheduled, expected);
+
+        assert!(CombinedReporter::new(ReportFormat::Edges).is_err());
+    }
+
+    #[test]
+    fn test_json_number_writes_null_for_non_finite() {
+        assert_eq!(json_number(3.5), "3.5");
+        assert_eq!(json_number(f32::NAN), "null");
+        assert_eq!(json_number(f32::INFINITY), "null");
+    }
+}
@@ -0,0 +1,140 @@
+//! Edge-list CSV exporter
+//!
+//! Writes the requisite graph as raw `from,to,type` rows for import into graph
+//! tools such as networkx or Gephi. Edges point from the requisite to the course
+//! that requires it, so they follow the direction students progress through the
+//! curriculum.
+
+use crate::core::metrics_export::csv_field;
+use crate::core::models::DAG;
+use crate::core::report::{ReportContext, ReportGenerator};
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::fs;
+use std::path::Path;
+
+/// Kind of requisite relationship an edge represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeType {
+    /// Must be completed in an earlier term
+    Prerequisite,
+    /// May be taken earlier or in the same term
+    Corequisite,
+    /// Must be taken in the same term
+    Strict,
+}
+
+impl EdgeType {
+    /// Label written in the `type` column
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Prerequisite => "prerequisite",
+            Self::Corequisite => "corequisite",
+            Self::Strict => "strict",
+        }
+    }
+}
+
+impl fmt::Display for EdgeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Edge-list CSV exporter for the requisite graph
+pub struct EdgeListExporter;
+
+impl EdgeListExporter {
+    /// Create a new edge-list exporter
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Collect every edge in the DAG as `(from, to, type)`
+    ///
+    /// Edges are sorted by source then target so output is reproducible. Strict
+    /// corequisites are reported once, as `Strict`, rather than also as plain
+    /// corequisites.
+    #[must_use]
+    pub fn edges(dag: &DAG) -> Vec<(&str, &str, EdgeType)> {
+        let mut edges = Vec::new();
+        for course in &dag.courses {
+            for prereq in dag.dependencies.get(course).into_iter().flatten() {
+                edges.push((prereq.as_str(), course.as_str(), EdgeType::Prerequisite));
+            }
+            for coreq in dag.corequisites.get(course).into_iter().flatten() {
+                let edge_type = if dag.is_strict_corequisite(course, coreq) {
+                    EdgeType::Strict
+                } else {
+                    EdgeType::Corequisite
+                };
+                edges.push((coreq.as_str(), course.as_str(), edge_type));
+            }
+        }
+        edges.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+        edges
+    }
+
+    /// Render the DAG as an edge-list CSV with a `from,to,type` header
+    #[must_use]
+    pub fn render_dag(dag: &DAG) -> String {
+        let mut output = String::from("from,to,type\n");
+        for (from, to, edge_type) in Self::edges(dag) {
+            let _ = writeln!(output, "{},{},{edge_type}", csv_field(from), csv_field(to));
+        }
+        output
+    }
+}
+
+impl Default for EdgeListExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReportGenerator for EdgeListExporter {
+    fn generate(&self, ctx: &ReportContext, output_path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(output_path, self.render(ctx)?)?;
+        Ok(())
+    }
+
+    fn render(&self, ctx: &ReportContext) -> Result<String, Box<dyn Error>> {
+        Ok(Self::render_dag(ctx.dag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_list_covers_every_edge_with_type() {
+        let mut dag = DAG::new();
+        dag.add_prerequisite("CS201".to_string(), "CS101");
+        dag.add_prerequisite("CS301".to_string(), "CS201");
+        dag.add_prerequisite("CS301".to_string(), "MATH101");
+        dag.add_corequisite("PHYS101".to_string(), "MATH101");
+        dag.add_strict_corequisite("CS101".to_string(), "CS101L");
+
+        let total_edges: usize = dag.dependencies.values().map(Vec::len).sum::<usize>()
+            + dag.corequisites.values().map(Vec::len).sum::<usize>();
+
+        let csv = EdgeListExporter::render_dag(&dag);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("from,to,type"));
+        let rows: Vec<&str> = lines.collect();
+        assert_eq!(rows.len(), total_edges);
+
+        assert!(rows.contains(&"CS101,CS201,prerequisite"));
+        assert!(rows.contains(&"MATH101,CS301,prerequisite"));
+        assert!(rows.contains(&"MATH101,PHYS101,corequisite"));
+        assert!(rows.contains(&"CS101L,CS101,strict"));
+        assert_eq!(rows.iter().filter(|r| r.ends_with(",strict")).count(), 1);
+        assert_eq!(
+            rows.iter().filter(|r| r.ends_with(",corequisite")).count(),
+            1
+        );
+    }
+}
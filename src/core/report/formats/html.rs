@@ -3,8 +3,9 @@
 //! Generates curriculum reports in HTML format with grid-based visualization.
 //! The generated HTML is self-contained with embedded CSS and JavaScript.
 
-use crate::core::metrics::CourseMetrics;
-use crate::core::report::{ReportContext, ReportGenerator};
+use crate::core::metrics::{summary_rows, ComplexityThresholds, ComplexityTier};
+use crate::core::metrics_export::path_step_courses;
+use crate::core::report::{ReportContext, ReportGenerator, SvgScheduleGenerator};
 use std::error::Error;
 use std::fmt::Write;
 use std::fs;
@@ -35,8 +36,12 @@ impl HtmlReporter {
         output = output.replace("{{system_type}}", ctx.system_type());
         output = output.replace("{{cip_code}}", ctx.cip_code());
         output = output.replace("{{years}}", &format!("{:.0}", ctx.years()));
-        output = output.replace("{{total_credits}}", &format!("{:.1}", ctx.total_credits()));
+        output = output.replace(
+            "{{total_credits}}",
+            &ctx.format_credits(ctx.total_credits()),
+        );
         output = output.replace("{{course_count}}", &ctx.course_count().to_string());
+        output = output.replace("{{credits_per_year}}", &ctx.credits_per_year_summary());
 
         // Substitute summary metrics
         output = output.replace(
@@ -65,6 +70,31 @@ impl HtmlReporter {
         };
         output = output.replace("{{longest_delay_path}}", &delay_path);
 
+        // Generate complexity share by prefix
+        let prefix_breakdown = if ctx.summary.complexity_by_prefix_pct.is_empty() {
+            "N/A".to_string()
+        } else {
+            ctx.summary
+                .complexity_by_prefix_pct
+                .iter()
+                .map(|(prefix, pct)| format!("{prefix} {}%", ctx.format_decimal(*pct)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        output = output.replace("{{complexity_by_prefix}}", &prefix_breakdown);
+
+        // Generate potential dead-end courses
+        let dead_ends = if ctx.summary.dead_end_courses.is_empty() {
+            "None".to_string()
+        } else {
+            ctx.summary.dead_end_courses.join(", ")
+        };
+        output = output.replace("{{dead_end_courses}}", &dead_ends);
+
+        // Generate credit load warnings callout
+        let load_warnings = Self::generate_load_warnings_html(ctx);
+        output = output.replace("{{credit_load_warnings}}", &load_warnings);
+
         // Generate term schedule HTML
         let schedule_html = Self::generate_schedule_html(ctx);
         output = output.replace("{{term_schedule}}", &schedule_html);
@@ -97,26 +127,33 @@ impl HtmlReporter {
     /// Handles corequisite groups in the path (e.g., "(CSE1321+CSE1321L)") by
     /// extracting all individual course IDs for JavaScript highlighting.
     fn generate_critical_path_ids(ctx: &ReportContext) -> String {
-        let mut all_ids: Vec<String> = Vec::new();
-
-        for entry in &ctx.summary.longest_delay_path {
-            // Check if this is a grouped corequisite entry like "(A+B+C)"
-            let trimmed = entry.trim();
-            if trimmed.starts_with('(') && trimmed.ends_with(')') {
-                // Extract individual course IDs from the group
-                let inner = &trimmed[1..trimmed.len() - 1]; // Remove parens
-                for id in inner.split('+') {
-                    all_ids.push(format!("\"{}\"", id.trim()));
-                }
-            } else {
-                // Regular single course ID
-                all_ids.push(format!("\"{trimmed}\""));
-            }
-        }
+        let all_ids: Vec<String> = ctx
+            .summary
+            .longest_delay_path
+            .iter()
+            .flat_map(|entry| path_step_courses(entry))
+            .map(|id| format!("\"{id}\""))
+            .collect();
 
         format!("[{}]", all_ids.join(", "))
     }
 
+    /// Generate the credit load warnings callout (empty when there are none)
+    fn generate_load_warnings_html(ctx: &ReportContext) -> String {
+        let warnings = ctx.credit_load_warnings();
+        if warnings.is_empty() {
+            return String::new();
+        }
+
+        let mut html =
+            String::from("<div class=\"callout-warning\"><strong>Credit Load:</strong><ul>");
+        for warning in warnings {
+            let _ = write!(html, "<li>{warning}</li>");
+        }
+        html.push_str("</ul></div>");
+        html
+    }
+
     /// Generate HTML for the grid-based term visualization
     fn generate_term_graph(ctx: &ReportContext) -> String {
         let mut html = String::new();
@@ -132,28 +169,27 @@ impl HtmlReporter {
 
             for course_key in &term.courses {
                 let course = ctx.school.get_course(course_key);
-                let metrics = ctx.metrics.get(course_key);
+                let metrics = ctx.school.course_metrics(ctx.metrics, course_key);
 
                 let name = course.map_or("", |c| &c.name);
-                let short_name = if name.len() > 25 { &name[..22] } else { name };
+                let title = escape_html(name);
+                let label = escape_html(&truncate_label(name, ctx.course_label_width));
                 let complexity = metrics.map_or(0, |m| m.complexity);
 
-                let complexity_class = match complexity {
-                    0..=5 => "complexity-low",
-                    6..=15 => "complexity-medium",
-                    _ => "complexity-high",
-                };
+                let complexity_class =
+                    ComplexityTier::for_complexity(complexity, &ComplexityThresholds::default())
+                        .as_css_class();
 
                 let _ = writeln!(
                     html,
-                    "    <div class=\"course-node\" data-course-id=\"{course_key}\">"
+                    "    <div class=\"course-node\" data-course-id=\"{course_key}\" title=\"{title}\">"
                 );
                 let _ = writeln!(
                     html,
                     "      <span class=\"complexity-badge {complexity_class}\">{complexity}</span>"
                 );
                 let _ = writeln!(html, "      <div class=\"course-id\">{course_key}</div>");
-                let _ = writeln!(html, "      <div class=\"course-name\">{short_name}</div>");
+                let _ = writeln!(html, "      <div class=\"course-name\">{label}</div>");
                 let _ = writeln!(html, "    </div>");
             }
 
@@ -221,10 +257,10 @@ impl HtmlReporter {
 
             let _ = writeln!(
                 html,
-                "<tr><td>{}</td><td>{}</td><td>{:.1}</td></tr>",
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
                 term.number,
                 courses_html.join("<br>"),
-                term.total_credits
+                ctx.format_credits(term.total_credits)
             );
         }
 
@@ -244,33 +280,22 @@ impl HtmlReporter {
     fn generate_metrics_html(ctx: &ReportContext) -> String {
         let mut html = String::new();
 
-        // Sort courses by complexity (descending)
-        let mut courses: Vec<_> = ctx.plan.courses.iter().collect();
-        courses.sort_by(|a, b| {
-            let ma = ctx.metrics.get(*a).map_or(0, |m| m.complexity);
-            let mb = ctx.metrics.get(*b).map_or(0, |m| m.complexity);
-            mb.cmp(&ma)
-        });
-
-        for course_key in courses {
-            let course = ctx.school.get_course(course_key);
-            let metrics = ctx.metrics.get(course_key);
-
-            let name = course.map_or("-", |c| &c.name);
-            let credits = course.map_or(0.0, |c| c.credit_hours);
-            let (complexity, blocking, delay, centrality) =
-                metrics.map_or((0, 0, 0, 0), CourseMetrics::as_export_tuple);
+        for row in summary_rows(ctx.plan, ctx.school, ctx.metrics, ctx.metrics_sort) {
+            let course_key = row.key;
+            let name = row.name.unwrap_or("-");
+            let notes = row.notes.map_or_else(String::new, escape_html);
+            let credits = ctx.format_credits(row.credits);
+            let (complexity, blocking, delay, centrality) = row.metrics.as_export_tuple();
+            let paths = ctx.format_decimal(row.metrics.path_percentage());
 
             // Add complexity class for color coding
-            let complexity_class = match complexity {
-                0..=5 => "low",
-                6..=15 => "medium",
-                _ => "high",
-            };
+            let complexity_class =
+                ComplexityTier::for_complexity(complexity, &ComplexityThresholds::default())
+                    .as_css_class();
 
             let _ = writeln!(
                 html,
-                "<tr class=\"complexity-{complexity_class}\"><td>{course_key}</td><td>{name}</td><td>{credits:.1}</td><td>{complexity}</td><td>{blocking}</td><td>{delay}</td><td>{centrality}</td></tr>"
+                "<tr class=\"{complexity_class}\"><td>{course_key}</td><td>{name}</td><td>{credits}</td><td>{complexity}</td><td>{blocking}</td><td>{delay}</td><td>{centrality}</td><td>{paths}%</td><td class=\"notes\">{notes}</td></tr>"
             );
         }
 
@@ -280,80 +305,7 @@ impl HtmlReporter {
     /// Generate SVG paths with baked coordinates (server-side calculation)
     /// This avoids JavaScript positioning issues when printing to PDF
     fn generate_svg_paths(ctx: &ReportContext) -> String {
-        // Grid layout constants
-        const TERM_WIDTH: f32 = 130.0;
-        const TERM_X_OFFSET: f32 = 20.0;
-        const COURSE_HEIGHT: f32 = 115.0;
-        const COURSE_Y_OFFSET: f32 = 50.0;
-        const COURSE_CENTER_X: f32 = 65.0;
-        const COURSE_CENTER_Y: f32 = 30.0;
-
-        // Build position map: course_id -> (x, y)
-        let mut positions = std::collections::HashMap::new();
-        for (term_idx, term) in ctx.term_plan.terms.iter().enumerate() {
-            #[allow(clippy::cast_precision_loss)]
-            let term_x = (term_idx as f32).mul_add(TERM_WIDTH, TERM_X_OFFSET);
-            for (course_idx, course_key) in term.courses.iter().enumerate() {
-                #[allow(clippy::cast_precision_loss)]
-                let course_y = (course_idx as f32).mul_add(COURSE_HEIGHT, COURSE_Y_OFFSET);
-                positions.insert(
-                    course_key.clone(),
-                    (term_x + COURSE_CENTER_X, course_y + COURSE_CENTER_Y),
-                );
-            }
-        }
-
-        let mut paths = Vec::new();
-
-        // Generate prerequisite paths
-        for (course, prereqs) in &ctx.dag.dependencies {
-            if !ctx.plan.courses.contains(course) || !positions.contains_key(course) {
-                continue;
-            }
-            for prereq in prereqs {
-                if !ctx.plan.courses.contains(prereq) || !positions.contains_key(prereq) {
-                    continue;
-                }
-
-                if let (Some(&(x1, y1)), Some(&(x2, y2))) =
-                    (positions.get(prereq), positions.get(course))
-                {
-                    // Curved path: quadratic Bezier from prereq to course
-                    let mid_x = f32::midpoint(x1, x2);
-                    let mid_y = f32::midpoint(y1, y2);
-                    let path = format!(
-                        "<path class=\"prereq-line\" d=\"M {x1:.1} {y1:.1} Q {mid_x:.1} {mid_y:.1} {x2:.1} {y2:.1}\" data-from=\"{prereq}\" data-to=\"{course}\"></path>"
-                    );
-                    paths.push(path);
-                }
-            }
-        }
-
-        // Generate corequisite paths (dashed)
-        for (course, coreqs) in &ctx.dag.corequisites {
-            if !ctx.plan.courses.contains(course) || !positions.contains_key(course) {
-                continue;
-            }
-            for coreq in coreqs {
-                if !ctx.plan.courses.contains(coreq) || !positions.contains_key(coreq) {
-                    continue;
-                }
-
-                if let (Some(&(x1, y1)), Some(&(x2, y2))) =
-                    (positions.get(coreq), positions.get(course))
-                {
-                    // Curved path for corequisites
-                    let mid_x = f32::midpoint(x1, x2);
-                    let mid_y = f32::midpoint(y1, y2);
-                    let path = format!(
-                        "<path class=\"coreq-line\" d=\"M {x1:.1} {y1:.1} Q {mid_x:.1} {mid_y:.1} {x2:.1} {y2:.1}\" data-from=\"{coreq}\" data-to=\"{course}\"></path>"
-                    );
-                    paths.push(path);
-                }
-            }
-        }
-
-        paths.join("\n")
+        SvgScheduleGenerator::edge_paths(ctx.term_plan, ctx.dag, &ctx.plan.courses).join("\n")
     }
 
     /// Generate vis.js node and edge data as JSON arrays
@@ -365,6 +317,31 @@ impl HtmlReporter {
     }
 }
 
+/// Cut `name` to at most `width` characters, ending in `...` when shortened
+pub(crate) fn truncate_label(name: &str, width: usize) -> String {
+    if name.chars().count() <= width {
+        return name.to_string();
+    }
+    let kept: String = name.chars().take(width.saturating_sub(3)).collect();
+    format!("{kept}...")
+}
+
+/// Escape text for safe inclusion in HTML content and attribute values
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 impl Default for HtmlReporter {
     fn default() -> Self {
         Self::new()
@@ -390,6 +367,7 @@ mod tests {
     use crate::core::metrics_export::CurriculumSummary;
     use crate::core::models::{Course, Degree, Plan, School, DAG};
     use crate::core::report::term_scheduler::TermPlan;
+    use crate::core::report::{CreditLoadThresholds, NumberFormat};
     use std::collections::HashMap;
 
     fn create_test_context() -> (
@@ -439,6 +417,7 @@ mod tests {
                 blocking: 1,
                 delay: 1,
                 centrality: 1,
+                ..CourseMetrics::default()
             },
         );
         metrics.insert(
@@ -448,6 +427,7 @@ mod tests {
                 blocking: 0,
                 delay: 2,
                 centrality: 1,
+                ..CourseMetrics::default()
             },
         );
 
@@ -458,6 +438,8 @@ mod tests {
             longest_delay: 2,
             longest_delay_course: "CS201".to_string(),
             longest_delay_path: vec!["CS101".to_string(), "CS201".to_string()],
+            complexity_by_prefix_pct: vec![("CS".to_string(), 100.0)],
+            dead_end_courses: Vec::new(),
         };
 
         let mut dag = DAG::new();
@@ -532,6 +514,112 @@ mod tests {
         assert!(html.contains("CS201"));
     }
 
+    #[test]
+    fn test_render_with_comma_decimal_separator() {
+        let (mut school, mut plan, degree, metrics, summary, dag, term_plan) =
+            create_test_context();
+        school.add_course(Course::new(
+            "Capstone".to_string(),
+            "CS".to_string(),
+            "499".to_string(),
+            8.0,
+        ));
+        plan.add_course("CS499".to_string());
+
+        let ctx = ReportContext::new(
+            &school,
+            &plan,
+            Some(&degree),
+            &metrics,
+            &summary,
+            &dag,
+            &term_plan,
+        )
+        .with_number_format(NumberFormat::european());
+
+        let html = HtmlReporter::new().render(&ctx).unwrap();
+
+        assert!(html.contains("15,0"));
+        assert!(!html.contains("15.0"));
+        assert!(html.contains("<td>CS201</td><td>Data Structures</td><td>4,0</td>"));
+        assert!(html.contains("CS 100,0%"));
+    }
+
+    #[test]
+    fn test_credit_load_warnings_callout() {
+        let (school, plan, degree, metrics, summary, dag, mut term_plan) = create_test_context();
+        term_plan.terms[2].add_course("CS301".to_string(), 19.0);
+
+        let ctx = ReportContext::new(
+            &school,
+            &plan,
+            Some(&degree),
+            &metrics,
+            &summary,
+            &dag,
+            &term_plan,
+        )
+        .with_credit_load_thresholds(CreditLoadThresholds::new(18.0, 3.0));
+
+        assert_eq!(
+            ctx.credit_load_warnings(),
+            vec!["Term 3 exceeds 18 credits (19.0)".to_string()]
+        );
+
+        let html = HtmlReporter::new().render(&ctx).unwrap();
+        assert!(html.contains("<li>Term 3 exceeds 18 credits (19.0)</li>"));
+        assert!(!html.contains("{{credit_load_warnings}}"));
+    }
+
+    #[test]
+    fn test_course_notes_rendered_escaped() {
+        let (mut school, plan, degree, metrics, summary, dag, term_plan) = create_test_context();
+        school
+            .get_course_mut("CS201")
+            .unwrap()
+            .set_notes("Offered <b>alternate</b> years & \"by permission\"".to_string());
+
+        let ctx = ReportContext::new(
+            &school,
+            &plan,
+            Some(&degree),
+            &metrics,
+            &summary,
+            &dag,
+            &term_plan,
+        );
+        let html = HtmlReporter::new().render(&ctx).unwrap();
+
+        assert!(html.contains(
+            "<td class=\"notes\">Offered &lt;b&gt;alternate&lt;/b&gt; years &amp; &quot;by permission&quot;</td>"
+        ));
+        assert!(!html.contains("<b>alternate</b>"));
+    }
+
+    #[test]
+    fn test_truncated_course_label_keeps_full_name_in_title() {
+        let (mut school, plan, degree, metrics, summary, dag, term_plan) = create_test_context();
+        school.get_course_mut("CS201").unwrap().name =
+            "Data Structures & Algorithms for Engineers".to_string();
+
+        let ctx = ReportContext::new(
+            &school,
+            &plan,
+            Some(&degree),
+            &metrics,
+            &summary,
+            &dag,
+            &term_plan,
+        )
+        .with_course_label_width(12);
+        let graph = HtmlReporter::generate_term_graph(&ctx);
+
+        assert!(graph.contains("title=\"Data Structures &amp; Algorithms for Engineers\""));
+        assert!(graph.contains("<div class=\"course-name\">Data Stru...</div>"));
+        assert!(graph.contains("title=\"Intro to CS\""));
+        assert!(graph.contains("<div class=\"course-name\">Intro to CS</div>"));
+    }
+
     #[test]
     fn test_generate_critical_path_ids() {
         let (school, plan, degree, metrics, summary, dag, term_plan) = create_test_context();
@@ -563,6 +651,8 @@ mod tests {
             longest_delay: 2,
             longest_delay_course: "CS201".to_string(),
             longest_delay_path: vec!["(CS101+CS101L)".to_string(), "CS201".to_string()],
+            complexity_by_prefix_pct: Vec::new(),
+            dead_end_courses: Vec::new(),
         };
 
         let (school, plan, degree, metrics, _, dag, term_plan) = create_test_context();
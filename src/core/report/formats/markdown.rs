@@ -3,7 +3,7 @@
 //! Generates curriculum reports in Markdown format with embedded Mermaid diagrams
 //! for visualization. These reports render well in GitHub, GitLab, and VS Code.
 
-use crate::core::metrics::CourseMetrics;
+use crate::core::metrics::summary_rows;
 use crate::core::report::visualization::MermaidGenerator;
 use crate::core::report::{ReportContext, ReportGenerator};
 use std::error::Error;
@@ -36,8 +36,12 @@ impl MarkdownReporter {
         output = output.replace("{{system_type}}", ctx.system_type());
         output = output.replace("{{years}}", &format!("{:.0}", ctx.years()));
         output = output.replace("{{cip_code}}", ctx.cip_code());
-        output = output.replace("{{total_credits}}", &format!("{:.1}", ctx.total_credits()));
+        output = output.replace(
+            "{{total_credits}}",
+            &ctx.format_credits(ctx.total_credits()),
+        );
         output = output.replace("{{course_count}}", &ctx.course_count().to_string());
+        output = output.replace("{{credits_per_year}}", &ctx.credits_per_year_summary());
 
         // Substitute summary metrics
         output = output.replace(
@@ -66,6 +70,18 @@ impl MarkdownReporter {
         };
         output = output.replace("{{longest_delay_path}}", &delay_path);
 
+        // Generate complexity share by prefix
+        let prefix_table = Self::generate_prefix_table(ctx);
+        output = output.replace("{{complexity_by_prefix}}", &prefix_table);
+
+        // Generate potential dead-end courses
+        let dead_ends = if ctx.summary.dead_end_courses.is_empty() {
+            "None".to_string()
+        } else {
+            ctx.summary.dead_end_courses.join(", ")
+        };
+        output = output.replace("{{dead_end_courses}}", &dead_ends);
+
         // Generate term schedule table
         let schedule_table = Self::generate_schedule_table(ctx);
         output = output.replace("{{term_schedule}}", &schedule_table);
@@ -86,6 +102,21 @@ impl MarkdownReporter {
         output
     }
 
+    /// Generate the complexity-share-by-prefix table
+    fn generate_prefix_table(ctx: &ReportContext) -> String {
+        if ctx.summary.complexity_by_prefix_pct.is_empty() {
+            return "N/A".to_string();
+        }
+
+        let mut table = String::new();
+        table.push_str("| Prefix | Share of Complexity |\n");
+        table.push_str("|---|---|\n");
+        for (prefix, pct) in &ctx.summary.complexity_by_prefix_pct {
+            let _ = writeln!(table, "| {prefix} | {}% |", ctx.format_decimal(*pct));
+        }
+        table
+    }
+
     /// Generate the term-by-term schedule table
     fn generate_schedule_table(ctx: &ReportContext) -> String {
         let mut table = String::new();
@@ -111,10 +142,10 @@ impl MarkdownReporter {
 
             let _ = writeln!(
                 table,
-                "| {} | {} | {:.1} |",
+                "| {} | {} | {} |",
                 term.number,
                 courses_str.join(", "),
-                term.total_credits
+                ctx.format_credits(term.total_credits)
             );
         }
 
@@ -134,30 +165,21 @@ impl MarkdownReporter {
     fn generate_metrics_table(ctx: &ReportContext) -> String {
         let mut table = String::new();
 
-        table
-            .push_str("| Course | Name | Credits | Complexity | Blocking | Delay | Centrality |\n");
-        table.push_str("|---|---|---|---|---|---|---|\n");
-
-        // Sort courses by complexity (descending)
-        let mut courses: Vec<_> = ctx.plan.courses.iter().collect();
-        courses.sort_by(|a, b| {
-            let ma = ctx.metrics.get(*a).map_or(0, |m| m.complexity);
-            let mb = ctx.metrics.get(*b).map_or(0, |m| m.complexity);
-            mb.cmp(&ma)
-        });
-
-        for course_key in courses {
-            let course = ctx.school.get_course(course_key);
-            let metrics = ctx.metrics.get(course_key);
-
-            let name = course.map_or("-", |c| &c.name);
-            let credits = course.map_or(0.0, |c| c.credit_hours);
-            let (complexity, blocking, delay, centrality) =
-                metrics.map_or((0, 0, 0, 0), CourseMetrics::as_export_tuple);
+        table.push_str(
+            "| Course | Name | Credits | Complexity | Blocking | Delay | Centrality | Path Share |\n",
+        );
+        table.push_str("|---|---|---|---|---|---|---|---|\n");
+
+        for row in summary_rows(ctx.plan, ctx.school, ctx.metrics, ctx.metrics_sort) {
+            let course_key = row.key;
+            let name = row.name.unwrap_or("-");
+            let credits = ctx.format_credits(row.credits);
+            let (complexity, blocking, delay, centrality) = row.metrics.as_export_tuple();
+            let paths = ctx.format_decimal(row.metrics.path_percentage());
 
             let _ = writeln!(
                 table,
-                "| {course_key} | {name} | {credits:.1} | {complexity} | {blocking} | {delay} | {centrality} |"
+                "| {course_key} | {name} | {credits} | {complexity} | {blocking} | {delay} | {centrality} | {paths}% |"
             );
         }
 
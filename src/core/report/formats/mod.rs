@@ -1,13 +1,21 @@
 //! Report format implementations
 //!
-//! Provides exporters for different report formats: Markdown, HTML, and PDF.
+//! Provides exporters for different report formats: Markdown, HTML, PDF, a raw
+//! edge-list CSV of the requisite graph, and combined HTML/Markdown reports with
+//! embedded JSON data.
 
+pub mod combined;
+pub mod edges;
 pub mod html;
 pub mod markdown;
+#[cfg(feature = "pdf")]
 pub mod pdf;
 
+pub use combined::CombinedReporter;
+pub use edges::EdgeListExporter;
 pub use html::HtmlReporter;
 pub use markdown::MarkdownReporter;
+#[cfg(feature = "pdf")]
 pub use pdf::PdfReporter;
 
 use std::fmt;
@@ -22,9 +30,26 @@ pub enum ReportFormat {
     Html,
     /// PDF format (generated from HTML)
     Pdf,
+    /// Edge-list CSV of the requisite graph (`from,to,type`)
+    Edges,
 }
 
 impl ReportFormat {
+    /// Every report format, whether or not it is compiled into this build
+    pub const ALL: [Self; 4] = [Self::Markdown, Self::Html, Self::Pdf, Self::Edges];
+
+    /// Whether this format can be generated by the current build
+    ///
+    /// PDF output requires the `pdf` feature (on by default); the other formats
+    /// are always available.
+    #[must_use]
+    pub const fn is_available(self) -> bool {
+        match self {
+            Self::Pdf => cfg!(feature = "pdf"),
+            Self::Markdown | Self::Html | Self::Edges => true,
+        }
+    }
+
     /// Get the file extension for this format
     #[must_use]
     pub const fn extension(&self) -> &'static str {
@@ -32,6 +57,7 @@ impl ReportFormat {
             Self::Markdown => "md",
             Self::Html => "html",
             Self::Pdf => "pdf",
+            Self::Edges => "csv",
         }
     }
 }
@@ -44,6 +70,7 @@ impl FromStr for ReportFormat {
             "md" | "markdown" => Ok(Self::Markdown),
             "html" | "htm" => Ok(Self::Html),
             "pdf" => Ok(Self::Pdf),
+            "edges" => Ok(Self::Edges),
             _ => Err(format!("Unknown report format: {s}")),
         }
     }
@@ -55,6 +82,36 @@ impl fmt::Display for ReportFormat {
             Self::Markdown => write!(f, "markdown"),
             Self::Html => write!(f, "html"),
             Self::Pdf => write!(f, "pdf"),
+            Self::Edges => write!(f, "edges"),
         }
     }
 }
+
+/// Report formats that can be generated by the current build
+#[must_use]
+pub fn available_formats() -> Vec<ReportFormat> {
+    ReportFormat::ALL
+        .into_iter()
+        .filter(|format| format.is_available())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_availability_follows_features() {
+        assert!(ReportFormat::Markdown.is_available());
+        assert!(ReportFormat::Html.is_available());
+        assert_eq!(ReportFormat::Pdf.is_available(), cfg!(feature = "pdf"));
+
+        let available = available_formats();
+        assert!(available.contains(&ReportFormat::Markdown));
+        assert!(available.contains(&ReportFormat::Html));
+        assert_eq!(
+            available.contains(&ReportFormat::Pdf),
+            cfg!(feature = "pdf")
+        );
+    }
+}
@@ -7,15 +7,128 @@ pub mod formats;
 pub mod term_scheduler;
 pub mod visualization;
 
-use crate::core::metrics::CurriculumMetrics;
+use crate::core::metrics::{CurriculumMetrics, MetricsSort};
 use crate::core::metrics_export::CurriculumSummary;
 use crate::core::models::{Degree, Plan, School, DAG};
 use std::error::Error;
 use std::path::Path;
 
-pub use formats::{HtmlReporter, MarkdownReporter, PdfReporter, ReportFormat};
-pub use term_scheduler::{SchedulerConfig, TermPlan, TermScheduler};
-pub use visualization::MermaidGenerator;
+#[cfg(feature = "pdf")]
+pub use formats::PdfReporter;
+pub use formats::{
+    available_formats, CombinedReporter, EdgeListExporter, HtmlReporter, MarkdownReporter,
+    ReportFormat,
+};
+pub use term_scheduler::{
+    PlacementReason, SchedulerConfig, SchedulerEvent, TermPlan, TermScheduler,
+};
+pub use visualization::{MermaidGenerator, SvgScheduleGenerator};
+
+/// Number formatting used for credit and metric values in reports
+///
+/// Defaults to the US convention with a `.` decimal separator and no digit
+/// grouping, matching the historical report output. European reports typically
+/// use [`NumberFormat::european`] (`15,0`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Character separating the integer and fractional parts
+    pub decimal_separator: char,
+    /// Optional character grouping the integer part into thousands
+    pub thousands_separator: Option<char>,
+}
+
+impl NumberFormat {
+    /// US number format: `.` decimal separator, no thousands separator
+    #[must_use]
+    pub const fn us() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: None,
+        }
+    }
+
+    /// European number format: `,` decimal separator, no thousands separator
+    #[must_use]
+    pub const fn european() -> Self {
+        Self {
+            decimal_separator: ',',
+            thousands_separator: None,
+        }
+    }
+
+    /// Set the decimal separator
+    #[must_use]
+    pub const fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Set the thousands separator (`None` disables grouping)
+    #[must_use]
+    pub const fn with_thousands_separator(mut self, separator: Option<char>) -> Self {
+        self.thousands_separator = separator;
+        self
+    }
+
+    /// Format a value with the given number of decimal places
+    #[must_use]
+    pub fn format(&self, value: f32, precision: usize) -> String {
+        let formatted = format!("{value:.precision$}");
+        let (sign, unsigned) = formatted
+            .strip_prefix('-')
+            .map_or(("", formatted.as_str()), |rest| ("-", rest));
+        let (integer, fraction) = unsigned
+            .split_once('.')
+            .map_or((unsigned, None), |(i, f)| (i, Some(f)));
+
+        let mut output = String::from(sign);
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                if let Some(separator) = self.thousands_separator {
+                    output.push(separator);
+                }
+            }
+            output.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            output.push(self.decimal_separator);
+            output.push_str(fraction);
+        }
+        output
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::us()
+    }
+}
+
+/// Per-term credit thresholds used to flag overloaded or underloaded terms
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CreditLoadThresholds {
+    /// Terms with more credits than this are flagged as overloaded
+    pub high: f32,
+    /// Non-empty terms with fewer credits than this are flagged as underloaded
+    pub low: f32,
+}
+
+impl CreditLoadThresholds {
+    /// Create thresholds from high and low credit limits
+    #[must_use]
+    pub const fn new(high: f32, low: f32) -> Self {
+        Self { high, low }
+    }
+}
+
+impl Default for CreditLoadThresholds {
+    fn default() -> Self {
+        Self::new(18.0, 12.0)
+    }
+}
+
+/// Default [`ReportContext::course_label_width`]
+pub const DEFAULT_COURSE_LABEL_WIDTH: usize = 25;
 
 /// Data context for report generation
 ///
@@ -37,6 +150,16 @@ pub struct ReportContext<'a> {
     pub dag: &'a DAG,
     /// Term-by-term course schedule
     pub term_plan: &'a TermPlan,
+    /// Number format for credit and metric values
+    pub number_format: NumberFormat,
+    /// Thresholds for per-term credit load warnings
+    pub credit_load_thresholds: CreditLoadThresholds,
+    /// Row order of the course metrics table
+    pub metrics_sort: MetricsSort,
+    /// Longest course name shown on a schedule grid node, in characters
+    pub course_label_width: usize,
+    /// Decimal places used when displaying credit hours
+    pub credit_precision: usize,
 }
 
 impl<'a> ReportContext<'a> {
@@ -59,9 +182,68 @@ impl<'a> ReportContext<'a> {
             summary,
             dag,
             term_plan,
+            number_format: NumberFormat::us(),
+            credit_load_thresholds: CreditLoadThresholds::new(18.0, 12.0),
+            metrics_sort: MetricsSort::Complexity,
+            course_label_width: DEFAULT_COURSE_LABEL_WIDTH,
+            credit_precision: 1,
         }
     }
 
+    /// Use the given thresholds for credit load warnings
+    #[must_use]
+    pub const fn with_credit_load_thresholds(mut self, thresholds: CreditLoadThresholds) -> Self {
+        self.credit_load_thresholds = thresholds;
+        self
+    }
+
+    /// Order the course metrics table by the given column
+    #[must_use]
+    pub const fn with_metrics_sort(mut self, sort: MetricsSort) -> Self {
+        self.metrics_sort = sort;
+        self
+    }
+
+    /// Truncate course names on schedule grid nodes to `width` characters
+    ///
+    /// Longer names end in `...`; the full name is still available as a tooltip.
+    #[must_use]
+    pub const fn with_course_label_width(mut self, width: usize) -> Self {
+        self.course_label_width = width;
+        self
+    }
+
+    /// Use the given number format for credit and metric values
+    #[must_use]
+    pub const fn with_number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = number_format;
+        self
+    }
+
+    /// Display credit hours with `precision` decimal places (default 1)
+    ///
+    /// Only affects rendering; credits keep full precision in the model and JSON.
+    #[must_use]
+    pub const fn with_credit_precision(mut self, precision: usize) -> Self {
+        self.credit_precision = precision;
+        self
+    }
+
+    /// Format a value with one decimal place using the context's number format
+    #[must_use]
+    pub fn format_decimal(&self, value: f32) -> String {
+        self.number_format.format(value, 1)
+    }
+
+    /// Format credit hours with the context's credit precision and number format
+    ///
+    /// Totals are rounded once for display, so three 1.33-credit courses show
+    /// as `4.0` at the default precision.
+    #[must_use]
+    pub fn format_credits(&self, credits: f32) -> String {
+        self.number_format.format(credits, self.credit_precision)
+    }
+
     /// Get the institution name
     #[must_use]
     pub fn institution_name(&self) -> &str {
@@ -90,15 +272,51 @@ impl<'a> ReportContext<'a> {
         self.degree.map_or("", |d| d.cip_code.as_str())
     }
 
-    /// Calculate total credit hours
+    /// Calculate total credit hours (cross-listed courses count once)
     #[must_use]
     pub fn total_credits(&self) -> f32 {
-        self.plan
-            .courses
+        self.school.total_credits(self.plan)
+    }
+
+    /// Total credit hours converted to semester-hour equivalents
+    ///
+    /// Applies the degree's [`Degree::credit_conversion_factor`] so quarter and
+    /// semester plans can be compared directly. Unchanged when no factor is set.
+    #[must_use]
+    pub fn semester_equivalent_credits(&self) -> f32 {
+        let factor = self.degree.map_or(1.0, Degree::credit_conversion_factor);
+        self.total_credits() * factor
+    }
+
+    /// Human-readable warnings for terms whose credit load is outside the thresholds
+    ///
+    /// Empty terms are ignored. Messages look like `Term 3 exceeds 18 credits (19.0)`
+    /// or `Term 5 has only 6.0 credits (below 12)`.
+    #[must_use]
+    pub fn credit_load_warnings(&self) -> Vec<String> {
+        let CreditLoadThresholds { high, low } = self.credit_load_thresholds;
+
+        self.term_plan
+            .terms
             .iter()
-            .filter_map(|key| self.school.get_course(key))
-            .map(|c| c.credit_hours)
-            .sum()
+            .filter(|term| !term.courses.is_empty())
+            .filter_map(|term| {
+                let credits = self.format_credits(term.total_credits);
+                if term.total_credits > high {
+                    Some(format!(
+                        "Term {} exceeds {high} credits ({credits})",
+                        term.number
+                    ))
+                } else if term.total_credits < low {
+                    Some(format!(
+                        "Term {} has only {credits} credits (below {low})",
+                        term.number
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect()
     }
 
     /// Get course count
@@ -112,12 +330,25 @@ impl<'a> ReportContext<'a> {
     #[must_use]
     pub fn years(&self) -> f32 {
         let terms_used = self.term_plan.terms_used();
-        let terms_per_year = if self.term_plan.is_quarter_system {
-            3.0 // quarters per year
-        } else {
-            2.0 // semesters per year
-        };
-        (terms_used as f32 / terms_per_year).ceil()
+        let terms_per_year = self.term_plan.terms_per_year();
+        (terms_used as f32 / terms_per_year as f32).ceil()
+    }
+
+    /// Credits per academic year as `Year 1: 30.0, Year 2: 31.0, ...`
+    ///
+    /// Returns `N/A` when nothing is scheduled.
+    #[must_use]
+    pub fn credits_per_year_summary(&self) -> String {
+        let years = self.term_plan.credits_per_year();
+        if years.is_empty() {
+            return "N/A".to_string();
+        }
+        years
+            .iter()
+            .enumerate()
+            .map(|(idx, credits)| format!("Year {}: {}", idx + 1, self.format_credits(*credits)))
+            .collect::<Vec<_>>()
+            .join(", ")
     }
 }
 
@@ -135,3 +366,134 @@ pub trait ReportGenerator {
     /// Returns an error if report generation fails
     fn render(&self, ctx: &ReportContext) -> Result<String, Box<dyn Error>>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{NumberFormat, ReportContext, TermPlan};
+    use crate::core::approx::credits_eq;
+    use crate::core::metrics::CurriculumMetrics;
+    use crate::core::metrics_export::CurriculumSummary;
+    use crate::core::models::{Course, Degree, Plan, School, DAG};
+
+    #[test]
+    fn number_format_us_and_european() {
+        assert_eq!(NumberFormat::us().format(15.0, 1), "15.0");
+        assert_eq!(NumberFormat::us().format(1234.5, 1), "1234.5");
+        assert_eq!(NumberFormat::european().format(15.0, 1), "15,0");
+        assert_eq!(NumberFormat::european().format(-0.25, 2), "-0,25");
+        assert_eq!(
+            NumberFormat::european()
+                .with_thousands_separator(Some('.'))
+                .format(1_234_567.5, 1),
+            "1.234.567,5"
+        );
+        assert_eq!(
+            NumberFormat::us()
+                .with_thousands_separator(Some(','))
+                .format(123.0, 0),
+            "123"
+        );
+    }
+
+    #[test]
+    fn semester_equivalent_credits_converts_quarter_plan() {
+        let mut school = School::new("Quarter University".to_string());
+        let mut plan = Plan::new("Quarter Plan".to_string(), "BS CS".to_string());
+        for (number, credits) in [("101", 5.0), ("102", 5.0), ("103", 5.0), ("104", 4.0)] {
+            school.add_course(Course::new(
+                format!("Course {number}"),
+                "CS".to_string(),
+                number.to_string(),
+                credits,
+            ));
+            plan.add_course(format!("CS{number}"));
+        }
+
+        let metrics = CurriculumMetrics::new();
+        let summary = CurriculumSummary {
+            total_complexity: 0,
+            highest_centrality: 0,
+            highest_centrality_course: String::new(),
+            longest_delay: 0,
+            longest_delay_course: String::new(),
+            longest_delay_path: Vec::new(),
+            complexity_by_prefix_pct: Vec::new(),
+            dead_end_courses: Vec::new(),
+        };
+        let dag = DAG::new();
+        let term_plan = TermPlan::new(12, true, 15.0);
+
+        let quarter = Degree::new(
+            "Computer Science".to_string(),
+            "BS".to_string(),
+            "11.0701".to_string(),
+            "quarter".to_string(),
+        );
+        let ctx = ReportContext::new(
+            &school,
+            &plan,
+            Some(&quarter),
+            &metrics,
+            &summary,
+            &dag,
+            &term_plan,
+        );
+        // No factor set: credits are left as-is
+        assert!(credits_eq(ctx.semester_equivalent_credits(), 19.0));
+
+        let converted = quarter.with_credit_conversion_factor(2.0 / 3.0);
+        let ctx = ReportContext::new(
+            &school,
+            &plan,
+            Some(&converted),
+            &metrics,
+            &summary,
+            &dag,
+            &term_plan,
+        );
+        assert!(credits_eq(ctx.total_credits(), 19.0));
+        assert!(credits_eq(
+            ctx.semester_equivalent_credits(),
+            19.0 * 2.0 / 3.0
+        ));
+    }
+
+    #[test]
+    fn credit_precision_rounds_display_not_storage() {
+        let mut school = School::new("Quarter-Credit University".to_string());
+        let mut plan = Plan::new("Labs".to_string(), "BS CS".to_string());
+        let mut term_plan = TermPlan::new(1, false, 15.0);
+        for number in ["101L", "102L", "103L"] {
+            school.add_course(Course::new(
+                format!("Lab {number}"),
+                "CS".to_string(),
+                number.to_string(),
+                1.33,
+            ));
+            plan.add_course(format!("CS{number}"));
+            term_plan.terms[0].add_course(format!("CS{number}"), 1.33);
+        }
+
+        let metrics = CurriculumMetrics::new();
+        let summary = CurriculumSummary {
+            total_complexity: 0,
+            highest_centrality: 0,
+            highest_centrality_course: String::new(),
+            longest_delay: 0,
+            longest_delay_course: String::new(),
+            longest_delay_path: Vec::new(),
+            complexity_by_prefix_pct: Vec::new(),
+            dead_end_courses: Vec::new(),
+        };
+        let dag = DAG::new();
+        let ctx = ReportContext::new(&school, &plan, None, &metrics, &summary, &dag, &term_plan);
+
+        assert!(credits_eq(term_plan.terms[0].total_credits, 3.99));
+        assert!(credits_eq(ctx.total_credits(), 3.99));
+        assert_eq!(ctx.format_credits(ctx.total_credits()), "4.0");
+        assert_eq!(ctx.credits_per_year_summary(), "Year 1: 4.0");
+
+        let precise = ctx.with_credit_precision(2);
+        assert_eq!(precise.format_credits(precise.total_credits()), "3.99");
+    }
+}
@@ -1,14 +1,15 @@
 //! Term scheduler for distributing courses across semesters/quarters
 //!
 //! This module implements a scheduling algorithm that:
-//! 1. Prioritizes courses with long prerequisite chains (high delay factor)
+//! 1. Prioritizes courses with long prerequisite chains (high delay factor) and
+//!    gateway courses that block many others (high blocking factor)
 //! 2. Groups corequisites and strict corequisites into the same term
 //! 3. Respects prerequisite constraints (prerequisites must come before dependents)
 //! 4. Balances credit hours across terms (~15 credits/term for semesters)
 //! 5. Fills in low-complexity courses to balance underloaded terms
 
-use crate::core::metrics::compute_delay;
-use crate::core::models::{School, DAG};
+use crate::core::metrics::{compute_blocking, compute_delay};
+use crate::core::models::{Course, Degree, Plan, School, TermOffering, TermParity, DAG};
 use std::collections::{BinaryHeap, HashMap, HashSet};
 
 /// Priority queue item for topological group ordering
@@ -53,6 +54,16 @@ pub const SEMESTER_TERMS: usize = 8;
 /// Number of terms in a standard 4-year quarter plan
 pub const QUARTER_TERMS: usize = 12;
 
+/// Default credit-equivalent weight of each already-scheduled course when
+/// choosing a term for filler courses
+pub const DEFAULT_COURSE_COUNT_WEIGHT: f32 = 1.0;
+
+/// Most semesters the scheduler will ever create (ten years)
+pub const SEMESTER_ABSOLUTE_MAX_TERMS: usize = 20;
+
+/// Most quarters the scheduler will ever create (ten years)
+pub const QUARTER_ABSOLUTE_MAX_TERMS: usize = 30;
+
 /// A single term in the schedule with its assigned courses
 #[derive(Debug, Clone, Default)]
 pub struct Term {
@@ -108,6 +119,46 @@ impl TermPlan {
         }
     }
 
+    /// Build a term plan from the terms recorded in the source CSV
+    ///
+    /// Places each plan course in its parsed `Term` without running the
+    /// scheduler, so hand-built plans render exactly as written. Courses with no
+    /// recorded term (or missing from the school) go to `unscheduled`. The plan
+    /// has as many terms as the highest term used.
+    #[must_use]
+    pub fn from_course_terms(school: &School, plan: &Plan) -> Self {
+        let is_quarter_system = school
+            .get_degree(&plan.degree_id)
+            .is_some_and(Degree::is_quarter_system);
+        let target_credits = if is_quarter_system {
+            DEFAULT_QUARTER_CREDITS
+        } else {
+            DEFAULT_SEMESTER_CREDITS
+        };
+
+        let placed: Vec<(&String, usize, f32)> = plan
+            .courses
+            .iter()
+            .filter_map(|key| {
+                let course = school.get_course(key)?;
+                Some((key, course.term?, course.credit_hours))
+            })
+            .collect();
+        let num_terms = placed.iter().map(|&(_, term, _)| term).max().unwrap_or(0);
+
+        let mut term_plan = Self::new(num_terms, is_quarter_system, target_credits);
+        for &(key, term, credits) in &placed {
+            term_plan.terms[term - 1].add_course(key.clone(), credits);
+        }
+        term_plan.unscheduled = plan
+            .courses
+            .iter()
+            .filter(|key| !placed.iter().any(|(placed_key, _, _)| placed_key == key))
+            .cloned()
+            .collect();
+        term_plan
+    }
+
     /// Add a new term to the plan
     pub fn add_term(&mut self) {
         let next_number = self.terms.len() + 1;
@@ -129,6 +180,33 @@ impl TermPlan {
     pub fn terms_used(&self) -> usize {
         self.terms.iter().filter(|t| !t.courses.is_empty()).count()
     }
+
+    /// Terms in one academic year (3 for quarters, 2 for semesters)
+    #[must_use]
+    pub const fn terms_per_year(&self) -> usize {
+        if self.is_quarter_system {
+            3
+        } else {
+            2
+        }
+    }
+
+    /// Total credits for each academic year, in order
+    ///
+    /// Terms are grouped by [`terms_per_year`](Self::terms_per_year) up to the
+    /// last term with courses; a trailing partial year sums the terms it has.
+    #[must_use]
+    pub fn credits_per_year(&self) -> Vec<f32> {
+        let last_used = self
+            .terms
+            .iter()
+            .rposition(|t| !t.courses.is_empty())
+            .map_or(0, |idx| idx + 1);
+        self.terms[..last_used]
+            .chunks(self.terms_per_year())
+            .map(|year| year.iter().map(|t| t.total_credits).sum())
+            .collect()
+    }
 }
 
 /// Configuration for the term scheduler
@@ -142,6 +220,16 @@ pub struct SchedulerConfig {
     pub num_terms: usize,
     /// Whether using quarter system
     pub is_quarter_system: bool,
+    /// Credits each already-scheduled course adds to a term's load when placing
+    /// filler courses (0.0 considers credits alone)
+    pub course_count_weight: f32,
+    /// Leave inactive courses, and courses that require them, unscheduled
+    pub exclude_inactive: bool,
+    /// Hard cap on terms; groups that would need a later term are left unscheduled
+    pub absolute_max_terms: usize,
+    /// Only strict corequisites force a shared term; regular corequisites may be
+    /// taken in an earlier term when that balances loads better
+    pub loose_coreqs: bool,
 }
 
 impl SchedulerConfig {
@@ -153,6 +241,10 @@ impl SchedulerConfig {
             max_credits: target_credits + 6.0, // Allow some overflow
             num_terms: SEMESTER_TERMS,
             is_quarter_system: false,
+            course_count_weight: DEFAULT_COURSE_COUNT_WEIGHT,
+            exclude_inactive: false,
+            absolute_max_terms: SEMESTER_ABSOLUTE_MAX_TERMS,
+            loose_coreqs: false,
         }
     }
 
@@ -164,8 +256,40 @@ impl SchedulerConfig {
             max_credits: target_credits + 4.0,
             num_terms: QUARTER_TERMS,
             is_quarter_system: true,
+            course_count_weight: DEFAULT_COURSE_COUNT_WEIGHT,
+            exclude_inactive: false,
+            absolute_max_terms: QUARTER_ABSOLUTE_MAX_TERMS,
+            loose_coreqs: false,
         }
     }
+
+    /// Set how much each already-scheduled course counts when placing filler courses
+    #[must_use]
+    pub const fn with_course_count_weight(mut self, weight: f32) -> Self {
+        self.course_count_weight = weight;
+        self
+    }
+
+    /// Leave inactive courses and their dependents unscheduled
+    #[must_use]
+    pub const fn with_exclude_inactive(mut self, enabled: bool) -> Self {
+        self.exclude_inactive = enabled;
+        self
+    }
+
+    /// Set the hard cap on how many terms the scheduler may create
+    #[must_use]
+    pub const fn with_absolute_max_terms(mut self, max_terms: usize) -> Self {
+        self.absolute_max_terms = max_terms;
+        self
+    }
+
+    /// Group only strict corequisites; let regular corequisites come earlier
+    #[must_use]
+    pub const fn with_loose_coreqs(mut self, enabled: bool) -> Self {
+        self.loose_coreqs = enabled;
+        self
+    }
 }
 
 impl Default for SchedulerConfig {
@@ -174,6 +298,58 @@ impl Default for SchedulerConfig {
     }
 }
 
+/// Why the scheduler put a group in the term it did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementReason {
+    /// Earliest term after its prerequisites that stays within the target credits
+    WithinTarget,
+    /// No term after its prerequisites stayed within target; first one under the maximum
+    UnderMax,
+    /// Filler group (no in-plan requisites) placed in the least-loaded term
+    LeastLoaded,
+    /// No existing term had room, so the group starts a new term
+    NewTerm,
+}
+
+/// A scheduling decision reported to an observer
+///
+/// Term numbers are 1-indexed, matching [`Term::number`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchedulerEvent {
+    /// A corequisite group (or single course) was placed in a term
+    GroupPlaced {
+        /// Course keys in the group
+        group: Vec<String>,
+        /// Term the group was placed in
+        term: usize,
+        /// Why that term was chosen
+        reason: PlacementReason,
+    },
+    /// A term was appended beyond the configured count
+    TermAdded {
+        /// Number of the new term
+        term: usize,
+    },
+    /// The plan's longest prerequisite chain needs more terms than configured
+    ///
+    /// Reported once, before any placement, alongside a logged warning.
+    ChainTooLong {
+        /// Terms the longest chain needs
+        required: usize,
+        /// Terms configured in [`SchedulerConfig::num_terms`]
+        allotted: usize,
+    },
+    /// The rebalance pass moved a course to balance credit loads
+    CourseMoved {
+        /// Course key
+        course: String,
+        /// Term the course left
+        from: usize,
+        /// Term the course moved to
+        to: usize,
+    },
+}
+
 /// Term scheduler that distributes courses across terms
 pub struct TermScheduler<'a> {
     school: &'a School,
@@ -202,15 +378,61 @@ impl<'a> TermScheduler<'a> {
     /// 5. Rebalance by moving low-complexity filler courses to underloaded terms
     #[must_use]
     pub fn schedule(&self, course_keys: &[String]) -> TermPlan {
+        self.schedule_with_observer(course_keys, &mut |_| {})
+    }
+
+    /// Schedule courses into terms, reporting each decision to `observer`
+    ///
+    /// Produces the same plan as [`Self::schedule`]. The observer receives a
+    /// [`SchedulerEvent::ChainTooLong`] first if the plan cannot fit in the
+    /// configured terms, then a [`SchedulerEvent::GroupPlaced`] for every
+    /// corequisite group, a [`SchedulerEvent::TermAdded`] whenever the plan
+    /// grows, and a [`SchedulerEvent::CourseMoved`] for each rebalancing move,
+    /// in the order they happen.
+    ///
+    /// With [`SchedulerConfig::exclude_inactive`], inactive courses and every
+    /// plan course that requires one are left in [`TermPlan::unscheduled`].
+    /// Groups that would need a term past [`SchedulerConfig::absolute_max_terms`],
+    /// and everything that requires them, are also left unscheduled.
+    pub fn schedule_with_observer<F>(&self, course_keys: &[String], observer: &mut F) -> TermPlan
+    where
+        F: FnMut(SchedulerEvent),
+    {
         let mut plan = TermPlan::new(
             self.config.num_terms,
             self.config.is_quarter_system,
             self.config.target_credits,
         );
 
+        let (schedulable, blocked) = self.partition_inactive(course_keys);
+        let course_keys = schedulable.as_slice();
+        plan.unscheduled = blocked;
+        let blocked_count = plan.unscheduled.len();
+
         let course_set: HashSet<_> = course_keys.iter().collect();
+
+        let min_terms = self.minimum_terms_required(course_keys);
+        if min_terms > self.config.num_terms {
+            crate::warn!(
+                "Plan cannot fit in {} terms: its longest prerequisite chain needs {} terms ({} more than allotted)",
+                self.config.num_terms,
+                min_terms,
+                min_terms - self.config.num_terms
+            );
+            observer(SchedulerEvent::ChainTooLong {
+                required: min_terms,
+                allotted: self.config.num_terms,
+            });
+        }
+
         let delay_factors = compute_delay(self.dag).unwrap_or_default();
-        let chain_priority = self.compute_chain_priority(course_keys, &course_set, &delay_factors);
+        let blocking_factors = compute_blocking(self.dag).unwrap_or_default();
+        let chain_priority = self.compute_chain_priority(
+            course_keys,
+            &course_set,
+            &delay_factors,
+            &blocking_factors,
+        );
 
         let mut course_term: HashMap<String, usize> = HashMap::new();
         let coreq_groups = self.build_corequisite_groups(course_keys);
@@ -229,28 +451,296 @@ impl<'a> TermScheduler<'a> {
             &mut plan,
             &mut course_term,
             &course_set,
+            observer,
         );
 
         // Now fill in filler courses to balance terms
-        self.schedule_filler_groups(&filler_groups, &mut plan, &mut course_term);
+        self.schedule_filler_groups(&filler_groups, &mut plan, &mut course_term, observer);
+
+        let overflow = &plan.unscheduled[blocked_count..];
+        if !overflow.is_empty() {
+            crate::error!(
+                "Plan does not fit in {} terms; leaving {} unscheduled",
+                self.config.absolute_max_terms,
+                overflow.join(", ")
+            );
+        }
 
         // Final rebalancing pass
-        self.rebalance_terms(&mut plan, &delay_factors);
+        self.rebalance_terms(&mut plan, &delay_factors, observer);
+
+        plan
+    }
+
+    /// Split plan courses into those that can be scheduled and those blocked by an inactive course
+    ///
+    /// A course is blocked when it is inactive or any of its in-plan
+    /// requisites is blocked. Nothing is blocked unless
+    /// [`SchedulerConfig::exclude_inactive`] is set. Both lists keep input order.
+    fn partition_inactive(&self, course_keys: &[String]) -> (Vec<String>, Vec<String>) {
+        if !self.config.exclude_inactive {
+            return (course_keys.to_vec(), Vec::new());
+        }
+
+        let mut blocked: HashSet<&str> = HashSet::new();
+        for key in course_keys {
+            if self.school.get_course(key).is_some_and(|c| !c.active) {
+                crate::warn!("{key} is inactive this cycle; leaving it unscheduled");
+                blocked.insert(key);
+            }
+        }
+
+        // Propagate to dependents until nothing changes
+        loop {
+            let newly_blocked: Vec<&str> = course_keys
+                .iter()
+                .map(String::as_str)
+                .filter(|k| !blocked.contains(k))
+                .filter(|k| {
+                    let deps = self.dag.dependencies.get(*k).into_iter().flatten();
+                    let coreqs = self.dag.corequisites.get(*k).into_iter().flatten();
+                    deps.chain(coreqs).any(|r| blocked.contains(r.as_str()))
+                })
+                .collect();
+            if newly_blocked.is_empty() {
+                break;
+            }
+            for key in newly_blocked {
+                crate::warn!("{key} requires an inactive course; leaving it unscheduled");
+                blocked.insert(key);
+            }
+        }
+
+        course_keys
+            .iter()
+            .cloned()
+            .partition(|k| !blocked.contains(k.as_str()))
+    }
+
+    /// Schedule courses into as few terms as possible under a hard credit cap
+    ///
+    /// Unlike [`Self::schedule`], which balances load around the target credits,
+    /// this packs terms as tightly as the cap allows. Heuristic (list scheduling):
+    /// 1. Build corequisite groups; a group is ready once all of its in-plan
+    ///    prerequisites sit in earlier terms
+    /// 2. Fill one term at a time with ready groups, ordered by the longest
+    ///    in-plan chain still depending on them (descending), then by credits
+    ///    (descending, first-fit decreasing), then by key
+    /// 3. A group skipped because it would exceed the cap waits for the next term
+    ///
+    /// A group whose credits alone exceed the cap is placed in an otherwise empty
+    /// term so scheduling always progresses. Courses caught in a prerequisite
+    /// cycle, or that would need a term past
+    /// [`SchedulerConfig::absolute_max_terms`], are reported in
+    /// [`TermPlan::unscheduled`].
+    #[must_use]
+    pub fn schedule_min_terms(
+        &self,
+        course_keys: &[String],
+        max_credits_per_term: f32,
+    ) -> TermPlan {
+        let mut plan = TermPlan::new(0, self.config.is_quarter_system, self.config.target_credits);
+
+        let course_set: HashSet<_> = course_keys.iter().collect();
+        let mut chain_below: HashMap<String, usize> = HashMap::new();
+        let mut pending: Vec<(usize, f32, Vec<String>)> = self
+            .build_corequisite_groups(course_keys)
+            .into_iter()
+            .map(|group| {
+                let height = group
+                    .iter()
+                    .map(|k| self.chain_length_below(k, &course_set, &mut chain_below))
+                    .max()
+                    .unwrap_or(0);
+                let credits = self.group_credits(&group);
+                (height, credits, group)
+            })
+            .collect();
+        pending.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then_with(|| b.1.total_cmp(&a.1))
+                .then_with(|| a.2.cmp(&b.2))
+        });
+
+        let mut course_term: HashMap<String, usize> = HashMap::new();
+        while !pending.is_empty() {
+            let term_idx = plan.terms.len();
+            if term_idx >= self.config.absolute_max_terms {
+                plan.unscheduled = pending.into_iter().flat_map(|(_, _, g)| g).collect();
+                crate::error!(
+                    "Plan does not fit in {} terms; leaving {} unscheduled",
+                    self.config.absolute_max_terms,
+                    plan.unscheduled.join(", ")
+                );
+                break;
+            }
+            plan.add_term();
+
+            let mut placed_any = false;
+            pending.retain(|(_, credits, group)| {
+                let ready = group.iter().all(|key| {
+                    let prereqs_done =
+                        self.dag.dependencies.get(key).into_iter().flatten().all(|prereq| {
+                            !course_set.contains(prereq)
+                                || group.contains(prereq)
+                                || course_term.get(prereq).is_some_and(|&t| t < term_idx)
+                        });
+                    // Loose corequisites only need to be placed, possibly this term
+                    prereqs_done
+                        && self.loose_coreqs_of(key).iter().all(|coreq| {
+                            !course_set.contains(coreq) || course_term.contains_key(*coreq)
+                        })
+                });
+                let term = &plan.terms[term_idx];
+                let fits = term.total_credits + credits <= max_credits_per_term
+                    || term.courses.is_empty();
+                if !ready || !fits {
+                    return true;
+                }
+
+                if *credits > max_credits_per_term {
+                    crate::warn!(
+                        "Corequisite group {} needs {credits} credits, above the {max_credits_per_term} credit cap",
+                        group.join("+")
+                    );
+                }
+                for key in group {
+                    let credit_hours = self.school.get_course(key).map_or(0.0, |c| c.credit_hours);
+                    plan.terms[term_idx].add_course(key.clone(), credit_hours);
+                    course_term.insert(key.clone(), term_idx);
+                }
+                placed_any = true;
+                false
+            });
+
+            if !placed_any {
+                // Nothing became ready: the remaining groups depend on each other cyclically
+                plan.terms.pop();
+                plan.unscheduled = pending.into_iter().flat_map(|(_, _, g)| g).collect();
+                break;
+            }
+        }
 
         plan
     }
 
+    /// Number of in-plan courses on the longest chain of dependents below `key`.
+    ///
+    /// Memoized in `memo`; cycles are cut by treating a revisited course as a leaf.
+    fn chain_length_below(
+        &self,
+        key: &str,
+        course_set: &HashSet<&String>,
+        memo: &mut HashMap<String, usize>,
+    ) -> usize {
+        if let Some(&length) = memo.get(key) {
+            return length;
+        }
+        // Provisional entry guards against cycles
+        memo.insert(key.to_string(), 0);
+
+        let length = self
+            .dag
+            .dependents
+            .get(key)
+            .into_iter()
+            .flatten()
+            .filter(|dependent| course_set.contains(dependent))
+            .map(|dependent| self.chain_length_below(dependent, course_set, memo) + 1)
+            .max()
+            .unwrap_or(0);
+
+        memo.insert(key.to_string(), length);
+        length
+    }
+
+    /// Total credit hours of a corequisite group
+    fn group_credits(&self, group: &[String]) -> f32 {
+        group
+            .iter()
+            .filter_map(|k| self.school.get_course(k))
+            .map(|c| c.credit_hours)
+            .sum()
+    }
+
+    /// Compute the minimum number of terms needed to schedule the given courses.
+    ///
+    /// This is the length of the longest prerequisite chain among the plan's
+    /// courses, ignoring credit limits. Corequisites share a term and so do not
+    /// lengthen the chain. Prerequisites outside the plan are ignored.
+    #[must_use]
+    pub fn minimum_terms_required(&self, course_keys: &[String]) -> usize {
+        let course_set: HashSet<_> = course_keys.iter().collect();
+        let mut earliest: HashMap<&str, usize> = HashMap::new();
+
+        course_keys
+            .iter()
+            .map(|key| {
+                self.earliest_term_index(key, &course_set, &mut earliest, &mut HashSet::new()) + 1
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Earliest 0-based term for a course considering only in-plan prerequisites.
+    ///
+    /// Results are memoized in `earliest`; `visiting` guards against cycles.
+    fn earliest_term_index<'k>(
+        &'k self,
+        key: &'k str,
+        course_set: &HashSet<&String>,
+        earliest: &mut HashMap<&'k str, usize>,
+        visiting: &mut HashSet<&'k str>,
+    ) -> usize {
+        if let Some(&term) = earliest.get(key) {
+            return term;
+        }
+        if !visiting.insert(key) {
+            return 0;
+        }
+
+        let term = self
+            .dag
+            .dependencies
+            .get(key)
+            .into_iter()
+            .flatten()
+            .filter(|prereq| course_set.contains(prereq))
+            .map(|prereq| self.earliest_term_index(prereq, course_set, earliest, visiting) + 1)
+            .max()
+            .unwrap_or(0);
+
+        visiting.remove(key);
+        earliest.insert(key, term);
+        term
+    }
+
     /// Compute chain priority scores for course scheduling
+    ///
+    /// The score is tiered by chain position and delay factor. The course's
+    /// blocking factor only breaks ties within the same tier score, so among
+    /// courses with equal delay, gateways that unlock more of the curriculum
+    /// are placed first, but no amount of blocking outranks a longer chain.
     fn compute_chain_priority(
         &self,
         course_keys: &[String],
         course_set: &HashSet<&String>,
         delay_factors: &HashMap<String, usize>,
+        blocking_factors: &HashMap<String, usize>,
     ) -> HashMap<String, usize> {
+        // Scale tier scores past the largest blocking factor so blocking acts
+        // as a secondary key
+        let stride = course_keys
+            .iter()
+            .filter_map(|k| blocking_factors.get(k))
+            .max()
+            .map_or(1, |max| max + 1);
+
         course_keys
             .iter()
             .map(|k| {
                 let delay = delay_factors.get(k).copied().unwrap_or(0);
+                let blocking = blocking_factors.get(k).copied().unwrap_or(0);
                 let has_prereqs_in_plan = self
                     .dag
                     .dependencies
@@ -271,7 +761,7 @@ impl<'a> TermScheduler<'a> {
                     delay // End-of-chain or standalone
                 };
 
-                (k.clone(), priority)
+                (k.clone(), priority * stride + blocking)
             })
             .collect()
     }
@@ -349,11 +839,12 @@ impl<'a> TermScheduler<'a> {
         for (b_idx, group) in groups.iter().enumerate() {
             let mut prereq_groups: HashSet<usize> = HashSet::new();
             for key in group {
-                let deps = self.collect_transitive_prereqs_in_set(
+                let mut deps = self.collect_transitive_prereqs_in_set(
                     key.as_str(),
                     course_set,
                     &mut prereq_cache,
                 );
+                deps.extend(self.loose_coreqs_of(key).into_iter().cloned());
                 for dep in deps {
                     if let Some(&a_idx) = course_to_group.get(dep.as_str()) {
                         if a_idx != b_idx {
@@ -468,7 +959,16 @@ impl<'a> TermScheduler<'a> {
                     .dependents
                     .get(k)
                     .is_some_and(|d| d.iter().any(|dep| course_set.contains(dep)));
-                !has_prereqs && !has_dependents
+                // Corequisites split across groups (loose mode) must stay ordered
+                let has_split_coreqs = self
+                    .dag
+                    .corequisites
+                    .get(k)
+                    .into_iter()
+                    .chain(self.dag.coreq_dependents.get(k))
+                    .flatten()
+                    .any(|c| course_set.contains(c) && !group.contains(c));
+                !has_prereqs && !has_dependents && !has_split_coreqs
             });
 
             if is_filler {
@@ -495,23 +995,42 @@ impl<'a> TermScheduler<'a> {
         plan: &mut TermPlan,
         course_term: &mut HashMap<String, usize>,
         course_set: &HashSet<&String>,
+        observer: &mut dyn FnMut(SchedulerEvent),
     ) {
+        let mut overflow: HashSet<&String> = HashSet::new();
         for group in groups {
+            let after_overflow = group.iter().any(|key| {
+                self.dag
+                    .dependencies
+                    .get(key)
+                    .into_iter()
+                    .flatten()
+                    .chain(self.loose_coreqs_of(key))
+                    .any(|prereq| overflow.contains(prereq))
+            });
             let min_term = self.calculate_earliest_term(group, course_term, course_set);
-            let group_credits: f32 = group
-                .iter()
-                .filter_map(|k| self.school.get_course(k))
-                .map(|c| c.credit_hours)
-                .sum();
-
-            let term_idx = self.find_best_term(plan, min_term, group_credits);
+            let group_credits = self.group_credits(group);
 
-            for key in group {
-                if let Some(course) = self.school.get_course(key) {
-                    plan.terms[term_idx].add_course(key.clone(), course.credit_hours);
-                    course_term.insert(key.clone(), term_idx);
-                }
-            }
+            let terms_before = plan.terms.len();
+            let placement = if after_overflow {
+                None
+            } else {
+                self.find_best_term(plan, min_term, group_credits)
+            };
+            let Some((term_idx, reason)) = placement else {
+                overflow.extend(group);
+                plan.unscheduled.extend(group.iter().cloned());
+                continue;
+            };
+            self.place_group(
+                group,
+                term_idx,
+                reason,
+                terms_before,
+                plan,
+                course_term,
+                observer,
+            );
         }
     }
 
@@ -521,50 +1040,105 @@ impl<'a> TermScheduler<'a> {
         groups: &[Vec<String>],
         plan: &mut TermPlan,
         course_term: &mut HashMap<String, usize>,
+        observer: &mut dyn FnMut(SchedulerEvent),
     ) {
         for group in groups {
-            let group_credits: f32 = group
-                .iter()
-                .filter_map(|k| self.school.get_course(k))
-                .map(|c| c.credit_hours)
-                .sum();
+            let group_credits = self.group_credits(group);
 
-            let term_idx = self.find_underloaded_term(plan, group_credits);
+            let terms_before = plan.terms.len();
+            let Some(term_idx) = self.find_underloaded_term(plan, group_credits) else {
+                plan.unscheduled.extend(group.iter().cloned());
+                continue;
+            };
+            let reason = if plan.terms.len() > terms_before {
+                PlacementReason::NewTerm
+            } else {
+                PlacementReason::LeastLoaded
+            };
+            self.place_group(
+                group,
+                term_idx,
+                reason,
+                terms_before,
+                plan,
+                course_term,
+                observer,
+            );
+        }
+    }
 
-            for key in group {
-                if let Some(course) = self.school.get_course(key) {
-                    plan.terms[term_idx].add_course(key.clone(), course.credit_hours);
-                    course_term.insert(key.clone(), term_idx);
-                }
+    /// Add a group to a term, reporting any terms added since `terms_before`
+    #[allow(clippy::too_many_arguments)]
+    fn place_group(
+        &self,
+        group: &[String],
+        term_idx: usize,
+        reason: PlacementReason,
+        terms_before: usize,
+        plan: &mut TermPlan,
+        course_term: &mut HashMap<String, usize>,
+        observer: &mut dyn FnMut(SchedulerEvent),
+    ) {
+        for term in &plan.terms[terms_before..] {
+            observer(SchedulerEvent::TermAdded { term: term.number });
+        }
+
+        for key in group {
+            if let Some(course) = self.school.get_course(key) {
+                plan.terms[term_idx].add_course(key.clone(), course.credit_hours);
+                course_term.insert(key.clone(), term_idx);
             }
         }
+
+        observer(SchedulerEvent::GroupPlaced {
+            group: group.to_vec(),
+            term: plan.terms[term_idx].number,
+            reason,
+        });
     }
 
-    /// Find the term with lowest credits that can accommodate the group
-    fn find_underloaded_term(&self, plan: &mut TermPlan, group_credits: f32) -> usize {
-        // Find the term with minimum credits that won't exceed max
+    /// Find the least-loaded term that can accommodate the group
+    ///
+    /// Load is the term's credits plus `course_count_weight` per course already in it,
+    /// so terms full of small courses are not preferred over equally credited terms
+    /// with fewer courses. Returns `None` if no term fits and the plan is already
+    /// at [`SchedulerConfig::absolute_max_terms`].
+    fn find_underloaded_term(&self, plan: &mut TermPlan, group_credits: f32) -> Option<usize> {
+        // Find the term with minimum load that won't exceed max credits
         let mut best_term = 0;
-        let mut min_credits = f32::INFINITY;
+        let mut min_load = f32::INFINITY;
 
         for (idx, term) in plan.terms.iter().enumerate() {
             let projected = term.total_credits + group_credits;
-            if projected <= self.config.max_credits && term.total_credits < min_credits {
-                min_credits = term.total_credits;
+            #[allow(clippy::cast_precision_loss)]
+            let load = self
+                .config
+                .course_count_weight
+                .mul_add(term.courses.len() as f32, term.total_credits);
+            if projected <= self.config.max_credits && load < min_load {
+                min_load = load;
                 best_term = idx;
             }
         }
 
         // If no term fits, add a new one
-        if min_credits == f32::INFINITY {
+        if min_load < f32::INFINITY {
+            Some(best_term)
+        } else if plan.terms.len() < self.config.absolute_max_terms {
             plan.add_term();
-            plan.terms.len() - 1
+            Some(plan.terms.len() - 1)
         } else {
-            best_term
+            None
         }
     }
 
     /// Rebalance terms by moving low-complexity courses from overloaded to underloaded terms
-    fn rebalance_terms(&self, plan: &mut TermPlan, delay_factors: &HashMap<String, usize>) {
+    fn rebalance_terms(
+        &self,
+        plan: &mut TermPlan,
+        delay_factors: &HashMap<String, usize>,
+        observer: &mut dyn FnMut(SchedulerEvent),
+    ) {
         let target = self.config.target_credits;
 
         // Multiple passes to iteratively balance
@@ -619,6 +1193,11 @@ impl<'a> TermScheduler<'a> {
                             plan.terms[over_idx].courses.retain(|k| k != &course_key);
                             plan.terms[over_idx].total_credits -= credits;
                             plan.terms[under_idx].add_course(course_key.clone(), credits);
+                            observer(SchedulerEvent::CourseMoved {
+                                course: course_key.clone(),
+                                from: plan.terms[over_idx].number,
+                                to: plan.terms[under_idx].number,
+                            });
                             break;
                         }
                     }
@@ -648,7 +1227,15 @@ impl<'a> TermScheduler<'a> {
 
     /// Build groups of courses that must be in the same term (corequisites/strict corequisites)
     /// This performs bidirectional search: if A has B as coreq, or B has A as coreq, they're grouped
-    fn build_corequisite_groups(&self, course_keys: &[String]) -> Vec<Vec<String>> {
+    ///
+    /// Every key appears in exactly one group; courses without corequisites form
+    /// singleton groups. Groups follow the order of `course_keys`.
+    ///
+    /// With [`SchedulerConfig::loose_coreqs`], a regular corequisite only joins
+    /// the group when both courses list each other (which forces the same term
+    /// anyway); otherwise it is ordered by [`Self::loose_coreqs_of`] instead.
+    #[must_use]
+    pub fn build_corequisite_groups(&self, course_keys: &[String]) -> Vec<Vec<String>> {
         let course_set: HashSet<_> = course_keys.iter().cloned().collect();
         let mut visited: HashSet<String> = HashSet::new();
         let mut groups: Vec<Vec<String>> = Vec::new();
@@ -657,7 +1244,7 @@ impl<'a> TermScheduler<'a> {
         let mut reverse_coreqs: HashMap<String, Vec<String>> = HashMap::new();
         for key in course_keys {
             if let Some(course) = self.school.get_course(key) {
-                for coreq in &course.corequisites {
+                for coreq in self.grouped_coreqs(key, course) {
                     if course_set.contains(coreq) {
                         reverse_coreqs
                             .entry(coreq.clone())
@@ -699,7 +1286,7 @@ impl<'a> TermScheduler<'a> {
                     }
 
                     // Add regular corequisites (should be same term when possible)
-                    for coreq in &course.corequisites {
+                    for coreq in self.grouped_coreqs(&current, course) {
                         if course_set.contains(coreq) && !visited.contains(coreq) {
                             group.push(coreq.clone());
                             to_check.push(coreq.clone());
@@ -730,7 +1317,47 @@ impl<'a> TermScheduler<'a> {
         groups
     }
 
+    /// Regular corequisites of `course` (stored under `key`) that are grouped with it
+    ///
+    /// All of them by default; with [`SchedulerConfig::loose_coreqs`], only
+    /// those that list `key` back.
+    fn grouped_coreqs<'c>(
+        &'c self,
+        key: &'c str,
+        course: &'c Course,
+    ) -> impl Iterator<Item = &'c String> {
+        course.corequisites.iter().filter(move |coreq| {
+            !self.config.loose_coreqs
+                || self
+                    .school
+                    .get_course(coreq)
+                    .is_some_and(|other| other.corequisites.iter().any(|c| c == key))
+        })
+    }
+
+    /// Regular corequisites of `key` that may be scheduled in an earlier term
+    ///
+    /// Empty unless [`SchedulerConfig::loose_coreqs`] is set. Mutual
+    /// corequisites are excluded since they share a group.
+    #[must_use]
+    pub fn loose_coreqs_of(&self, key: &str) -> Vec<&String> {
+        let Some(course) = self.school.get_course(key) else {
+            return Vec::new();
+        };
+        if !self.config.loose_coreqs {
+            return Vec::new();
+        }
+        let grouped: HashSet<&String> = self.grouped_coreqs(key, course).collect();
+        course
+            .corequisites
+            .iter()
+            .filter(|coreq| !grouped.contains(coreq))
+            .collect()
+    }
+
     /// Calculate the earliest term a group can be placed (based on prerequisites)
+    ///
+    /// Loose corequisites (see [`Self::loose_coreqs_of`]) may share the term.
     fn calculate_earliest_term(
         &self,
         group: &[String],
@@ -748,14 +1375,29 @@ impl<'a> TermScheduler<'a> {
                     }
                 }
             }
+            for coreq in self.loose_coreqs_of(key) {
+                if let Some(&coreq_term) = scheduled.get(coreq) {
+                    min_term = min_term.max(coreq_term);
+                }
+            }
         }
 
         min_term
     }
 
     /// Find the best term to place a group, starting from `min_term`
-    /// Expands the plan if needed to fit all courses
-    fn find_best_term(&self, plan: &mut TermPlan, min_term: usize, group_credits: f32) -> usize {
+    /// Expands the plan if needed to fit all courses, up to
+    /// [`SchedulerConfig::absolute_max_terms`]; returns `None` past that cap
+    fn find_best_term(
+        &self,
+        plan: &mut TermPlan,
+        min_term: usize,
+        group_credits: f32,
+    ) -> Option<(usize, PlacementReason)> {
+        if min_term >= plan.terms.len().max(self.config.absolute_max_terms) {
+            return None;
+        }
+
         // Ensure we have enough terms
         while min_term >= plan.terms.len() {
             plan.add_term();
@@ -765,7 +1407,7 @@ impl<'a> TermScheduler<'a> {
         for term_idx in min_term..plan.terms.len() {
             let projected = plan.terms[term_idx].total_credits + group_credits;
             if projected <= self.config.target_credits {
-                return term_idx;
+                return Some((term_idx, PlacementReason::WithinTarget));
             }
         }
 
@@ -773,19 +1415,91 @@ impl<'a> TermScheduler<'a> {
         for term_idx in min_term..plan.terms.len() {
             let projected = plan.terms[term_idx].total_credits + group_credits;
             if projected <= self.config.max_credits {
-                return term_idx;
+                return Some((term_idx, PlacementReason::UnderMax));
             }
         }
 
         // If still no fit, add a new term and place there
+        if plan.terms.len() >= self.config.absolute_max_terms {
+            return None;
+        }
         plan.add_term();
-        plan.terms.len() - 1
+        Some((plan.terms.len() - 1, PlacementReason::NewTerm))
+    }
+}
+
+/// Earliest 0-based term for every course, honoring offering parity
+///
+/// Like the scheduler's prerequisite-chain bound, but a course can only land in
+/// a term its [`TermOffering`] allows, counted from a student starting in
+/// `start`. The result is the number of terms before each course is available,
+/// so a fall-only course is `1` for a spring entrant rather than `0`.
+/// Corequisites may share a term; prerequisites must come strictly earlier.
+/// Courses missing from `school` are treated as offered every term.
+#[must_use]
+pub fn compute_earliest_term_with_start(
+    dag: &DAG,
+    school: &School,
+    start: TermParity,
+) -> HashMap<String, usize> {
+    let mut earliest = HashMap::new();
+    for course in &dag.courses {
+        earliest_offered_term(
+            course,
+            dag,
+            school,
+            start,
+            &mut earliest,
+            &mut HashSet::new(),
+        );
     }
+    earliest
+}
+
+/// Memoized helper for [`compute_earliest_term_with_start`]
+///
+/// `visiting` guards against cycles, which contribute no constraint.
+fn earliest_offered_term(
+    key: &str,
+    dag: &DAG,
+    school: &School,
+    start: TermParity,
+    earliest: &mut HashMap<String, usize>,
+    visiting: &mut HashSet<String>,
+) -> usize {
+    if let Some(&term) = earliest.get(key) {
+        return term;
+    }
+    if !visiting.insert(key.to_string()) {
+        return 0;
+    }
+
+    let mut term = 0;
+    for prereq in dag.dependencies.get(key).into_iter().flatten() {
+        term = term.max(earliest_offered_term(prereq, dag, school, start, earliest, visiting) + 1);
+    }
+    for coreq in dag.corequisites.get(key).into_iter().flatten() {
+        term = term.max(earliest_offered_term(
+            coreq, dag, school, start, earliest, visiting,
+        ));
+    }
+
+    let offered = school
+        .get_course(key)
+        .map_or(TermOffering::Every, |course| course.offered);
+    while !offered.is_offered_in(start.at(term)) {
+        term += 1;
+    }
+
+    visiting.remove(key);
+    earliest.insert(key.to_string(), term);
+    term
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::approx::credits_eq;
     use crate::core::models::Course;
 
     fn create_test_school() -> School {
@@ -896,15 +1610,15 @@ mod tests {
     fn test_term_add_course() {
         let mut term = Term::new(1);
         assert_eq!(term.courses.len(), 0);
-        assert!((term.total_credits - 0.0).abs() < f32::EPSILON);
+        assert!(credits_eq(term.total_credits, 0.0));
 
         term.add_course("CS101".to_string(), 3.0);
         assert_eq!(term.courses.len(), 1);
-        assert!((term.total_credits - 3.0).abs() < f32::EPSILON);
+        assert!(credits_eq(term.total_credits, 3.0));
 
         term.add_course("CS102".to_string(), 4.0);
         assert_eq!(term.courses.len(), 2);
-        assert!((term.total_credits - 7.0).abs() < f32::EPSILON);
+        assert!(credits_eq(term.total_credits, 7.0));
     }
 
     #[test]
@@ -929,11 +1643,35 @@ mod tests {
         assert_eq!(plan.terms_used(), 2);
     }
 
+    #[test]
+    fn test_credits_per_year_groups_semesters() {
+        let mut plan = TermPlan::new(8, false, 15.0);
+        for (idx, term) in plan.terms.iter_mut().enumerate() {
+            term.add_course(format!("C{idx}"), 15.0);
+        }
+        plan.terms[7].add_course("CS499".to_string(), 1.0);
+
+        let years = plan.credits_per_year();
+        assert_eq!(years.len(), 4);
+        assert!(credits_eq(years[0], 30.0));
+        assert!(credits_eq(years[3], 31.0));
+
+        // A trailing partial quarter year sums the terms it has
+        let mut quarters = TermPlan::new(6, true, 15.0);
+        for term in &mut quarters.terms[..4] {
+            term.add_course("CS101".to_string(), 5.0);
+        }
+        let years = quarters.credits_per_year();
+        assert_eq!(years.len(), 2);
+        assert!(credits_eq(years[0], 15.0));
+        assert!(credits_eq(years[1], 5.0));
+    }
+
     #[test]
     fn test_scheduler_config_semester() {
         let config = SchedulerConfig::semester(15.0);
-        assert!((config.target_credits - 15.0).abs() < f32::EPSILON);
-        assert!((config.max_credits - 21.0).abs() < f32::EPSILON); // 15 + 6
+        assert!(credits_eq(config.target_credits, 15.0));
+        assert!(credits_eq(config.max_credits, 21.0)); // 15 + 6
         assert_eq!(config.num_terms, 8);
         assert!(!config.is_quarter_system);
     }
@@ -941,8 +1679,8 @@ mod tests {
     #[test]
     fn test_scheduler_config_quarter() {
         let config = SchedulerConfig::quarter(15.0);
-        assert!((config.target_credits - 15.0).abs() < f32::EPSILON);
-        assert!((config.max_credits - 19.0).abs() < f32::EPSILON); // 15 + 4
+        assert!(credits_eq(config.target_credits, 15.0));
+        assert!(credits_eq(config.max_credits, 19.0)); // 15 + 4
         assert_eq!(config.num_terms, 12);
         assert!(config.is_quarter_system);
     }
@@ -992,6 +1730,239 @@ mod tests {
         assert_eq!(main_course_term, lab_course_term);
     }
 
+    #[test]
+    fn test_loose_coreqs_split_regular_but_not_strict() {
+        let mut school = School::new("Test".to_string());
+        let course = |prefix: &str, number: &str| {
+            Course::new(
+                format!("{prefix} {number}"),
+                prefix.to_string(),
+                number.to_string(),
+                4.0,
+            )
+        };
+
+        let mut phys = course("PHYS", "101");
+        phys.add_corequisite("MATH101".to_string());
+        let mut lab = course("CS", "101L");
+        lab.add_strict_corequisite("CS101".to_string());
+        school.add_course(course("MATH", "101"));
+        school.add_course(phys);
+        school.add_course(course("CS", "101"));
+        school.add_course(lab);
+        let dag = school.build_dag();
+
+        // Each pair is 8 credits, more than a term may hold
+        let mut config = SchedulerConfig::semester(6.0);
+        config.max_credits = 6.0;
+        let courses: Vec<String> = ["MATH101", "PHYS101", "CS101", "CS101L"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let term_of = |plan: &TermPlan, key: &str| {
+            plan.terms
+                .iter()
+                .position(|t| t.courses.iter().any(|c| c == key))
+                .unwrap()
+        };
+
+        let grouped = TermScheduler::new(&school, &dag, config.clone()).schedule(&courses);
+        assert_eq!(term_of(&grouped, "MATH101"), term_of(&grouped, "PHYS101"));
+        assert_eq!(term_of(&grouped, "CS101"), term_of(&grouped, "CS101L"));
+
+        let scheduler = TermScheduler::new(&school, &dag, config.with_loose_coreqs(true));
+        assert_eq!(scheduler.loose_coreqs_of("PHYS101"), vec!["MATH101"]);
+        assert!(scheduler.loose_coreqs_of("CS101L").is_empty());
+
+        let loose = scheduler.schedule(&courses);
+        // The regular corequisite comes first, in its own term; the strict pair stays together
+        assert!(term_of(&loose, "MATH101") < term_of(&loose, "PHYS101"));
+        assert_eq!(term_of(&loose, "CS101"), term_of(&loose, "CS101L"));
+
+        let packed = scheduler.schedule_min_terms(&courses, 6.0);
+        assert!(term_of(&packed, "MATH101") < term_of(&packed, "PHYS101"));
+        assert_eq!(term_of(&packed, "CS101"), term_of(&packed, "CS101L"));
+    }
+
+    #[test]
+    fn test_chain_longer_than_allotted_terms() {
+        let mut school = School::new("Test".to_string());
+        let mut dag = DAG::new();
+        let mut courses = Vec::new();
+
+        // Build a 10-deep prerequisite chain CS100 -> CS101 -> ... -> CS109
+        for i in 0..10 {
+            let mut course = Course::new(
+                format!("Course {i}"),
+                "CS".to_string(),
+                format!("{}", 100 + i),
+                3.0,
+            );
+            let key = course.key();
+            dag.add_course(key.clone());
+            if i > 0 {
+                let prereq = format!("CS{}", 99 + i);
+                course.add_prerequisite(prereq.clone());
+                dag.add_prerequisite(key.clone(), &prereq);
+            }
+            school.add_course(course);
+            courses.push(key);
+        }
+
+        let config = SchedulerConfig::semester(15.0);
+        assert_eq!(config.num_terms, 8);
+        let scheduler = TermScheduler::new(&school, &dag, config);
+
+        // The chain needs 10 terms, 2 more than the 8 allotted, so schedule() warns
+        assert_eq!(scheduler.minimum_terms_required(&courses), 10);
+
+        let mut events = Vec::new();
+        let plan = scheduler.schedule_with_observer(&courses, &mut |e| events.push(e));
+        assert_eq!(plan.terms_used(), 10);
+        assert_eq!(
+            events.first(),
+            Some(&SchedulerEvent::ChainTooLong {
+                required: 10,
+                allotted: 8,
+            })
+        );
+        let warnings = events
+            .iter()
+            .filter(|e| matches!(e, SchedulerEvent::ChainTooLong { .. }))
+            .count();
+        assert_eq!(warnings, 1);
+
+        // A plan that fits reports no warning
+        let mut events = Vec::new();
+        scheduler.schedule_with_observer(&courses[..8], &mut |e| events.push(e));
+        assert!(!events
+            .iter()
+            .any(|e| matches!(e, SchedulerEvent::ChainTooLong { .. })));
+    }
+
+    #[test]
+    fn test_absolute_max_terms_caps_deep_chain() {
+        let mut school = School::new("Test".to_string());
+        let mut dag = DAG::new();
+        let mut courses = Vec::new();
+
+        // A 25-deep chain CS100 -> ... -> CS124 needs more terms than the cap
+        for i in 0..25 {
+            let mut course = Course::new(
+                format!("Course {i}"),
+                "CS".to_string(),
+                format!("{}", 100 + i),
+                3.0,
+            );
+            let key = course.key();
+            dag.add_course(key.clone());
+            if i > 0 {
+                let prereq = format!("CS{}", 99 + i);
+                course.add_prerequisite(prereq.clone());
+                dag.add_prerequisite(key.clone(), &prereq);
+            }
+            school.add_course(course);
+            courses.push(key);
+        }
+
+        let config = SchedulerConfig::semester(15.0);
+        assert_eq!(config.absolute_max_terms, SEMESTER_ABSOLUTE_MAX_TERMS);
+        let scheduler = TermScheduler::new(&school, &dag, config);
+
+        let plan = scheduler.schedule(&courses);
+        assert_eq!(plan.terms.len(), 20);
+        assert_eq!(plan.terms_used(), 20);
+        assert_eq!(plan.unscheduled, courses[20..].to_vec());
+
+        let packed = scheduler.schedule_min_terms(&courses, 18.0);
+        assert_eq!(packed.terms.len(), 20);
+        assert_eq!(packed.unscheduled.len(), 5);
+
+        // A tighter cap applies the same way
+        let config = SchedulerConfig::semester(15.0).with_absolute_max_terms(12);
+        let plan = TermScheduler::new(&school, &dag, config).schedule(&courses);
+        assert_eq!(plan.terms.len(), 12);
+        assert_eq!(plan.unscheduled, courses[12..].to_vec());
+    }
+
+    #[test]
+    fn test_minimum_terms_ignores_out_of_plan_prereqs() {
+        let school = create_test_school();
+        let mut dag = DAG::new();
+        dag.add_prerequisite("CS201".to_string(), "CS101");
+        dag.add_prerequisite("CS301".to_string(), "CS201");
+
+        let scheduler = TermScheduler::new(&school, &dag, SchedulerConfig::default());
+
+        let courses = vec!["CS201".to_string(), "CS301".to_string()];
+        assert_eq!(scheduler.minimum_terms_required(&courses), 2);
+        assert_eq!(scheduler.minimum_terms_required(&[]), 0);
+    }
+
+    #[test]
+    fn test_schedule_min_terms_not_worse_than_default() {
+        let school =
+            crate::core::planner::parse_curriculum_csv("samples/plans/Colostate_CSDegree.csv")
+                .expect("parse curriculum");
+        let dag = school.build_dag();
+        let courses = school.plans[0].courses.clone();
+
+        let config = SchedulerConfig::semester(15.0);
+        let cap = config.max_credits;
+        let scheduler = TermScheduler::new(&school, &dag, config);
+
+        let balanced = scheduler.schedule(&courses);
+        let packed = scheduler.schedule_min_terms(&courses, cap);
+
+        assert!(packed.terms_used() <= balanced.terms_used());
+        assert!(packed.terms_used() >= scheduler.minimum_terms_required(&courses));
+        assert!(packed.unscheduled.is_empty());
+
+        let term_of: HashMap<&str, usize> = packed
+            .terms
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, t)| t.courses.iter().map(move |k| (k.as_str(), idx)))
+            .collect();
+        assert_eq!(term_of.len(), courses.len());
+        for term in &packed.terms {
+            assert!(term.total_credits <= cap + 0.01);
+        }
+        for course in &courses {
+            for prereq in dag.dependencies.get(course).into_iter().flatten() {
+                if let Some(&prereq_term) = term_of.get(prereq.as_str()) {
+                    assert!(
+                        prereq_term < term_of[course.as_str()],
+                        "{prereq} before {course}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_schedule_min_terms_packs_to_cap() {
+        let mut school = School::new("Test".to_string());
+        let mut dag = DAG::new();
+        for i in 1..=6 {
+            school.add_course(Course::new(
+                format!("Course {i}"),
+                "CS".to_string(),
+                format!("{i}00"),
+                4.0,
+            ));
+            dag.add_course(format!("CS{i}00"));
+        }
+
+        let scheduler = TermScheduler::new(&school, &dag, SchedulerConfig::default());
+        let courses: Vec<String> = (1..=6).map(|i| format!("CS{i}00")).collect();
+
+        // 24 credits under a 12-credit cap needs exactly two terms
+        let plan = scheduler.schedule_min_terms(&courses, 12.0);
+        assert_eq!(plan.terms.len(), 2);
+        assert_eq!(plan.terms_used(), 2);
+    }
+
     #[test]
     fn test_schedule_respects_credit_limits() {
         let mut school = School::new("Test".to_string());
@@ -1085,4 +2056,294 @@ mod tests {
         let total_scheduled: usize = plan.terms.iter().map(|t| t.courses.len()).sum();
         assert_eq!(total_scheduled, 4);
     }
+
+    #[test]
+    fn test_filler_placement_counts_courses() {
+        let mut school = School::new("Test".to_string());
+        let mut dag = DAG::new();
+        for (prefix, number, credits) in [
+            ("SEM", "101", 1.0),
+            ("SEM", "102", 1.0),
+            ("SEM", "103", 1.0),
+            ("SEM", "104", 1.0),
+            ("CS", "400", 4.0),
+            ("GEN", "101", 1.0),
+            ("GEN", "102", 1.0),
+        ] {
+            school.add_course(Course::new(
+                format!("{prefix} {number}"),
+                prefix.to_string(),
+                number.to_string(),
+                credits,
+            ));
+            dag.add_course(format!("{prefix}{number}"));
+        }
+
+        // Both terms carry 4 credits, but the first holds four 1-credit seminars
+        let loaded_plan = || {
+            let mut plan = TermPlan::new(2, false, 15.0);
+            for key in ["SEM101", "SEM102", "SEM103", "SEM104"] {
+                plan.terms[0].add_course(key.to_string(), 1.0);
+            }
+            plan.terms[1].add_course("CS400".to_string(), 4.0);
+            plan
+        };
+        let fillers = vec![vec!["GEN101".to_string()], vec!["GEN102".to_string()]];
+
+        let scheduler = TermScheduler::new(&school, &dag, SchedulerConfig::semester(15.0));
+        let mut plan = loaded_plan();
+        scheduler.schedule_filler_groups(&fillers, &mut plan, &mut HashMap::new(), &mut |_| {});
+        assert_eq!(plan.terms[0].courses.len(), 4);
+        assert_eq!(
+            plan.terms[1].courses,
+            vec![
+                "CS400".to_string(),
+                "GEN101".to_string(),
+                "GEN102".to_string()
+            ]
+        );
+
+        // Credits alone break the tie toward the first term, clustering small courses
+        let credits_only = SchedulerConfig::semester(15.0).with_course_count_weight(0.0);
+        let scheduler = TermScheduler::new(&school, &dag, credits_only);
+        let mut plan = loaded_plan();
+        scheduler.schedule_filler_groups(&fillers, &mut plan, &mut HashMap::new(), &mut |_| {});
+        assert_eq!(plan.terms[0].courses.len(), 5);
+    }
+
+    #[test]
+    fn test_fall_only_course_delays_spring_entrant() {
+        let mut school = School::new("Test University".to_string());
+        school.add_course(Course::new(
+            "Intro to CS".to_string(),
+            "CS".to_string(),
+            "101".to_string(),
+            3.0,
+        ));
+        let mut systems = Course::new(
+            "Systems".to_string(),
+            "CS".to_string(),
+            "210".to_string(),
+            4.0,
+        );
+        systems.set_offered(TermOffering::Fall);
+        school.add_course(systems);
+        let mut networks = Course::new(
+            "Networks".to_string(),
+            "CS".to_string(),
+            "310".to_string(),
+            3.0,
+        );
+        networks.add_prerequisite("CS210".to_string());
+        networks.set_offered(TermOffering::Spring);
+        school.add_course(networks);
+        let dag = school.build_dag();
+
+        let fall = compute_earliest_term_with_start(&dag, &school, TermParity::Fall);
+        assert_eq!(fall["CS101"], 0);
+        assert_eq!(fall["CS210"], 0);
+        assert_eq!(fall["CS310"], 1);
+
+        // A spring entrant waits a term for CS210, pushing CS310 out as well
+        let spring = compute_earliest_term_with_start(&dag, &school, TermParity::Spring);
+        assert_eq!(spring["CS101"], 0);
+        assert_eq!(spring["CS210"], 1);
+        assert_eq!(spring["CS310"], 2);
+    }
+
+    #[test]
+    fn test_term_plan_from_explicit_terms() {
+        let csv = "Curriculum,Hand Plan\n\
+                   Institution,Test University\n\
+                   Degree Type,BS\n\
+                   System Type,semester\n\
+                   CIP,11.0701\n\
+                   Courses\n\
+                   Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours,Term\n\
+                   1,Intro to CS,CS,101,,,,4,1\n\
+                   2,Calculus I,MATH,156,,,,4,1\n\
+                   3,Data Structures,CS,201,1,,,4,3\n\
+                   4,Seminar,CS,190,,,,1,\n";
+        let school = crate::core::planner::parse_curriculum_reader(
+            csv.as_bytes(),
+            &crate::core::planner::ParseOptions::default(),
+        )
+        .unwrap();
+        let plan = &school.plans[0];
+
+        let term_plan = TermPlan::from_course_terms(&school, plan);
+
+        assert_eq!(term_plan.terms.len(), 3);
+        let mut first = term_plan.terms[0].courses.clone();
+        first.sort();
+        assert_eq!(first, vec!["CS101".to_string(), "MATH156".to_string()]);
+        assert!(credits_eq(term_plan.terms[0].total_credits, 8.0));
+        assert!(term_plan.terms[1].courses.is_empty());
+        assert_eq!(term_plan.terms[2].courses, vec!["CS201".to_string()]);
+        assert_eq!(term_plan.unscheduled, vec!["CS190".to_string()]);
+    }
+
+    #[test]
+    fn test_observer_sees_every_group_placed() {
+        let mut school = create_test_school();
+        let mut lab = Course::new(
+            "Intro Lab".to_string(),
+            "CS".to_string(),
+            "101L".to_string(),
+            1.0,
+        );
+        lab.add_strict_corequisite("CS101".to_string());
+        school.add_course(lab);
+        let dag = school.build_dag();
+
+        let scheduler = TermScheduler::new(&school, &dag, SchedulerConfig::semester(15.0));
+        let courses: Vec<String> = ["CS101", "CS101L", "CS201", "CS301", "MATH101"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        let mut events = Vec::new();
+        let plan = scheduler.schedule_with_observer(&courses, &mut |e| events.push(e));
+
+        let placed: Vec<&Vec<String>> = events
+            .iter()
+            .filter_map(|e| match e {
+                SchedulerEvent::GroupPlaced { group, .. } => Some(group),
+                _ => None,
+            })
+            .collect();
+        let groups = scheduler.build_corequisite_groups(&courses);
+        assert_eq!(placed.len(), groups.len());
+        assert!(placed
+            .iter()
+            .any(|g| g.len() == 2 && g.contains(&"CS101L".to_string())));
+        for course in &courses {
+            assert_eq!(placed.iter().filter(|g| g.contains(course)).count(), 1);
+        }
+
+        // Same plan as the observer-free entry point
+        assert_eq!(
+            scheduler
+                .schedule(&courses)
+                .terms
+                .iter()
+                .map(|t| &t.courses)
+                .collect::<Vec<_>>(),
+            plan.terms.iter().map(|t| &t.courses).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_high_blocking_gateway_scheduled_first() {
+        let mut school = School::new("Test".to_string());
+        let course = |prefix: &str, number: &str, prereq: Option<&str>| {
+            let mut c = Course::new(
+                format!("{prefix} {number}"),
+                prefix.to_string(),
+                number.to_string(),
+                10.0,
+            );
+            if let Some(p) = prereq {
+                c.add_prerequisite(p.to_string());
+            }
+            c
+        };
+        // Both gateways have delay 2, but CS101 blocks three courses and AAA101 one
+        school.add_course(course("AAA", "101", None));
+        school.add_course(course("AAA", "201", Some("AAA101")));
+        school.add_course(course("CS", "101", None));
+        school.add_course(course("CS", "201", Some("CS101")));
+        school.add_course(course("CS", "202", Some("CS101")));
+        school.add_course(course("CS", "203", Some("CS101")));
+        let dag = school.build_dag();
+
+        // Only one 10-credit gateway fits in the first term
+        let scheduler = TermScheduler::new(&school, &dag, SchedulerConfig::semester(12.0));
+        let courses: Vec<String> = ["AAA101", "AAA201", "CS101", "CS201", "CS202", "CS203"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let plan = scheduler.schedule(&courses);
+
+        let term_of = |key: &str| {
+            plan.terms
+                .iter()
+                .position(|t| t.courses.iter().any(|c| c == key))
+                .unwrap()
+        };
+        assert_eq!(term_of("CS101"), 0);
+        assert!(term_of("AAA101") > term_of("CS101"));
+    }
+
+    #[test]
+    fn test_blocking_does_not_outrank_longer_chain() {
+        let mut school = School::new("Test".to_string());
+        let course = |prefix: &str, number: &str, credits: f32, prereq: Option<&str>| {
+            let mut c = Course::new(
+                format!("{prefix} {number}"),
+                prefix.to_string(),
+                number.to_string(),
+                credits,
+            );
+            if let Some(p) = prereq {
+                c.add_prerequisite(p.to_string());
+            }
+            c
+        };
+        // MATH101 heads a three-course chain; CS101 heads a shorter chain but
+        // blocks far more courses
+        school.add_course(course("MATH", "101", 10.0, None));
+        school.add_course(course("MATH", "201", 1.0, Some("MATH101")));
+        school.add_course(course("MATH", "301", 1.0, Some("MATH201")));
+        school.add_course(course("CS", "101", 10.0, None));
+        let mut courses: Vec<String> = ["MATH101", "MATH201", "MATH301", "CS101"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        for n in 200..215 {
+            let number = n.to_string();
+            school.add_course(course("CS", &number, 1.0, Some("CS101")));
+            courses.push(format!("CS{number}"));
+        }
+        let dag = school.build_dag();
+
+        // Only one 10-credit gateway fits in the first term
+        let scheduler = TermScheduler::new(&school, &dag, SchedulerConfig::semester(12.0));
+        let plan = scheduler.schedule(&courses);
+
+        let term_of = |key: &str| {
+            plan.terms
+                .iter()
+                .position(|t| t.courses.iter().any(|c| c == key))
+                .unwrap()
+        };
+        assert_eq!(term_of("MATH101"), 0);
+        assert!(term_of("CS101") > term_of("MATH101"));
+    }
+
+    #[test]
+    fn test_inactive_course_leaves_dependents_unscheduled() {
+        let mut school = create_test_school();
+        school.get_course_mut("CS201").unwrap().set_active(false);
+        let dag = school.build_dag();
+        let courses: Vec<String> = ["CS101", "CS201", "CS301", "MATH101"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        let config = SchedulerConfig::semester(15.0).with_exclude_inactive(true);
+        let plan = TermScheduler::new(&school, &dag, config).schedule(&courses);
+
+        assert_eq!(plan.unscheduled, vec!["CS201", "CS301"]);
+        let scheduled: Vec<&String> = plan.terms.iter().flat_map(|t| &t.courses).collect();
+        assert_eq!(scheduled.len(), 2);
+        assert!(scheduled.contains(&&"CS101".to_string()));
+        assert!(scheduled.contains(&&"MATH101".to_string()));
+
+        // Without the option the inactive flag is ignored
+        let plan =
+            TermScheduler::new(&school, &dag, SchedulerConfig::semester(15.0)).schedule(&courses);
+        assert!(plan.unscheduled.is_empty());
+        assert_eq!(school.active_courses().len(), 3);
+    }
 }
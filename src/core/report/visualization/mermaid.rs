@@ -5,9 +5,14 @@
 
 use crate::core::metrics::CurriculumMetrics;
 use crate::core::models::{School, DAG};
-use crate::core::report::term_scheduler::TermPlan;
+use crate::core::report::formats::html::truncate_label;
+use crate::core::report::term_scheduler::{SchedulerConfig, TermPlan, TermScheduler};
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 
+/// Maximum characters of a course name shown in a node label
+const LABEL_WIDTH: usize = 20;
+
 /// Generator for Mermaid diagram syntax
 pub struct MermaidGenerator;
 
@@ -18,32 +23,71 @@ impl MermaidGenerator {
     /// Each node displays the course name and complexity metric.
     #[must_use]
     pub fn generate_dag(dag: &DAG, school: &School, metrics: &CurriculumMetrics) -> String {
+        Self::generate_dag_with_options(dag, school, metrics, false)
+    }
+
+    /// Generate a Mermaid flowchart from a DAG, optionally collapsing corequisites
+    ///
+    /// With `collapse_corequisites`, each corequisite group (as built by the term
+    /// scheduler) becomes a single node labeled with all member keys, and the
+    /// corequisite edges inside a group are omitted. Edges to or from members are
+    /// redirected to the combined node.
+    #[must_use]
+    pub fn generate_dag_with_options(
+        dag: &DAG,
+        school: &School,
+        metrics: &CurriculumMetrics,
+        collapse_corequisites: bool,
+    ) -> String {
         let mut output = String::from("```mermaid\nflowchart LR\n");
 
+        let groups = if collapse_corequisites {
+            TermScheduler::new(school, dag, SchedulerConfig::default())
+                .build_corequisite_groups(&dag.courses)
+        } else {
+            dag.courses.iter().map(|key| vec![key.clone()]).collect()
+        };
+
         // Define nodes with their complexity values
-        for course_key in &dag.courses {
-            let label = Self::get_node_label(course_key, school, metrics);
-            let safe_id = Self::sanitize_id(course_key);
+        let mut node_ids: HashMap<&str, String> = HashMap::new();
+        for group in &groups {
+            let safe_id = Self::sanitize_id(&group.join("_"));
+            let label = match group.as_slice() {
+                [course_key] => Self::get_node_label(course_key, school, metrics),
+                _ => Self::get_group_label(group, school, metrics),
+            };
             let _ = writeln!(output, "    {safe_id}[\"{label}\"]");
+            for key in group {
+                node_ids.insert(key, safe_id.clone());
+            }
         }
 
         output.push('\n');
 
+        let node_id = |key: &str| {
+            node_ids
+                .get(key)
+                .cloned()
+                .unwrap_or_else(|| Self::sanitize_id(key))
+        };
+        let mut written: HashSet<(String, String, &str)> = HashSet::new();
+        let mut add_edge = |output: &mut String, from: String, to: String, arrow: &'static str| {
+            if from != to && written.insert((from.clone(), to.clone(), arrow)) {
+                let _ = writeln!(output, "    {from} {arrow} {to}");
+            }
+        };
+
         // Add prerequisite edges
         for (course, prereqs) in &dag.dependencies {
-            let course_id = Self::sanitize_id(course);
             for prereq in prereqs {
-                let prereq_id = Self::sanitize_id(prereq);
-                let _ = writeln!(output, "    {prereq_id} --> {course_id}");
+                add_edge(&mut output, node_id(prereq), node_id(course), "-->");
             }
         }
 
         // Add corequisite edges (dashed)
         for (course, coreqs) in &dag.corequisites {
-            let course_id = Self::sanitize_id(course);
             for coreq in coreqs {
-                let coreq_id = Self::sanitize_id(coreq);
-                let _ = writeln!(output, "    {coreq_id} -.-> {course_id}");
+                add_edge(&mut output, node_id(coreq), node_id(course), "-.->");
             }
         }
 
@@ -146,21 +190,37 @@ impl MermaidGenerator {
     fn get_node_label(course_key: &str, school: &School, metrics: &CurriculumMetrics) -> String {
         let course_name = school.get_course(course_key).map_or_else(
             || course_key.to_string(),
-            |c| {
-                // Truncate long names
-                if c.name.len() > 20 {
-                    format!("{}...", &c.name[..17])
-                } else {
-                    c.name.clone()
-                }
-            },
+            |c| truncate_label(&c.name, LABEL_WIDTH),
         );
 
-        let complexity = metrics.get(course_key).map_or(0, |m| m.complexity);
+        let complexity = school
+            .course_metrics(metrics, course_key)
+            .map_or(0, |m| m.complexity);
 
         format!("{course_key}<br/>{course_name}<br/>C:{complexity}")
     }
 
+    /// Get a display label for a collapsed corequisite group
+    ///
+    /// Shows every member key, the first member's name, and the highest member
+    /// complexity.
+    fn get_group_label(group: &[String], school: &School, metrics: &CurriculumMetrics) -> String {
+        let keys = group.join(" + ");
+        let name = group
+            .first()
+            .and_then(|key| school.get_course(key))
+            .map_or("", |c| c.name.as_str());
+        let name = truncate_label(name, LABEL_WIDTH);
+        let complexity = group
+            .iter()
+            .filter_map(|key| school.course_metrics(metrics, key))
+            .map(|m| m.complexity)
+            .max()
+            .unwrap_or(0);
+
+        format!("{keys}<br/>{name}<br/>C:{complexity}")
+    }
+
     /// Sanitize a course key for use as a Mermaid node ID
     fn sanitize_id(key: &str) -> String {
         key.chars()
@@ -204,6 +264,7 @@ mod tests {
                 blocking: 1,
                 complexity: 2,
                 centrality: 1,
+                ..CourseMetrics::default()
             },
         );
         metrics.insert(
@@ -213,6 +274,7 @@ mod tests {
                 blocking: 0,
                 complexity: 2,
                 centrality: 1,
+                ..CourseMetrics::default()
             },
         );
 
@@ -225,9 +287,70 @@ mod tests {
         assert!(diagram.contains("-->"));
     }
 
+    #[test]
+    fn test_collapse_corequisites_merges_lab_into_one_node() {
+        let mut school = School::new("Test".to_string());
+        let mut lecture = Course::new(
+            "Intro".to_string(),
+            "CS".to_string(),
+            "101".to_string(),
+            3.0,
+        );
+        lecture.add_strict_corequisite("CS101L".to_string());
+        school.add_course(lecture);
+        school.add_course(Course::new(
+            "Intro Lab".to_string(),
+            "CS".to_string(),
+            "101L".to_string(),
+            1.0,
+        ));
+        let mut next = Course::new(
+            "Data Structures".to_string(),
+            "CS".to_string(),
+            "201".to_string(),
+            3.0,
+        );
+        next.add_prerequisite("CS101L".to_string());
+        school.add_course(next);
+        let dag = school.build_dag();
+        let metrics = CurriculumMetrics::new();
+
+        let expanded = MermaidGenerator::generate_dag(&dag, &school, &metrics);
+        assert!(expanded.contains("    CS101[\""));
+        assert!(expanded.contains("    CS101L[\""));
+        assert!(expanded.contains("CS101L -.-> CS101"));
+
+        let collapsed = MermaidGenerator::generate_dag_with_options(&dag, &school, &metrics, true);
+        assert_eq!(collapsed.lines().filter(|l| l.contains("[\"")).count(), 2);
+        assert!(collapsed.contains("    CS101_CS101L[\"CS101 + CS101L<br/>"));
+        assert!(!collapsed.contains("-.->"));
+        assert!(collapsed.contains("CS101_CS101L --> CS201"));
+    }
+
     #[test]
     fn test_sanitize_id() {
         assert_eq!(MermaidGenerator::sanitize_id("CS 101"), "CS_101");
         assert_eq!(MermaidGenerator::sanitize_id("MATH-1341"), "MATH_1341");
     }
+
+    #[test]
+    fn test_labels_truncate_multibyte_names_by_chars() {
+        let mut school = School::new("Test".to_string());
+        // Byte 17 falls inside a multibyte character
+        let name = "Introducción Análisis de Datos";
+        school.add_course(Course::new(
+            name.to_string(),
+            "MAT".to_string(),
+            "301".to_string(),
+            3.0,
+        ));
+        let metrics = CurriculumMetrics::new();
+
+        let label = MermaidGenerator::get_node_label("MAT301", &school, &metrics);
+        assert!(label.contains("Introducción Anál..."));
+
+        let group = vec!["MAT301".to_string()];
+        let label = MermaidGenerator::get_group_label(&group, &school, &metrics);
+        assert!(label.contains("Introducción Anál..."));
+    }
 }
@@ -1,8 +1,11 @@
 //! Visualization generation for curriculum graphs
 //!
-//! Provides generators for Mermaid diagrams (for Markdown) and data structures
-//! for JavaScript-based visualizations (vis.js/Cytoscape.js for HTML).
+//! Provides generators for Mermaid diagrams (for Markdown), standalone SVG
+//! schedule graphs, and data structures for JavaScript-based visualizations
+//! (vis.js/Cytoscape.js for HTML).
 
 pub mod mermaid;
+pub mod svg;
 
 pub use mermaid::MermaidGenerator;
+pub use svg::SvgScheduleGenerator;
@@ -0,0 +1,289 @@
+//! Standalone SVG generator for term-layered schedules
+//!
+//! Lays the requisite graph out in term columns (x = term, y = position within
+//! the term) with server-side computed coordinates. The same coordinate and
+//! path math backs the connection overlay in the HTML report.
+
+use crate::core::models::DAG;
+use crate::core::report::formats::html::escape_html;
+use crate::core::report::term_scheduler::TermPlan;
+use crate::core::report::ReportContext;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Width of each term column
+const TERM_WIDTH: f32 = 130.0;
+/// Horizontal offset of the first term column
+const TERM_X_OFFSET: f32 = 20.0;
+/// Vertical spacing between courses within a term
+const COURSE_HEIGHT: f32 = 115.0;
+/// Vertical offset of the first course in a term
+const COURSE_Y_OFFSET: f32 = 50.0;
+/// Horizontal offset of a course node's center within its column
+const COURSE_CENTER_X: f32 = 65.0;
+/// Vertical offset of a course node's center within its slot
+const COURSE_CENTER_Y: f32 = 30.0;
+/// Width of a course node box in standalone SVG output
+const NODE_WIDTH: f32 = 110.0;
+/// Height of a course node box in standalone SVG output
+const NODE_HEIGHT: f32 = 60.0;
+
+/// Generator for term-layered schedule graphs as SVG
+pub struct SvgScheduleGenerator;
+
+impl SvgScheduleGenerator {
+    /// Compute the center point of every scheduled course
+    ///
+    /// # Returns
+    /// Map from course key to `(x, y)` center coordinates
+    #[must_use]
+    pub fn node_positions(term_plan: &TermPlan) -> HashMap<String, (f32, f32)> {
+        let mut positions = HashMap::new();
+        for (term_idx, term) in term_plan.terms.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let term_x = (term_idx as f32).mul_add(TERM_WIDTH, TERM_X_OFFSET);
+            for (course_idx, course_key) in term.courses.iter().enumerate() {
+                #[allow(clippy::cast_precision_loss)]
+                let course_y = (course_idx as f32).mul_add(COURSE_HEIGHT, COURSE_Y_OFFSET);
+                positions.insert(
+                    course_key.clone(),
+                    (term_x + COURSE_CENTER_X, course_y + COURSE_CENTER_Y),
+                );
+            }
+        }
+        positions
+    }
+
+    /// Generate `<path>` elements for every in-plan prerequisite and corequisite
+    ///
+    /// Prerequisites use the `prereq-line` class and corequisites `coreq-line`.
+    /// Edges are drawn as quadratic curves between course centers, and only
+    /// when both ends are in the plan and scheduled.
+    #[must_use]
+    pub fn edge_paths(term_plan: &TermPlan, dag: &DAG, plan_courses: &[String]) -> Vec<String> {
+        let positions = Self::node_positions(term_plan);
+        let mut paths = Vec::new();
+
+        let edge_sets = [
+            ("prereq-line", &dag.dependencies),
+            ("coreq-line", &dag.corequisites),
+        ];
+        for (class, edges) in edge_sets {
+            for (course, sources) in edges {
+                if !plan_courses.contains(course) {
+                    continue;
+                }
+                for source in sources {
+                    if !plan_courses.contains(source) {
+                        continue;
+                    }
+
+                    if let (Some(&(x1, y1)), Some(&(x2, y2))) =
+                        (positions.get(source), positions.get(course))
+                    {
+                        // Curved path: quadratic Bezier from requisite to course
+                        let mid_x = f32::midpoint(x1, x2);
+                        let mid_y = f32::midpoint(y1, y2);
+                        paths.push(format!(
+                            "<path class=\"{class}\" d=\"M {x1:.1} {y1:.1} Q {mid_x:.1} {mid_y:.1} {x2:.1} {y2:.1}\" data-from=\"{}\" data-to=\"{}\"></path>",
+                            escape_html(source),
+                            escape_html(course)
+                        ));
+                    }
+                }
+            }
+        }
+
+        paths
+    }
+
+    /// Generate a complete standalone SVG document for the schedule
+    ///
+    /// Includes a header per term, a node (`<g class="course-node">`) per
+    /// scheduled course, and the requisite edges from [`Self::edge_paths`].
+    #[must_use]
+    pub fn generate(ctx: &ReportContext) -> String {
+        let term_plan = ctx.term_plan;
+        let positions = Self::node_positions(term_plan);
+        let max_courses = term_plan
+            .terms
+            .iter()
+            .map(|t| t.courses.len())
+            .max()
+            .unwrap_or(0);
+
+        #[allow(clippy::cast_precision_loss)]
+        let width = (term_plan.terms.len() as f32).mul_add(TERM_WIDTH, 2.0 * TERM_X_OFFSET);
+        #[allow(clippy::cast_precision_loss)]
+        let height = (max_courses as f32).mul_add(COURSE_HEIGHT, COURSE_Y_OFFSET);
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width:.0}\" height=\"{height:.0}\" viewBox=\"0 0 {width:.0} {height:.0}\">"
+        );
+        svg.push_str(
+            "<style>\
+             .term-header{font:bold 13px sans-serif;text-anchor:middle;fill:#333}\
+             .course-node rect{fill:white;stroke:#37474f;stroke-width:2;rx:6}\
+             .course-node text{font:12px sans-serif;text-anchor:middle;fill:#222}\
+             .prereq-line{fill:none;stroke:#555;stroke-width:1.5}\
+             .coreq-line{fill:none;stroke:#555;stroke-width:1.5;stroke-dasharray:4 3}\
+             </style>\n",
+        );
+
+        let term_label = term_plan.term_label();
+        for (term_idx, term) in term_plan.terms.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let x = (term_idx as f32).mul_add(TERM_WIDTH, TERM_X_OFFSET) + COURSE_CENTER_X;
+            let _ = writeln!(
+                svg,
+                "<text class=\"term-header\" x=\"{x:.1}\" y=\"25\">{term_label} {}</text>",
+                term.number
+            );
+        }
+
+        // Edges first so nodes are drawn on top
+        for path in Self::edge_paths(term_plan, ctx.dag, &ctx.plan.courses) {
+            let _ = writeln!(svg, "{path}");
+        }
+
+        for term in &term_plan.terms {
+            for course_key in &term.courses {
+                let Some(&(cx, cy)) = positions.get(course_key) else {
+                    continue;
+                };
+                let complexity = ctx
+                    .school
+                    .course_metrics(ctx.metrics, course_key)
+                    .map_or(0, |m| m.complexity);
+                let key = escape_html(course_key);
+                let _ = writeln!(
+                    svg,
+                    "<g class=\"course-node\" data-course-id=\"{key}\"><rect x=\"{:.1}\" y=\"{:.1}\" width=\"{NODE_WIDTH:.0}\" height=\"{NODE_HEIGHT:.0}\"/><text x=\"{cx:.1}\" y=\"{:.1}\">{key}</text><text x=\"{cx:.1}\" y=\"{:.1}\">C: {complexity}</text></g>",
+                    cx - NODE_WIDTH / 2.0,
+                    cy - NODE_HEIGHT / 2.0,
+                    cy - 4.0,
+                    cy + 14.0,
+                );
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Write the standalone SVG document to a file
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written
+    pub fn write_to_file(ctx: &ReportContext, output_path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(output_path, Self::generate(ctx))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::metrics::compute_all_metrics;
+    use crate::core::metrics_export::CurriculumSummary;
+    use crate::core::models::{Course, Plan, School};
+
+    #[test]
+    fn test_standalone_svg_has_nodes_and_edges() {
+        let mut school = School::new("Test".to_string());
+        let mut cs201 = Course::new("DS".to_string(), "CS".to_string(), "201".to_string(), 3.0);
+        cs201.add_prerequisite("CS101".to_string());
+        cs201.add_prerequisite("MATH100".to_string());
+        let mut cs301 = Course::new("Algo".to_string(), "CS".to_string(), "301".to_string(), 3.0);
+        cs301.add_prerequisite("CS201".to_string());
+        school.add_course(Course::new(
+            "Intro".to_string(),
+            "CS".to_string(),
+            "101".to_string(),
+            3.0,
+        ));
+        school.add_course(Course::new(
+            "Precalc".to_string(),
+            "MATH".to_string(),
+            "100".to_string(),
+            3.0,
+        ));
+        school.add_course(cs201);
+        school.add_course(cs301);
+
+        // MATH100 is outside the plan, so its edge to CS201 is not drawn
+        let mut plan = Plan::new("Plan".to_string(), "BS".to_string());
+        for key in ["CS101", "CS201", "CS301"] {
+            plan.add_course(key.to_string());
+        }
+
+        let dag = school.build_dag();
+        let metrics = compute_all_metrics(&dag).unwrap();
+        let summary = CurriculumSummary::from_metrics(&plan, &school, &metrics);
+        let mut term_plan = TermPlan::new(3, false, 15.0);
+        term_plan.terms[0].add_course("CS101".to_string(), 3.0);
+        term_plan.terms[1].add_course("CS201".to_string(), 3.0);
+        term_plan.terms[2].add_course("CS301".to_string(), 3.0);
+
+        let ctx = ReportContext::new(&school, &plan, None, &metrics, &summary, &dag, &term_plan);
+        let svg = SvgScheduleGenerator::generate(&ctx);
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<g class=\"course-node\"").count(), 3);
+        assert_eq!(svg.matches("<path class=\"prereq-line\"").count(), 2);
+        assert!(svg.contains("data-from=\"CS101\" data-to=\"CS201\""));
+        assert!(!svg.contains("data-from=\"MATH100\""));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("schedule.svg");
+        SvgScheduleGenerator::write_to_file(&ctx, &path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), svg);
+    }
+
+    #[test]
+    fn test_standalone_svg_escapes_course_keys() {
+        let mut school = School::new("Test".to_string());
+        let mut lab = Course::new(
+            "Lab".to_string(),
+            "R&D".to_string(),
+            "2<'L'>".to_string(),
+            1.0,
+        );
+        lab.add_prerequisite("R&D\"101\"".to_string());
+        school.add_course(Course::new(
+            "Intro".to_string(),
+            "R&D".to_string(),
+            "\"101\"".to_string(),
+            3.0,
+        ));
+        school.add_course(lab);
+
+        let mut plan = Plan::new("Plan".to_string(), "BS".to_string());
+        let keys = ["R&D\"101\"", "R&D2<'L'>"];
+        for key in keys {
+            plan.add_course(key.to_string());
+        }
+        let dag = school.build_dag();
+        let metrics = compute_all_metrics(&dag).unwrap();
+        let summary = CurriculumSummary::from_metrics(&plan, &school, &metrics);
+        let mut term_plan = TermPlan::new(2, false, 15.0);
+        term_plan.terms[0].add_course(keys[0].to_string(), 3.0);
+        term_plan.terms[1].add_course(keys[1].to_string(), 1.0);
+
+        let ctx = ReportContext::new(&school, &plan, None, &metrics, &summary, &dag, &term_plan);
+        let svg = SvgScheduleGenerator::generate(&ctx);
+
+        assert!(svg.contains("data-course-id=\"R&amp;D&quot;101&quot;\""));
+        assert!(svg.contains(">R&amp;D2&lt;&#39;L&#39;&gt;</text>"));
+        assert!(svg.contains(
+            "data-from=\"R&amp;D&quot;101&quot;\" data-to=\"R&amp;D2&lt;&#39;L&#39;&gt;\""
+        ));
+        assert!(!svg.contains("R&D"));
+    }
+}
@@ -0,0 +1,232 @@
+//! Minimal HTTP server for interactive analysis (requires the `server` feature)
+//!
+//! Exposes a single endpoint, `POST /analyze`, which accepts curriculum CSV
+//! text in the request body and responds with the computed metrics as JSON.
+
+use super::metrics::{self, CurriculumMetrics};
+use super::metrics_export::{CurriculumSummary, METRICS_JSON_SCHEMA_VERSION};
+use super::models::{Degree, Plan};
+use super::planner::{parse_curriculum_reader, ParseOptions};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::io::Read;
+use tiny_http::{Header, Method, Response, Server};
+
+/// Largest request body `POST /analyze` accepts, in bytes
+///
+/// Larger bodies are rejected with `413 Payload Too Large` without being read
+/// in full.
+pub const MAX_BODY_BYTES: u64 = 10 * 1024 * 1024;
+
+/// JSON body returned by `POST /analyze`
+///
+/// Field names and meanings are a stable contract versioned by
+/// [`METRICS_JSON_SCHEMA_VERSION`].
+#[derive(Debug, Serialize)]
+pub struct AnalyzeResponse {
+    /// Wire format version, always [`METRICS_JSON_SCHEMA_VERSION`]
+    pub schema_version: u32,
+    /// Name of the analyzed plan
+    pub plan: String,
+    /// Institution name
+    pub institution: String,
+    /// Total structural complexity of the plan
+    pub total_complexity: usize,
+    /// Longest delay value
+    pub longest_delay: usize,
+    /// Courses along the longest delay path
+    pub longest_delay_path: Vec<String>,
+    /// Highest centrality value
+    pub highest_centrality: usize,
+    /// Course with the highest centrality
+    pub highest_centrality_course: String,
+    /// Per-course metrics keyed by course key
+    pub courses: BTreeMap<String, metrics::CourseMetrics>,
+}
+
+/// Analyze curriculum CSV bytes and return the metrics as a JSON string
+///
+/// This is the handler behind `POST /analyze`; it is independent of the
+/// HTTP layer so it can be called directly.
+///
+/// # Errors
+/// Returns an error message if the CSV cannot be parsed or metrics cannot be computed
+pub fn handle_analyze(body: &[u8]) -> Result<String, String> {
+    let school = parse_curriculum_reader(body, &ParseOptions::default())
+        .map_err(|e| format!("Failed to parse curriculum: {e}"))?;
+    let dag = school.build_dag();
+    let all_metrics: CurriculumMetrics = metrics::compute_all_metrics(&dag)
+        .map_err(|e| format!("Failed to compute metrics: {e}"))?;
+
+    let plan = school.plans.first().cloned().unwrap_or_else(|| {
+        let mut plan = Plan::new(
+            "All Courses".to_string(),
+            school.degrees.first().map_or_else(String::new, Degree::id),
+        );
+        for course in &dag.courses {
+            plan.add_course(course.clone());
+        }
+        plan
+    });
+    let summary = CurriculumSummary::from_metrics(&plan, &school, &all_metrics)
+        .with_delay_path(&dag, &all_metrics);
+
+    let response = AnalyzeResponse {
+        schema_version: METRICS_JSON_SCHEMA_VERSION,
+        institution: plan
+            .institution
+            .clone()
+            .unwrap_or_else(|| school.name.clone()),
+        plan: plan.name,
+        total_complexity: summary.total_complexity,
+        longest_delay: summary.longest_delay,
+        longest_delay_path: summary.longest_delay_path,
+        highest_centrality: summary.highest_centrality,
+        highest_centrality_course: summary.highest_centrality_course,
+        courses: all_metrics.into_iter().collect(),
+    };
+
+    serde_json::to_string(&response).map_err(|e| format!("Failed to encode response: {e}"))
+}
+
+/// Serve `POST /analyze` on the given address until the process exits
+///
+/// # Arguments
+/// * `addr` - Address to bind, e.g. `127.0.0.1:8080`
+///
+/// # Errors
+/// Returns an error if the server cannot bind to `addr`
+pub fn serve(addr: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let server = Server::http(addr)?;
+    let content_type = Header::from_bytes("Content-Type", "application/json")
+        .map_err(|()| "invalid Content-Type header")?;
+    crate::info!("Serving POST /analyze on {addr}");
+
+    for mut request in server.incoming_requests() {
+        let response = if request.method() != &Method::Post || request.url() != "/analyze" {
+            Response::from_string(r#"{"error":"not found"}"#).with_status_code(404)
+        } else {
+            match read_body(request.as_reader(), MAX_BODY_BYTES) {
+                Ok(Some(body)) => match handle_analyze(&body) {
+                    Ok(json) => Response::from_string(json),
+                    Err(e) => Response::from_string(error_json(&e)).with_status_code(400),
+                },
+                Ok(None) => Response::from_string(error_json(&format!(
+                    "request body exceeds {MAX_BODY_BYTES} bytes"
+                )))
+                .with_status_code(413),
+                Err(e) => Response::from_string(error_json(&e.to_string())).with_status_code(400),
+            }
+        };
+
+        let response = response.with_header(content_type.clone());
+        if let Err(e) = request.respond(response) {
+            crate::warn!("Failed to send response: {e}");
+        }
+        // The server runs until killed, so don't leave log lines buffered
+        crate::logger::flush_log_file();
+    }
+
+    Ok(())
+}
+
+/// Read a request body of at most `limit` bytes
+///
+/// Returns `None` once the body is known to exceed `limit`; at most `limit + 1`
+/// bytes are read.
+fn read_body(reader: impl Read, limit: u64) -> std::io::Result<Option<Vec<u8>>> {
+    let mut body = Vec::new();
+    reader
+        .take(limit.saturating_add(1))
+        .read_to_end(&mut body)?;
+    Ok((body.len() as u64 <= limit).then_some(body))
+}
+
+/// Encode an error message as a JSON object
+fn error_json(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn analyze_returns_metrics_json() {
+        let csv = std::fs::read("samples/plans/Colostate_CSDegree.csv").unwrap();
+        let json = handle_analyze(&csv).expect("analyze");
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["institution"], "Colorado State University");
+        assert!(value["total_complexity"].as_u64().unwrap() > 0);
+        let cs220 = &value["courses"]["CS220"];
+        assert!(cs220["delay"].as_u64().unwrap() >= 2);
+        assert!(cs220["complexity"].is_u64());
+    }
+
+    #[test]
+    fn analyze_json_matches_documented_schema() {
+        let csv = std::fs::read("samples/plans/Colostate_CSDegree.csv").unwrap();
+        let value: serde_json::Value =
+            serde_json::from_str(&handle_analyze(&csv).expect("analyze")).unwrap();
+
+        assert_eq!(value["schema_version"], METRICS_JSON_SCHEMA_VERSION);
+
+        let keys = |v: &serde_json::Value| {
+            let mut keys: Vec<String> = v.as_object().unwrap().keys().cloned().collect();
+            keys.sort();
+            keys
+        };
+        assert_eq!(
+            keys(&value),
+            [
+                "courses",
+                "highest_centrality",
+                "highest_centrality_course",
+                "institution",
+                "longest_delay",
+                "longest_delay_path",
+                "plan",
+                "schema_version",
+                "total_complexity",
+            ]
+        );
+        assert!(value["longest_delay_path"].is_array());
+        for course in value["courses"].as_object().unwrap().values() {
+            assert_eq!(
+                keys(course),
+                [
+                    "blocking",
+                    "centrality",
+                    "complexity",
+                    "delay",
+                    "paths_through",
+                    "total_paths",
+                ]
+            );
+            assert!(course
+                .as_object()
+                .unwrap()
+                .values()
+                .all(serde_json::Value::is_u64));
+        }
+    }
+
+    #[test]
+    fn read_body_rejects_oversized_bodies() {
+        let body = vec![b'x'; 16];
+        assert_eq!(read_body(body.as_slice(), 16).unwrap(), Some(body.clone()));
+        assert_eq!(read_body(body.as_slice(), 15).unwrap(), None);
+
+        // Only one byte past the limit is consumed
+        let mut reader = body.as_slice();
+        assert_eq!(read_body(&mut reader, 4).unwrap(), None);
+        assert_eq!(reader.len(), 11);
+    }
+
+    #[test]
+    fn analyze_rejects_invalid_csv() {
+        assert!(handle_analyze(b"not a curriculum").is_err());
+    }
+}
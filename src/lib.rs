@@ -2,6 +2,7 @@
 //! Core functionality used by the CLI and other components
 
 pub mod core;
+mod json;
 pub mod logger;
 
 pub use core::*;
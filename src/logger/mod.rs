@@ -1,42 +1,196 @@
 //! Internal logger module (migrated from crates/logger).
-//! Feature flags: `log-info`, `log-debug`, `verbose`, `file-logging`.
+//! Feature flags: `log-info`, `log-debug`, `log-trace`, `verbose`, `file-logging`, `level-watch`.
 
 // This logger was originally a seperate filesystem crate used for mutiple projects
 // but copied into this project for easier deploy - needs updating - ACL
 
+use std::borrow::Cow;
 use std::fmt::Arguments;
-#[cfg(feature = "log-debug")]
-use std::sync::atomic::AtomicBool;
-use std::sync::atomic::{AtomicU8, Ordering};
-use std::sync::LazyLock;
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
 
 #[cfg(feature = "file-logging")]
 use std::{
     fs::{File, OpenOptions},
-    io::Write,
-    sync::Mutex,
+    io::{BufWriter, Write},
+    sync::MutexGuard,
 };
 
+#[cfg(all(feature = "level-watch", not(target_arch = "wasm32")))]
+use std::{path::PathBuf, thread::JoinHandle, time::Duration};
+
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::JsValue;
 #[cfg(target_arch = "wasm32")]
 use web_sys::console;
 
+/// An open log file and the settings needed to rotate it.
+#[cfg(feature = "file-logging")]
+#[derive(Debug)]
+struct LogFile {
+    /// Buffered until [`Logger::flush`]; dropping the writer also flushes it
+    file: BufWriter<File>,
+    /// Bytes in the file including those still buffered
+    len: u64,
+    path: std::path::PathBuf,
+    /// Size at which the file is rotated; `None` lets it grow without bound
+    max_bytes: Option<u64>,
+}
+
+#[cfg(feature = "file-logging")]
+impl LogFile {
+    fn open(path: &std::path::Path, max_bytes: Option<u64>) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            len: file.metadata()?.len(),
+            file: BufWriter::new(file),
+            path: path.to_path_buf(),
+            max_bytes,
+        })
+    }
+
+    fn write_line(&mut self, message: &str) {
+        self.rotate_if_needed(message.len() as u64 + 1);
+        if writeln!(self.file, "{message}").is_ok() {
+            self.len += message.len() as u64 + 1;
+        }
+    }
+
+    /// Rotate before writing `incoming` bytes if they would push the file past `max_bytes`.
+    ///
+    /// Backups are shifted up (`path.1` to `path.2`, ...) and the current file becomes
+    /// `path.1`. If rotation fails the current file is kept, since the error cannot be
+    /// logged while the file lock is held.
+    fn rotate_if_needed(&mut self, incoming: u64) {
+        let Some(max_bytes) = self.max_bytes else {
+            return;
+        };
+        if self.len == 0 || self.len + incoming <= max_bytes {
+            return;
+        }
+        // Buffered lines belong to the file being rotated out
+        let _ = self.file.flush();
+
+        let backup = |n: usize| {
+            let mut name = self.path.clone().into_os_string();
+            name.push(format!(".{n}"));
+            std::path::PathBuf::from(name)
+        };
+        let mut highest = 0;
+        while backup(highest + 1).exists() {
+            highest += 1;
+        }
+        for n in (1..=highest).rev() {
+            if std::fs::rename(backup(n), backup(n + 1)).is_err() {
+                return;
+            }
+        }
+        if std::fs::rename(&self.path, backup(1)).is_err() {
+            return;
+        }
+        if let Ok(fresh) = Self::open(&self.path, self.max_bytes) {
+            *self = fresh;
+        }
+    }
+}
+
 /// Logging levels.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Level {
-    /// Error-level messages (always enabled).
+    /// Silences all output; set with [`set_level`], never used to log a message.
+    ///
+    /// This is the only way to mute the always-on error and warning levels.
+    Off = 0,
+    /// Error-level messages (always enabled unless the level is [`Level::Off`]).
     Error = 1,
-    /// Warning-level messages (always enabled).
+    /// Warning-level messages (always enabled unless the level is [`Level::Off`]).
     Warn = 2,
     /// Info-level messages (requires `log-info` feature).
     Info = 3,
     /// Debug-level messages (requires `log-debug` feature and runtime enablement).
     Debug = 4,
+    /// Trace-level messages (requires `log-trace` feature).
+    Trace = 5,
+}
+
+impl std::str::FromStr for Level {
+    type Err = String;
+
+    /// Parse a level name (case-insensitive), accepting `err`, `warning`, and `silent` as aliases.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "off" | "silent" => Ok(Self::Off),
+            "error" | "err" => Ok(Self::Error),
+            "warn" | "warning" => Ok(Self::Warn),
+            "info" => Ok(Self::Info),
+            "debug" => Ok(Self::Debug),
+            "trace" => Ok(Self::Trace),
+            _ => Err(format!(
+                "unknown log level '{s}'; expected one of off, error, warn, info, debug, trace"
+            )),
+        }
+    }
+}
+
+/// Application-provided destination for log messages (see [`Logger::set_sink`]).
+pub type LogSink = Box<dyn Fn(Level, &str) + Send + Sync>;
+
+/// Installed sink, shared so it can be called without holding the slot's lock.
+type SharedSink = Arc<dyn Fn(Level, &str) + Send + Sync>;
+
+/// Slot holding the sink installed with [`Logger::set_sink`].
+#[derive(Default)]
+struct SinkSlot(Mutex<Option<SharedSink>>);
+
+impl SinkSlot {
+    fn get(&self) -> Option<SharedSink> {
+        self.0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    fn set(&self, sink: Option<LogSink>) {
+        *self
+            .0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = sink.map(Arc::from);
+    }
+}
+
+impl std::fmt::Debug for SinkSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = if self.get().is_some() { "set" } else { "unset" };
+        write!(f, "SinkSlot({state})")
+    }
+}
+
+/// Line format used for the log file.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// `[LEVEL] message`, as printed to the console (the default).
+    #[default]
+    Plain,
+    /// One JSON object per line with `level`, `message`, and optional `timestamp` fields.
+    Json,
 }
 
+/// Environment variable read by [`init_from_env`] in the CLI (e.g. `NU_LOG=debug`).
+pub const DEFAULT_LEVEL_ENV_VAR: &str = "NU_LOG";
+
+/// Color prefixes only when writing to a terminal (the default).
+const COLOR_AUTO: u8 = 0;
+/// Color prefixes even when output is piped.
+const COLOR_ALWAYS: u8 = 1;
+/// Never color prefixes.
+const COLOR_NEVER: u8 = 2;
+
 const fn default_level() -> u8 {
-    if cfg!(feature = "log-debug") {
+    if cfg!(feature = "log-trace") {
+        Level::Trace as u8
+    } else if cfg!(feature = "log-debug") {
         Level::Debug as u8
     } else if cfg!(feature = "log-info") {
         Level::Info as u8
@@ -45,234 +199,725 @@ const fn default_level() -> u8 {
     }
 }
 
-/// Global storage for the current log level.
-static LOG_LEVEL: LazyLock<AtomicU8> = LazyLock::new(|| AtomicU8::new(default_level()));
-#[cfg(feature = "log-debug")]
-static DEBUG_ENABLED: AtomicBool = AtomicBool::new(true);
-#[cfg(feature = "verbose")]
-static VERBOSE_ENABLED: AtomicBool = AtomicBool::new(false);
-#[cfg(feature = "file-logging")]
-static LOG_FILE: LazyLock<Mutex<Option<File>>> = LazyLock::new(|| Mutex::new(None));
+/// A logger handle holding its own level, debug, verbose, and file state.
+///
+/// The free functions in this module operate on a shared global instance (see
+/// [`global`]). Libraries embedding the analytics code can create their own
+/// `Logger` to keep settings independent of the host application.
+#[derive(Debug)]
+pub struct Logger {
+    level: AtomicU8,
+    #[cfg(feature = "log-debug")]
+    debug_enabled: AtomicBool,
+    #[cfg(feature = "verbose")]
+    verbose_enabled: AtomicBool,
+    #[cfg(feature = "file-logging")]
+    log_file: Mutex<Option<LogFile>>,
+    color_mode: AtomicU8,
+    timestamps_enabled: AtomicBool,
+    json_file_format: AtomicBool,
+    sink: SinkSlot,
+}
 
-/// Set the global log level.
-pub fn set_level(level: Level) {
-    LOG_LEVEL.store(level as u8, Ordering::SeqCst);
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-#[must_use]
-/// Parse level from string (case-insensitive) and set it. Returns `true` on success.
-pub fn set_level_from_str(level: &str) -> bool {
-    match level.to_ascii_lowercase().as_str() {
-        "error" | "err" => {
-            set_level(Level::Error);
-            true
+impl Logger {
+    /// Create a logger with the default level for the enabled features.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            level: AtomicU8::new(default_level()),
+            #[cfg(feature = "log-debug")]
+            debug_enabled: AtomicBool::new(true),
+            #[cfg(feature = "verbose")]
+            verbose_enabled: AtomicBool::new(false),
+            #[cfg(feature = "file-logging")]
+            log_file: Mutex::new(None),
+            color_mode: AtomicU8::new(COLOR_AUTO),
+            timestamps_enabled: AtomicBool::new(false),
+            json_file_format: AtomicBool::new(false),
+            sink: SinkSlot(Mutex::new(None)),
         }
-        "warn" | "warning" => {
-            set_level(Level::Warn);
-            true
+    }
+
+    /// Set the log level.
+    pub fn set_level(&self, level: Level) {
+        self.level.store(level as u8, Ordering::SeqCst);
+    }
+
+    #[must_use]
+    /// Returns the current log level.
+    pub fn level(&self) -> Level {
+        match self.level.load(Ordering::SeqCst) {
+            0 => Level::Off,
+            1 => Level::Error,
+            2 => Level::Warn,
+            3 => Level::Info,
+            4 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    /// Parse level from string (case-insensitive) and set it.
+    ///
+    /// # Errors
+    /// Returns a message naming the unknown level and the accepted ones.
+    pub fn try_set_level_from_str(&self, level: &str) -> Result<Level, String> {
+        let level = level.parse()?;
+        self.set_level(level);
+        Ok(level)
+    }
+
+    #[must_use]
+    /// Parse level from string (case-insensitive) and set it. Returns `true` on success.
+    pub fn set_level_from_str(&self, level: &str) -> bool {
+        self.try_set_level_from_str(level).is_ok()
+    }
+
+    /// Set the level from the environment variable `var`. Returns `true` if it was applied.
+    ///
+    /// An unset or empty variable leaves the level unchanged; an unknown level is
+    /// reported as a warning and also ignored.
+    pub fn init_from_env(&self, var: &str) -> bool {
+        let Some(value) = std::env::var(var).ok().filter(|v| !v.trim().is_empty()) else {
+            return false;
+        };
+        match self.try_set_level_from_str(value.trim()) {
+            Ok(_) => true,
+            Err(e) => {
+                self.log(Level::Warn, format_args!("Ignoring {var}: {e}"));
+                false
+            }
         }
-        "info" => {
-            set_level(Level::Info);
+    }
+
+    /// Enable debug logging at runtime (no-op when `log-debug` feature is disabled).
+    pub fn enable_debug(&self) {
+        #[cfg(feature = "log-debug")]
+        self.debug_enabled.store(true, Ordering::SeqCst);
+    }
+
+    /// Disable debug logging at runtime (no-op when `log-debug` feature is disabled).
+    pub fn disable_debug(&self) {
+        #[cfg(feature = "log-debug")]
+        self.debug_enabled.store(false, Ordering::SeqCst);
+    }
+
+    #[must_use]
+    /// Returns whether debug logging is enabled (always false when feature is disabled).
+    pub fn is_debug_enabled(&self) -> bool {
+        #[cfg(feature = "log-debug")]
+        return self.debug_enabled.load(Ordering::SeqCst);
+        #[cfg(not(feature = "log-debug"))]
+        false
+    }
+
+    /// Enable verbose output at runtime (no-op when `verbose` feature is disabled).
+    pub fn enable_verbose(&self) {
+        #[cfg(feature = "verbose")]
+        self.verbose_enabled.store(true, Ordering::SeqCst);
+    }
+
+    /// Disable verbose output at runtime (no-op when `verbose` feature is disabled).
+    pub fn disable_verbose(&self) {
+        #[cfg(feature = "verbose")]
+        self.verbose_enabled.store(false, Ordering::SeqCst);
+    }
+
+    #[must_use]
+    /// Parse a verbose flag from string (see [`parse_verbose`]) and apply it. Returns `true` on success.
+    pub fn set_verbose_from_str(&self, value: &str) -> bool {
+        parse_verbose(value).is_some_and(|enabled| {
+            if enabled {
+                self.enable_verbose();
+            } else {
+                self.disable_verbose();
+            }
             true
+        })
+    }
+
+    #[must_use]
+    /// Returns whether verbose output is enabled (always false when feature is disabled).
+    pub fn is_verbose_enabled(&self) -> bool {
+        #[cfg(feature = "verbose")]
+        return self.verbose_enabled.load(Ordering::SeqCst);
+        #[cfg(not(feature = "verbose"))]
+        false
+    }
+
+    /// Allow ANSI-colored prefixes on native terminals (the default).
+    pub fn enable_color(&self) {
+        self.color_mode.store(COLOR_AUTO, Ordering::SeqCst);
+    }
+
+    /// Always print plain prefixes, even on a terminal.
+    pub fn disable_color(&self) {
+        self.color_mode.store(COLOR_NEVER, Ordering::SeqCst);
+    }
+
+    /// Force colored (`true`) or plain (`false`) prefixes regardless of whether
+    /// output is a terminal. [`Logger::enable_color`] restores auto-detection.
+    pub fn set_color(&self, enabled: bool) {
+        let mode = if enabled { COLOR_ALWAYS } else { COLOR_NEVER };
+        self.color_mode.store(mode, Ordering::SeqCst);
+    }
+
+    /// Prepend an RFC 3339 UTC timestamp to each log line (off by default).
+    ///
+    /// Applies to native and file output; verbose output stays untagged.
+    pub fn set_timestamps(&self, enabled: bool) {
+        self.timestamps_enabled.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Deliver messages to `sink` instead of stdout/stderr (or the browser console).
+    ///
+    /// The sink receives the level and the formatted message without its
+    /// `[LEVEL]` prefix. File logging, when active, still takes precedence.
+    /// Verbose output (see [`Logger::verbose`]) also goes to the sink, as [`Level::Info`].
+    pub fn set_sink(&self, sink: LogSink) {
+        self.sink.set(Some(sink));
+    }
+
+    /// Remove the sink installed with [`Logger::set_sink`], restoring console output.
+    pub fn clear_sink(&self) {
+        self.sink.set(None);
+    }
+
+    /// Choose the line format for file output; console output is always plain.
+    pub fn set_file_format(&self, format: LogFormat) {
+        self.json_file_format
+            .store(format == LogFormat::Json, Ordering::SeqCst);
+    }
+
+    #[must_use]
+    /// Returns the line format used for file output.
+    pub fn file_format(&self) -> LogFormat {
+        if self.json_file_format.load(Ordering::SeqCst) {
+            LogFormat::Json
+        } else {
+            LogFormat::Plain
+        }
+    }
+
+    /// Timestamp and trailing space to put before a prefixed line, or empty.
+    ///
+    /// Never stamps on wasm, where the clock is unavailable and the browser
+    /// console already records times.
+    fn timestamp_prefix(&self, prefix: &str) -> String {
+        if prefix.is_empty()
+            || cfg!(target_arch = "wasm32")
+            || !self.timestamps_enabled.load(Ordering::SeqCst)
+        {
+            return String::new();
+        }
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        format!("{} ", rfc3339_utc(now))
+    }
+
+    #[must_use]
+    /// Returns whether prefixes written to a stream would be colored.
+    ///
+    /// By default color requires the stream to be a terminal and the `NO_COLOR`
+    /// environment variable unset or empty; [`Logger::set_color`] overrides both.
+    pub fn use_color(&self, is_terminal: bool) -> bool {
+        match self.color_mode.load(Ordering::SeqCst) {
+            COLOR_ALWAYS => true,
+            COLOR_NEVER => false,
+            _ => is_terminal && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()),
+        }
+    }
+
+    /// The line written to the console for a prefixed message.
+    fn console_line(&self, prefix: &str, msg: &str, is_terminal: bool) -> String {
+        let stamp = self.timestamp_prefix(prefix);
+        let prefix = styled_prefix(prefix, self.use_color(is_terminal));
+        if prefix.is_empty() {
+            msg.to_string()
+        } else {
+            format!("{stamp}{prefix} {msg}")
         }
-        "debug" => {
-            set_level(Level::Debug);
+    }
+
+    #[must_use]
+    /// Initialize file logging to a specific path. Returns `true` on success.
+    pub fn init_file_logging(&self, path: &std::path::Path) -> bool {
+        self.open_log_file(path, None)
+    }
+
+    #[must_use]
+    /// Initialize file logging that rotates once the file would exceed `max_bytes`.
+    ///
+    /// The full file is renamed to `path.1` (older backups shift to `path.2`, ...)
+    /// and logging continues in a fresh file at `path`. Returns `true` on success.
+    pub fn init_file_logging_with_rotation(&self, path: &std::path::Path, max_bytes: u64) -> bool {
+        self.open_log_file(path, Some(max_bytes))
+    }
+
+    #[cfg(feature = "file-logging")]
+    fn open_log_file(&self, path: &std::path::Path, max_bytes: Option<u64>) -> bool {
+        LogFile::open(path, max_bytes).is_ok_and(|log_file| {
+            *self.lock_log_file() = Some(log_file);
             true
+        })
+    }
+
+    /// File logging is a no-op when the `file-logging` feature is disabled.
+    #[cfg(not(feature = "file-logging"))]
+    #[allow(clippy::unused_self)]
+    const fn open_log_file(&self, _path: &std::path::Path, _max_bytes: Option<u64>) -> bool {
+        false
+    }
+
+    /// Lock the log file, recovering it if another thread panicked while holding it.
+    ///
+    /// The poison is cleared and a warning logged, so one panicking caller does not
+    /// disable file logging for the rest of the process.
+    #[cfg(feature = "file-logging")]
+    fn lock_log_file(&self) -> MutexGuard<'_, Option<LogFile>> {
+        match self.log_file.lock() {
+            Ok(log_file) => log_file,
+            Err(poisoned) => {
+                self.log_file.clear_poison();
+                // Release the guard before logging, which locks the file again
+                drop(poisoned);
+                self.log(
+                    Level::Warn,
+                    format_args!("Recovered log file lock after a thread panicked while logging"),
+                );
+                self.log_file
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+            }
         }
-        _ => false,
+    }
+
+    #[cfg(feature = "file-logging")]
+    fn write_to_file(&self, message: &str) {
+        if let Some(ref mut log_file) = *self.lock_log_file() {
+            log_file.write_line(message);
+        }
+    }
+
+    /// Write buffered log file lines to disk (no-op without an open log file).
+    ///
+    /// File output is buffered, so call this before exiting; lines still
+    /// buffered in a `static` logger are otherwise lost.
+    pub fn flush(&self) {
+        #[cfg(feature = "file-logging")]
+        if let Some(ref mut log_file) = *self.lock_log_file() {
+            let _ = log_file.file.flush();
+        }
+    }
+
+    #[cfg(feature = "file-logging")]
+    fn is_file_logging_active(&self) -> bool {
+        self.lock_log_file().is_some()
+    }
+
+    fn emit(&self, level: Level, prefix: &str, msg: &str, to_stderr: bool) {
+        #[cfg(feature = "file-logging")]
+        {
+            if self.is_file_logging_active() && !prefix.is_empty() {
+                let stamp = self.timestamp_prefix(prefix);
+                let file_message = match self.file_format() {
+                    LogFormat::Plain => format!("{stamp}{prefix} {msg}"),
+                    LogFormat::Json => json_record(prefix, msg, stamp.trim_end()),
+                };
+                self.write_to_file(&file_message);
+                return;
+            }
+        }
+        if let Some(sink) = self.sink.get() {
+            sink(level, msg);
+            return;
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = to_stderr;
+            if prefix.is_empty() {
+                console::log_1(&JsValue::from_str(msg));
+            } else {
+                let formatted = format!("%c{} {}", prefix, msg);
+                fn style_for(prefix: &str) -> &'static str {
+                    match prefix {
+                        "[ERROR]" => "color:#fff;background:#c0392b;font-weight:bold;padding:1px 4px;border-radius:3px",
+                        "[WARN]" => "color:#000;background:#ffeb3b;font-weight:bold;padding:1px 4px;border-radius:3px",
+                        "[INFO]" => "",
+                        "[DEBUG]" => "color:#000;background:#bdc3c7;padding:1px 4px;border-radius:3px",
+                        "[TRACE]" => "color:#95a5a6",
+                        _ => "font-weight:bold",
+                    }
+                }
+                let style = style_for(prefix);
+                let formatted_js = JsValue::from_str(&formatted);
+                let style_js = JsValue::from_str(style);
+                match prefix {
+                    "[ERROR]" => console::error_2(&formatted_js, &style_js),
+                    "[WARN]" => console::warn_2(&formatted_js, &style_js),
+                    _ => console::log_2(&formatted_js, &style_js),
+                }
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let is_terminal = if to_stderr {
+                std::io::stderr().is_terminal()
+            } else {
+                std::io::stdout().is_terminal()
+            };
+            let line = self.console_line(prefix, msg, is_terminal);
+            if to_stderr {
+                eprintln!("{line}");
+            } else {
+                println!("{line}");
+            }
+        }
+    }
+
+    #[must_use]
+    /// Returns whether a message at `level` would be emitted by this logger.
+    ///
+    /// Nothing is emitted while the level is [`Level::Off`].
+    pub fn enabled(&self, level: Level) -> bool {
+        match level {
+            Level::Off => return false,
+            Level::Info if !cfg!(feature = "log-info") => return false,
+            Level::Debug if !cfg!(feature = "log-debug") => return false,
+            Level::Trace if !cfg!(feature = "log-trace") => return false,
+            _ => {}
+        }
+        let current = self.level.load(Ordering::SeqCst);
+        (level as u8) <= current && (level != Level::Debug || self.is_debug_enabled())
+    }
+
+    /// Print a verbose message through this logger.
+    ///
+    /// Does nothing unless verbose output is enabled. The message goes to the
+    /// installed sink as [`Level::Info`] if there is one, otherwise to stdout;
+    /// it is never written to the log file.
+    pub fn verbose(&self, args: Arguments) {
+        if !self.is_verbose_enabled() {
+            return;
+        }
+        let msg = args.to_string();
+        match self.sink.get() {
+            Some(sink) => sink(Level::Info, &msg),
+            None => println!("{msg}"),
+        }
+    }
+
+    /// Log a message through this logger.
+    pub fn log(&self, level: Level, args: Arguments) {
+        if !self.enabled(level) {
+            return;
+        }
+        let msg = args.to_string();
+        match level {
+            Level::Off => {}
+            Level::Error => self.emit(level, "[ERROR]", &msg, true),
+            Level::Warn => self.emit(level, "[WARN]", &msg, true),
+            Level::Info => self.emit(level, "[INFO]", &msg, false),
+            Level::Debug => self.emit(level, "[DEBUG]", &msg, false),
+            Level::Trace => self.emit(level, "[TRACE]", &msg, false),
+        }
+    }
+
+    #[cfg(all(feature = "level-watch", not(target_arch = "wasm32")))]
+    /// Spawn a thread that applies the level written in `path` whenever it changes.
+    ///
+    /// The file is polled every `interval`; its trimmed contents (e.g. `debug`) are passed
+    /// to [`Logger::set_level_from_str`]. A missing file is ignored, and an unknown level
+    /// is reported once per change. The thread runs for the life of the process.
+    pub fn watch_level_file_with_interval(
+        &'static self,
+        path: impl Into<PathBuf>,
+        interval: Duration,
+    ) -> JoinHandle<()> {
+        let path = path.into();
+        std::thread::spawn(move || {
+            let mut last_applied: Option<String> = None;
+            loop {
+                if let Ok(contents) = std::fs::read_to_string(&path) {
+                    let requested = contents.trim();
+                    if !requested.is_empty() && last_applied.as_deref() != Some(requested) {
+                        if !self.set_level_from_str(requested) {
+                            self.log(
+                                Level::Warn,
+                                format_args!(
+                                    "Ignoring unknown log level '{requested}' in {}",
+                                    path.display()
+                                ),
+                            );
+                        }
+                        last_applied = Some(requested.to_string());
+                    }
+                }
+                std::thread::sleep(interval);
+            }
+        })
     }
 }
 
-#[cfg(feature = "log-debug")]
-/// Enable debug logging at runtime.
-pub fn enable_debug() {
-    DEBUG_ENABLED.store(true, Ordering::SeqCst);
+/// Wrap a level prefix in its ANSI color when `color` is set.
+///
+/// Errors are red, warnings yellow, info green, debug dimmed, and trace grey; unknown or
+/// empty prefixes are returned unchanged.
+#[must_use]
+pub fn styled_prefix(prefix: &str, color: bool) -> Cow<'_, str> {
+    let code = match prefix {
+        _ if !color => None,
+        "[ERROR]" => Some("1;31"),
+        "[WARN]" => Some("1;33"),
+        "[INFO]" => Some("32"),
+        "[DEBUG]" => Some("2"),
+        "[TRACE]" => Some("90"),
+        _ => None,
+    };
+    code.map_or(Cow::Borrowed(prefix), |code| {
+        Cow::Owned(format!("\x1b[{code}m{prefix}\x1b[0m"))
+    })
 }
-#[cfg(not(feature = "log-debug"))]
+
+/// Render one log line as a JSON object for [`LogFormat::Json`].
+///
+/// The level is taken from the prefix (`[WARN]` becomes `"warn"`); the
+/// `timestamp` field is omitted when `stamp` is empty.
+#[cfg(feature = "file-logging")]
+fn json_record(prefix: &str, msg: &str, stamp: &str) -> String {
+    let level = prefix.trim_matches(['[', ']']).to_ascii_lowercase();
+    let timestamp = if stamp.is_empty() {
+        String::new()
+    } else {
+        format!("\"timestamp\":{},", crate::json::json_string(stamp))
+    };
+    format!(
+        "{{{timestamp}\"level\":{},\"message\":{}}}",
+        crate::json::json_string(&level),
+        crate::json::json_string(msg)
+    )
+}
+
+/// Format seconds since the Unix epoch as an RFC 3339 UTC timestamp.
+///
+/// Uses Howard Hinnant's days-to-civil conversion, e.g. `2024-01-02T03:04:05Z`.
+#[must_use]
+pub fn rfc3339_utc(unix_secs: u64) -> String {
+    let days = unix_secs / 86_400;
+    let secs_of_day = unix_secs % 86_400;
+
+    // Shift the epoch to 0000-03-01 so leap days fall at the end of each era year
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+#[cfg(all(feature = "level-watch", not(target_arch = "wasm32")))]
+/// How often [`watch_level_file`] polls the level file.
+pub const LEVEL_WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Global logger instance backing the free functions and macros.
+static GLOBAL: Logger = Logger::new();
+
+/// Returns the global logger used by the free functions and macros.
+#[must_use]
+pub fn global() -> &'static Logger {
+    &GLOBAL
+}
+
+/// Set the global log level.
+pub fn set_level(level: Level) {
+    GLOBAL.set_level(level);
+}
+
+/// Parse level from string (case-insensitive) and set it globally.
+///
+/// # Errors
+/// Returns a message naming the unknown level and the accepted ones.
+pub fn try_set_level_from_str(level: &str) -> Result<Level, String> {
+    GLOBAL.try_set_level_from_str(level)
+}
+
+#[must_use]
+/// Parse level from string (case-insensitive) and set it. Returns `true` on success.
+pub fn set_level_from_str(level: &str) -> bool {
+    GLOBAL.set_level_from_str(level)
+}
+
+/// Set the global level from the environment variable `var` (see [`DEFAULT_LEVEL_ENV_VAR`]).
+///
+/// Returns `true` if the variable held a valid level; unset or empty variables are ignored.
+pub fn init_from_env(var: &str) -> bool {
+    GLOBAL.init_from_env(var)
+}
+
 /// Enable debug logging at runtime (no-op when `log-debug` feature is disabled).
-pub fn enable_debug() {}
+pub fn enable_debug() {
+    GLOBAL.enable_debug();
+}
 
-#[cfg(feature = "log-debug")]
-/// Disable debug logging at runtime.
+/// Disable debug logging at runtime (no-op when `log-debug` feature is disabled).
 pub fn disable_debug() {
-    DEBUG_ENABLED.store(false, Ordering::SeqCst);
+    GLOBAL.disable_debug();
 }
-#[cfg(not(feature = "log-debug"))]
-/// Disable debug logging at runtime (no-op when `log-debug` feature is disabled).
-pub fn disable_debug() {}
 
-#[cfg(feature = "log-debug")]
-/// Returns whether debug logging is enabled.
-pub fn is_debug_enabled() -> bool {
-    DEBUG_ENABLED.load(Ordering::SeqCst)
-}
-#[cfg(not(feature = "log-debug"))]
 /// Returns whether debug logging is enabled (always false when feature is disabled).
 pub fn is_debug_enabled() -> bool {
-    false
+    GLOBAL.is_debug_enabled()
 }
 
-#[cfg(feature = "verbose")]
-/// Enable verbose output at runtime.
+/// Enable verbose output at runtime (no-op when `verbose` feature is disabled).
 pub fn enable_verbose() {
-    VERBOSE_ENABLED.store(true, Ordering::SeqCst);
+    GLOBAL.enable_verbose();
 }
-#[cfg(not(feature = "verbose"))]
-/// Enable verbose output at runtime (no-op when `verbose` feature is disabled).
-pub fn enable_verbose() {}
 
-#[cfg(feature = "verbose")]
-/// Disable verbose output at runtime.
+/// Disable verbose output at runtime (no-op when `verbose` feature is disabled).
 pub fn disable_verbose() {
-    VERBOSE_ENABLED.store(false, Ordering::SeqCst);
+    GLOBAL.disable_verbose();
 }
-#[cfg(not(feature = "verbose"))]
-/// Disable verbose output at runtime (no-op when `verbose` feature is disabled).
-pub fn disable_verbose() {}
 
-#[cfg(feature = "verbose")]
-/// Returns whether verbose output is enabled.
-pub fn is_verbose_enabled() -> bool {
-    VERBOSE_ENABLED.load(Ordering::SeqCst)
+#[must_use]
+/// Parse a verbose flag from string and set it globally. Returns `true` on success.
+pub fn set_verbose_from_str(value: &str) -> bool {
+    GLOBAL.set_verbose_from_str(value)
 }
-#[cfg(not(feature = "verbose"))]
+
+#[must_use]
+/// Parse a verbose flag (case-insensitive): `true`/`false`, `1`/`0`, or `on`/`off`.
+pub fn parse_verbose(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "true" | "1" | "on" => Some(true),
+        "false" | "0" | "off" => Some(false),
+        _ => None,
+    }
+}
+
 /// Returns whether verbose output is enabled (always false when feature is disabled).
 pub fn is_verbose_enabled() -> bool {
-    false
+    GLOBAL.is_verbose_enabled()
 }
 
-#[cfg(feature = "file-logging")]
-#[must_use]
-/// Initialize file logging to a specific path. Returns `true` on success.
-pub fn init_file_logging(path: &std::path::Path) -> bool {
-    OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(path)
-        .is_ok_and(|file| {
-            LOG_FILE.lock().is_ok_and(|mut log_file| {
-                *log_file = Some(file);
-                true
-            })
-        })
+/// Allow ANSI-colored prefixes on native terminals (the default).
+pub fn enable_color() {
+    GLOBAL.enable_color();
 }
 
-#[cfg(not(feature = "file-logging"))]
-/// Initialize file logging (no-op when `file-logging` feature is disabled).
-pub fn init_file_logging(_path: &std::path::Path) -> bool {
-    false
+/// Always print plain prefixes, even on a terminal.
+pub fn disable_color() {
+    GLOBAL.disable_color();
 }
 
-#[cfg(feature = "file-logging")]
-fn write_to_file(message: &str) {
-    if let Ok(mut log_file) = LOG_FILE.lock() {
-        if let Some(ref mut file) = *log_file {
-            let _ = writeln!(file, "{message}");
-            let _ = file.flush();
-        }
-    }
+/// Force colored or plain global prefixes regardless of the terminal (see [`Logger::set_color`]).
+pub fn set_color(enabled: bool) {
+    GLOBAL.set_color(enabled);
 }
 
-#[cfg(not(feature = "file-logging"))]
-fn write_to_file(_message: &str) {}
+/// Write buffered lines of the global log file to disk (see [`Logger::flush`]).
+pub fn flush_log_file() {
+    GLOBAL.flush();
+}
 
-#[cfg(feature = "file-logging")]
-fn is_file_logging_active() -> bool {
-    LOG_FILE.lock().map(|lf| lf.is_some()).unwrap_or(false)
+/// Deliver global log messages to `sink` instead of the console (see [`Logger::set_sink`]).
+pub fn set_sink(sink: LogSink) {
+    GLOBAL.set_sink(sink);
 }
-#[cfg(not(feature = "file-logging"))]
-fn is_file_logging_active() -> bool {
-    false
+
+/// Remove the global sink, restoring console output.
+pub fn clear_sink() {
+    GLOBAL.clear_sink();
 }
 
-fn emit(prefix: &str, msg: &str, to_stderr: bool) {
-    #[cfg(feature = "file-logging")]
-    {
-        if is_file_logging_active() && !prefix.is_empty() {
-            let file_message = format!("{prefix} {msg}");
-            write_to_file(&file_message);
-            return;
-        }
-    }
-    #[cfg(target_arch = "wasm32")]
-    {
-        let _ = to_stderr;
-        if prefix.is_empty() {
-            console::log_1(&JsValue::from_str(msg));
-        } else {
-            let formatted = format!("%c{} {}", prefix, msg);
-            fn style_for(prefix: &str) -> &'static str {
-                match prefix {
-                    "[ERROR]" => "color:#fff;background:#c0392b;font-weight:bold;padding:1px 4px;border-radius:3px",
-                    "[WARN]" => "color:#000;background:#ffeb3b;font-weight:bold;padding:1px 4px;border-radius:3px",
-                    "[INFO]" => "",
-                    "[DEBUG]" => "color:#000;background:#bdc3c7;padding:1px 4px;border-radius:3px",
-                    _ => "font-weight:bold",
-                }
-            }
-            let style = style_for(prefix);
-            let formatted_js = JsValue::from_str(&formatted);
-            let style_js = JsValue::from_str(style);
-            match prefix {
-                "[ERROR]" => console::error_2(&formatted_js, &style_js),
-                "[WARN]" => console::warn_2(&formatted_js, &style_js),
-                _ => console::log_2(&formatted_js, &style_js),
-            }
-        }
-    }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        if to_stderr {
-            if prefix.is_empty() {
-                eprintln!("{msg}");
-            } else {
-                eprintln!("{prefix} {msg}");
-            }
-        } else if prefix.is_empty() {
-            println!("{msg}");
-        } else {
-            println!("{prefix} {msg}");
-        }
-    }
+/// Choose the line format for the global log file (see [`LogFormat`]).
+pub fn set_file_format(format: LogFormat) {
+    GLOBAL.set_file_format(format);
 }
 
-fn should_log(level: Level) -> bool {
-    match level {
-        Level::Info => {
-            if !cfg!(feature = "log-info") {
-                return false;
-            }
-        }
-        Level::Debug => {
-            if !cfg!(feature = "log-debug") {
-                return false;
-            }
-        }
-        _ => {}
-    }
-    let current = LOG_LEVEL.load(Ordering::SeqCst);
-    (level as u8) <= current && (level != Level::Debug || is_debug_enabled())
+/// Prepend an RFC 3339 UTC timestamp to each global log line (off by default).
+pub fn set_timestamps(enabled: bool) {
+    GLOBAL.set_timestamps(enabled);
+}
+
+#[must_use]
+/// Initialize file logging to a specific path. Returns `true` on success.
+pub fn init_file_logging(path: &std::path::Path) -> bool {
+    GLOBAL.init_file_logging(path)
+}
+
+#[must_use]
+/// Initialize file logging with size-based rotation (see [`Logger::init_file_logging_with_rotation`]).
+pub fn init_file_logging_with_rotation(path: &std::path::Path, max_bytes: u64) -> bool {
+    GLOBAL.init_file_logging_with_rotation(path, max_bytes)
+}
+
+#[cfg(all(feature = "level-watch", not(target_arch = "wasm32")))]
+/// Watch `path` in the background and apply the level it contains to the global logger.
+///
+/// Polls every [`LEVEL_WATCH_INTERVAL`]; see [`Logger::watch_level_file_with_interval`].
+pub fn watch_level_file(path: impl Into<PathBuf>) -> JoinHandle<()> {
+    GLOBAL.watch_level_file_with_interval(path, LEVEL_WATCH_INTERVAL)
+}
+
+#[cfg(target_arch = "wasm32")]
+/// Start a collapsible console group with the given label. Pair with [`group_end`].
+pub fn group(label: &str) {
+    console::group_1(&JsValue::from_str(label));
 }
+#[cfg(not(target_arch = "wasm32"))]
+/// Start a collapsible console group (no-op on native targets). Pair with [`group_end`].
+pub const fn group(_label: &str) {}
+
+#[cfg(target_arch = "wasm32")]
+/// Close the innermost console group opened by [`group`].
+pub fn group_end() {
+    console::group_end();
+}
+#[cfg(not(target_arch = "wasm32"))]
+/// Close the innermost console group (no-op on native targets).
+pub const fn group_end() {}
 
 /// Internal logging dispatcher used by public macros.
 pub fn log_impl(level: Level, args: Arguments) {
-    if !should_log(level) {
-        return;
-    }
-    let msg = args.to_string();
-    match level {
-        Level::Error => emit("[ERROR]", &msg, true),
-        Level::Warn => emit("[WARN]", &msg, true),
-        Level::Info => emit("[INFO]", &msg, false),
-        Level::Debug => emit("[DEBUG]", &msg, false),
-    }
+    GLOBAL.log(level, args);
+}
+
+/// Internal verbose dispatcher used by [`verbose!`].
+pub fn verbose_impl(args: Arguments) {
+    GLOBAL.verbose(args);
+}
+
+#[must_use]
+/// Returns whether the global logger would emit a message at `level`.
+///
+/// Lets callers skip work that only feeds a suppressed log message.
+pub fn log_enabled(level: Level) -> bool {
+    GLOBAL.enabled(level)
 }
 
 #[macro_export]
-/// Logs an error-level message (always enabled).
+/// Logs an error-level message (always enabled unless the level is `Off`).
 macro_rules! error { ($($arg:tt)*) => { $crate::logger::log_impl($crate::logger::Level::Error, format_args!($($arg)*)) }; }
 #[macro_export]
-/// Logs a warning-level message (always enabled).
+/// Logs a warning-level message (always enabled unless the level is `Off`).
 macro_rules! warn  { ($($arg:tt)*) => { $crate::logger::log_impl($crate::logger::Level::Warn,  format_args!($($arg)*)) }; }
 #[macro_export]
 /// Logs an info-level message (requires `log-info` feature).
@@ -281,12 +926,559 @@ macro_rules! info  { ($($arg:tt)*) => { $crate::logger::log_impl($crate::logger:
 /// Logs a debug-level message (requires `log-debug` feature and runtime enablement).
 macro_rules! debug { ($($arg:tt)*) => { $crate::logger::log_impl($crate::logger::Level::Debug, format_args!($($arg)*)) }; }
 #[macro_export]
+/// Logs a trace-level message (requires `log-trace` feature).
+macro_rules! trace { ($($arg:tt)*) => { $crate::logger::log_impl($crate::logger::Level::Trace, format_args!($($arg)*)) }; }
+#[macro_export]
+/// Logs a message at `level` through `logger`, evaluating arguments only if the level is enabled.
+///
+/// Backs the `*_lazy!` macros, e.g. `log_lazy!(global(), Level::Info, "{}", x)`.
+macro_rules! log_lazy {
+    ($logger:expr, $level:expr, $($arg:tt)*) => {{
+        let logger: &$crate::logger::Logger = $logger;
+        let level: $crate::logger::Level = $level;
+        if logger.enabled(level) { logger.log(level, format_args!($($arg)*)) }
+    }};
+}
+#[macro_export]
+/// Logs an error-level message, evaluating arguments only if the level is enabled.
+///
+/// Pass `logger: <&Logger>,` first to log through a specific logger instead of the global one.
+macro_rules! error_lazy {
+    (logger: $logger:expr, $($arg:tt)*) => { $crate::log_lazy!($logger, $crate::logger::Level::Error, $($arg)*) };
+    ($($arg:tt)*) => { $crate::log_lazy!($crate::logger::global(), $crate::logger::Level::Error, $($arg)*) };
+}
+#[macro_export]
+/// Logs a warning-level message, evaluating arguments only if the level is enabled.
+///
+/// Accepts a leading `logger: <&Logger>,` like [`error_lazy!`].
+macro_rules! warn_lazy {
+    (logger: $logger:expr, $($arg:tt)*) => { $crate::log_lazy!($logger, $crate::logger::Level::Warn, $($arg)*) };
+    ($($arg:tt)*) => { $crate::log_lazy!($crate::logger::global(), $crate::logger::Level::Warn, $($arg)*) };
+}
+#[macro_export]
+/// Logs an info-level message, evaluating arguments only if the level is enabled.
+///
+/// Accepts a leading `logger: <&Logger>,` like [`error_lazy!`].
+macro_rules! info_lazy {
+    (logger: $logger:expr, $($arg:tt)*) => { $crate::log_lazy!($logger, $crate::logger::Level::Info, $($arg)*) };
+    ($($arg:tt)*) => { $crate::log_lazy!($crate::logger::global(), $crate::logger::Level::Info, $($arg)*) };
+}
+#[macro_export]
+/// Logs a debug-level message, evaluating arguments only if debug logging is enabled.
+///
+/// Use instead of [`debug!`] when an argument is expensive to compute, e.g.
+/// `debug_lazy!("dag: {}", dag.dump())`. Accepts a leading `logger: <&Logger>,`
+/// like [`error_lazy!`].
+macro_rules! debug_lazy {
+    (logger: $logger:expr, $($arg:tt)*) => { $crate::log_lazy!($logger, $crate::logger::Level::Debug, $($arg)*) };
+    ($($arg:tt)*) => { $crate::log_lazy!($crate::logger::global(), $crate::logger::Level::Debug, $($arg)*) };
+}
+#[macro_export]
+/// Logs a trace-level message, evaluating arguments only if the level is enabled.
+///
+/// Accepts a leading `logger: <&Logger>,` like [`error_lazy!`].
+macro_rules! trace_lazy {
+    (logger: $logger:expr, $($arg:tt)*) => { $crate::log_lazy!($logger, $crate::logger::Level::Trace, $($arg)*) };
+    ($($arg:tt)*) => { $crate::log_lazy!($crate::logger::global(), $crate::logger::Level::Trace, $($arg)*) };
+}
+#[macro_export]
 /// Prints a verbose message (requires `verbose` feature and runtime enablement). This does not write to log files.
+///
+/// With a sink installed (see [`Logger::set_sink`]), the message goes to the sink instead of stdout.
 macro_rules! verbose {
     ($($arg:tt)*) => {
         #[cfg(feature = "verbose")]
         {
-            if $crate::logger::is_verbose_enabled() { println!($($arg)*); }
+            $crate::logger::verbose_impl(format_args!($($arg)*));
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instances_have_independent_levels() {
+        let quiet = Logger::new();
+        let loud = Logger::new();
+
+        quiet.set_level(Level::Error);
+        loud.set_level(Level::Debug);
+
+        assert!(quiet.enabled(Level::Error));
+        assert!(!quiet.enabled(Level::Warn));
+        assert!(loud.enabled(Level::Warn));
+        assert_eq!(
+            loud.enabled(Level::Debug),
+            cfg!(feature = "log-debug") && loud.is_debug_enabled()
+        );
+    }
+
+    #[cfg(all(feature = "level-watch", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_watch_level_file_applies_changes() {
+        fn wait_for(condition: impl Fn() -> bool) -> bool {
+            let deadline = std::time::Instant::now() + Duration::from_secs(2);
+            while std::time::Instant::now() < deadline {
+                if condition() {
+                    return true;
+                }
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            false
+        }
+
+        let logger: &'static Logger = Box::leak(Box::new(Logger::new()));
+        logger.set_level(Level::Error);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log-level");
+        std::fs::write(&path, "warn\n").unwrap();
+        logger.watch_level_file_with_interval(&path, Duration::from_millis(5));
+        assert!(wait_for(|| logger.enabled(Level::Warn)));
+
+        std::fs::write(&path, "error").unwrap();
+        assert!(wait_for(|| !logger.enabled(Level::Warn)));
+    }
+
+    #[cfg(feature = "file-logging")]
+    #[test]
+    fn test_file_logging_recovers_from_poisoned_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nuanalytics.log");
+        let logger = Logger::new();
+        assert!(logger.init_file_logging(&path));
+
+        let panicked = std::thread::scope(|s| {
+            s.spawn(|| {
+                let _guard = logger.log_file.lock().unwrap();
+                panic!("poisoning the log file lock");
+            })
+            .join()
+        });
+        assert!(panicked.is_err());
+        assert!(logger.log_file.is_poisoned());
+
+        logger.log(Level::Error, format_args!("still logging"));
+
+        assert!(!logger.log_file.is_poisoned());
+        logger.flush();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("[WARN] Recovered log file lock"));
+        assert!(contents.contains("[ERROR] still logging"));
+    }
+
+    #[test]
+    fn test_trace_is_silent_at_debug_level() {
+        let logger = Logger::new();
+        logger.set_level(Level::Debug);
+        assert!(!logger.enabled(Level::Trace));
+
+        logger.set_level(Level::Trace);
+        assert_eq!(logger.enabled(Level::Trace), cfg!(feature = "log-trace"));
+        assert_eq!("TRACE".parse(), Ok(Level::Trace));
+        assert_eq!(styled_prefix("[TRACE]", true), "\x1b[90m[TRACE]\x1b[0m");
+    }
+
+    #[cfg(feature = "file-logging")]
+    #[test]
+    fn test_trace_emits_at_trace_level() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trace.log");
+        let logger = Logger::new();
+        assert!(logger.init_file_logging(&path));
+
+        logger.set_level(Level::Debug);
+        logger.log(Level::Trace, format_args!("parsed row 3"));
+        logger.set_level(Level::Trace);
+        logger.log(Level::Trace, format_args!("parsed row 4"));
+
+        logger.flush();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("parsed row 3"));
+        assert_eq!(
+            contents.contains("[TRACE] parsed row 4"),
+            cfg!(feature = "log-trace")
+        );
+    }
+
+    #[test]
+    fn test_off_level_silences_errors() {
+        let logger = Logger::new();
+        logger.set_level(Level::Off);
+        assert!(!logger.enabled(Level::Error));
+        assert!(!logger.enabled(Level::Warn));
+        assert!(!logger.enabled(Level::Off));
+
+        assert!(logger.set_level_from_str("silent"));
+        assert!(!logger.enabled(Level::Error));
+        assert!(logger.set_level_from_str("error"));
+        assert!(logger.enabled(Level::Error));
+        assert!(logger.set_level_from_str("OFF"));
+        assert!(!logger.enabled(Level::Error));
+    }
+
+    #[cfg(feature = "file-logging")]
+    #[test]
+    fn test_error_produces_no_output_when_off() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("muted.log");
+        let logger = Logger::new();
+        assert!(logger.init_file_logging(&path));
+
+        logger.set_level(Level::Off);
+        logger.log(Level::Error, format_args!("muted failure"));
+        logger.log(Level::Warn, format_args!("muted warning"));
+
+        logger.flush();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+    }
+
+    #[test]
+    fn test_rfc3339_utc_formats_known_instants() {
+        assert_eq!(rfc3339_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(rfc3339_utc(1_704_164_645), "2024-01-02T03:04:05Z");
+        assert_eq!(rfc3339_utc(951_868_799), "2000-02-29T23:59:59Z");
+    }
+
+    #[cfg(feature = "file-logging")]
+    #[test]
+    fn test_timestamps_prefix_file_lines_when_enabled() {
+        fn is_timestamped(line: &str) -> bool {
+            let bytes = line.as_bytes();
+            line.len() > 21
+                && bytes[..20].iter().enumerate().all(|(i, &b)| match i {
+                    4 | 7 => b == b'-',
+                    10 => b == b'T',
+                    13 | 16 => b == b':',
+                    19 => b == b'Z',
+                    _ => b.is_ascii_digit(),
+                })
+                && line[20..].starts_with(" [ERROR]")
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stamped.log");
+        let logger = Logger::new();
+        assert!(logger.init_file_logging(&path));
+
+        logger.log(Level::Error, format_args!("plain"));
+        logger.set_timestamps(true);
+        logger.log(Level::Error, format_args!("stamped"));
+
+        logger.flush();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines[0], "[ERROR] plain");
+        assert!(is_timestamped(lines[1]), "{}", lines[1]);
+        assert!(lines[1].ends_with("[ERROR] stamped"));
+    }
+
+    #[test]
+    fn test_init_from_env_applies_valid_levels() {
+        // The name is unique to this test so parallel tests never share it
+        let var = "NU_ANALYTICS_TEST_INIT_FROM_ENV";
+        let logger = Logger::new();
+        logger.set_level(Level::Warn);
+
+        std::env::remove_var(var);
+        assert!(!logger.init_from_env(var));
+        std::env::set_var(var, "  ");
+        assert!(!logger.init_from_env(var));
+        assert_eq!(logger.level(), Level::Warn);
+
+        std::env::set_var(var, "error");
+        assert!(logger.init_from_env(var));
+        assert_eq!(logger.level(), Level::Error);
+
+        std::env::set_var(var, "chatty");
+        assert!(!logger.init_from_env(var));
+        assert_eq!(logger.level(), Level::Error);
+
+        std::env::remove_var(var);
+    }
+
+    #[cfg(feature = "file-logging")]
+    #[test]
+    fn test_log_file_rotates_past_max_bytes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("batch.log");
+        let rotated = dir.path().join("batch.log.1");
+        let logger = Logger::new();
+        assert!(logger.init_file_logging_with_rotation(&path, 64));
+
+        // Each line is 48 bytes, so the second one would exceed the limit
+        let line = "x".repeat(40);
+        logger.log(Level::Error, format_args!("{line}"));
+        assert!(!rotated.exists());
+        logger.log(Level::Error, format_args!("{line}"));
+        logger.flush();
+
+        assert!(path.exists());
+        assert!(rotated.exists());
+        assert_eq!(
+            std::fs::read_to_string(&rotated).unwrap().lines().count(),
+            1
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 1);
+        assert!(!dir.path().join("batch.log.2").exists());
+    }
+
+    #[cfg(feature = "file-logging")]
+    #[test]
+    fn test_json_file_format_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("records.jsonl");
+        let logger = Logger::new();
+        assert!(logger.init_file_logging(&path));
+        logger.set_file_format(LogFormat::Json);
+
+        logger.log(
+            Level::Error,
+            format_args!("Course \"CS 101\" has\ta \\ cycle"),
+        );
+        logger.set_timestamps(true);
+        logger.log(Level::Warn, format_args!("stamped"));
+
+        logger.flush();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let records: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+
+        assert_eq!(records[0]["level"], "error");
+        assert_eq!(records[0]["message"], "Course \"CS 101\" has\ta \\ cycle");
+        assert!(records[0].get("timestamp").is_none());
+
+        assert_eq!(records[1]["level"], "warn");
+        assert_eq!(records[1]["message"], "stamped");
+        assert!(records[1]["timestamp"].as_str().unwrap().ends_with('Z'));
+    }
+
+    #[cfg(feature = "file-logging")]
+    #[test]
+    fn test_buffered_file_lines_present_after_flush() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bulk.log");
+        let logger = Logger::new();
+        assert!(logger.init_file_logging(&path));
+
+        let start = std::time::Instant::now();
+        for i in 0..10_000 {
+            logger.log(Level::Error, format_args!("course {i} exported"));
+        }
+        logger.flush();
+        let elapsed = start.elapsed();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 10_000);
+        assert_eq!(
+            contents.lines().last(),
+            Some("[ERROR] course 9999 exported")
+        );
+        // Generous bound; an unbuffered flush per line is typically far slower
+        assert!(
+            elapsed < std::time::Duration::from_secs(5),
+            "took {elapsed:?}"
+        );
+    }
+
+    #[test]
+    fn test_sink_captures_messages() {
+        let captured: Arc<Mutex<Vec<(Level, String)>>> = Arc::default();
+        let logger = Logger::new();
+        logger.set_level(Level::Warn);
+
+        let into = Arc::clone(&captured);
+        logger.set_sink(Box::new(move |level, msg| {
+            into.lock().unwrap().push((level, msg.to_string()));
+        }));
+        logger.log(Level::Error, format_args!("cycle at {}", "CS201"));
+        logger.log(Level::Warn, format_args!("unknown prerequisite"));
+        logger.log(Level::Info, format_args!("filtered out by level"));
+
+        logger.clear_sink();
+        logger.log(Level::Error, format_args!("back to stderr"));
+
+        assert_eq!(
+            *captured.lock().unwrap(),
+            vec![
+                (Level::Error, "cycle at CS201".to_string()),
+                (Level::Warn, "unknown prerequisite".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sink_captures_verbose_output() {
+        let captured: Arc<Mutex<Vec<(Level, String)>>> = Arc::default();
+        let logger = Logger::new();
+        let into = Arc::clone(&captured);
+        logger.set_sink(Box::new(move |level, msg| {
+            into.lock().unwrap().push((level, msg.to_string()));
+        }));
+
+        logger.verbose(format_args!("suppressed until enabled"));
+        logger.enable_verbose();
+        logger.verbose(format_args!("parsed {} courses", 42));
+
+        let expected = if cfg!(feature = "verbose") {
+            vec![(Level::Info, "parsed 42 courses".to_string())]
+        } else {
+            Vec::new()
+        };
+        assert_eq!(*captured.lock().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_instance_does_not_touch_global() {
+        let before = global().enabled(Level::Warn);
+        let local = Logger::new();
+        local.set_level(Level::Error);
+
+        assert!(!local.enabled(Level::Warn));
+        assert_eq!(global().enabled(Level::Warn), before);
+    }
+
+    #[test]
+    fn test_parse_verbose_accepted_forms() {
+        for value in ["true", "1", "on", "ON", " True "] {
+            assert_eq!(parse_verbose(value), Some(true), "{value}");
+        }
+        for value in ["false", "0", "off", "Off"] {
+            assert_eq!(parse_verbose(value), Some(false), "{value}");
+        }
+        assert_eq!(parse_verbose("yes-please"), None);
+        assert_eq!(parse_verbose(""), None);
+    }
+
+    #[test]
+    fn test_set_verbose_from_str_on_instance() {
+        let logger = Logger::new();
+        assert!(logger.set_verbose_from_str("on"));
+        #[cfg(feature = "verbose")]
+        assert!(logger.is_verbose_enabled());
+        assert!(logger.set_verbose_from_str("0"));
+        assert!(!logger.is_verbose_enabled());
+        assert!(!logger.set_verbose_from_str("maybe"));
+        assert!(!logger.is_verbose_enabled());
+    }
+
+    #[test]
+    fn test_try_set_level_from_str_reports_unknown_level() {
+        let logger = Logger::new();
+        assert_eq!(logger.try_set_level_from_str("Info"), Ok(Level::Info));
+        assert_eq!(logger.level(), Level::Info);
+
+        let err = logger.try_set_level_from_str("verbos").unwrap_err();
+        assert_eq!(
+            err,
+            "unknown log level 'verbos'; expected one of off, error, warn, info, debug, trace"
+        );
+        assert_eq!(logger.level(), Level::Info);
+        assert!(!logger.set_level_from_str("verbos"));
+    }
+
+    #[test]
+    fn test_set_level_from_str_on_instance() {
+        let logger = Logger::new();
+        assert!(logger.set_level_from_str("WARNING"));
+        assert!(logger.enabled(Level::Warn));
+        assert!(!logger.enabled(Level::Info));
+        assert!(!logger.set_level_from_str("loud"));
+    }
+
+    #[test]
+    fn test_group_functions_are_noops_on_native() {
+        group("Exporting courses");
+        group("nested");
+        group_end();
+        group_end();
+        // Unbalanced calls must not panic either
+        group_end();
+    }
+
+    #[test]
+    fn test_prefix_is_plain_when_not_a_terminal() {
+        let logger = Logger::new();
+        assert!(!logger.use_color(false));
+        assert_eq!(styled_prefix("[ERROR]", logger.use_color(false)), "[ERROR]");
+
+        assert_eq!(styled_prefix("[ERROR]", true), "\x1b[1;31m[ERROR]\x1b[0m");
+        assert_eq!(styled_prefix("", true), "");
+
+        logger.disable_color();
+        assert!(!logger.use_color(true));
+    }
+
+    #[test]
+    fn test_lazy_macros_skip_arguments_when_suppressed() {
+        let logger = Logger::new();
+        let captured = Arc::new(Mutex::new(Vec::new()));
+        let sink_captured = Arc::clone(&captured);
+        logger.set_sink(Box::new(move |level, msg| {
+            sink_captured.lock().unwrap().push((level, msg.to_string()));
+        }));
+        logger.set_level(Level::Warn);
+
+        let expensive = || -> String { panic!("argument evaluated for a suppressed level") };
+        crate::info_lazy!(logger: &logger, "dump: {}", expensive());
+        crate::debug_lazy!(logger: &logger, "dump: {}", expensive());
+
+        let mut evaluated = false;
+        crate::error_lazy!(logger: &logger, "{}", {
+            evaluated = true;
+            "cycle detected"
+        });
+        assert!(evaluated);
+        assert_eq!(
+            *captured.lock().unwrap(),
+            vec![(Level::Error, "cycle detected".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_forced_color_overrides_terminal_detection() {
+        use std::io::Write as _;
+
+        let logger = Logger::new();
+        // Piped output stays plain by default
+        assert_eq!(
+            logger.console_line("[WARN]", "low credits", false),
+            "[WARN] low credits"
+        );
+
+        logger.set_color(true);
+        let mut captured = Vec::new();
+        for (prefix, msg) in [("[ERROR]", "cycle"), ("[WARN]", "low"), ("[DEBUG]", "scan")] {
+            writeln!(captured, "{}", logger.console_line(prefix, msg, false)).unwrap();
+        }
+        let captured = String::from_utf8(captured).unwrap();
+        assert!(captured.contains("\x1b[1;31m[ERROR]\x1b[0m cycle"));
+        assert!(captured.contains("\x1b[1;33m[WARN]\x1b[0m low"));
+        assert!(captured.contains("\x1b[2m[DEBUG]\x1b[0m scan"));
+
+        logger.set_color(false);
+        assert!(!logger
+            .console_line("[ERROR]", "cycle", true)
+            .contains('\x1b'));
+
+        logger.enable_color();
+        assert!(!logger
+            .console_line("[ERROR]", "cycle", false)
+            .contains('\x1b'));
+    }
+
+    #[test]
+    fn test_verbose_is_per_instance() {
+        let a = Logger::new();
+        let b = Logger::new();
+        a.enable_verbose();
+
+        assert_eq!(a.is_verbose_enabled(), cfg!(feature = "verbose"));
+        assert!(!b.is_verbose_enabled());
+    }
+}
@@ -139,6 +139,20 @@ fn test_config_unset() {
     assert_eq!(config.logging.level, defaults.logging.level);
 }
 
+#[test]
+fn test_diff_from_defaults_lists_only_changed_level() {
+    let mut config = Config::from_defaults();
+    assert!(config.diff_from_defaults().is_empty());
+
+    let default_level = config.logging.level.clone();
+    config.set("level", "trace").expect("Failed to set level");
+
+    assert_eq!(
+        config.diff_from_defaults(),
+        vec![("level".to_string(), default_level, "trace".to_string())]
+    );
+}
+
 #[test]
 fn test_config_save_and_load() {
     let (_temp_dir, config_file) = setup_temp_config();
@@ -161,6 +175,42 @@ fn test_config_save_and_load() {
     assert_eq!(loaded_config.logging.level, "info");
 }
 
+#[test]
+fn test_config_save_to_replaces_file_atomically() {
+    let (temp_dir, config_file) = setup_temp_config();
+    fs::write(&config_file, "[Logging]\nlevel = \"warn\"\n").expect("Failed to seed config");
+
+    let mut config = Config::from_defaults();
+    config.set("level", "debug").expect("Failed to set level");
+    config.save_to(&config_file).expect("Failed to save config");
+
+    let content = fs::read_to_string(&config_file).expect("Failed to read config");
+    let loaded = Config::from_toml(&content).expect("Saved config should parse");
+    assert_eq!(loaded.logging.level, "debug");
+    assert_eq!(loaded.paths.metrics_dir, config.paths.metrics_dir);
+
+    // Only the config itself is left behind
+    let entries: Vec<_> = fs::read_dir(temp_dir.path())
+        .expect("Failed to list dir")
+        .map(|e| e.expect("entry").file_name())
+        .collect();
+    assert_eq!(entries, vec![std::ffi::OsString::from("config.toml")]);
+}
+
+#[test]
+fn test_config_save_to_cleans_up_temp_file_on_error() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    // A directory at the target path makes the final rename fail
+    let target = temp_dir.path().join("config.toml");
+    fs::create_dir(&target).expect("Failed to create blocking dir");
+
+    let result = Config::from_defaults().save_to(&target);
+
+    assert!(result.is_err());
+    assert!(target.is_dir());
+    assert!(!temp_dir.path().join(".config.toml.tmp").exists());
+}
+
 #[test]
 fn test_config_overrides_apply() {
     let mut config = Config::from_defaults();
@@ -300,3 +350,142 @@ fn test_get_config_file_path() {
     let path_str = path.to_string_lossy();
     assert!(path_str.ends_with("config.toml") || path_str.ends_with("dconfig.toml"));
 }
+
+#[test]
+fn test_validate_defaults_ok() {
+    let config = Config::from_defaults();
+    assert!(
+        config.validate().is_ok(),
+        "Defaults should validate: {:?}",
+        config.validate().err()
+    );
+}
+
+#[test]
+fn test_validate_invalid_level() {
+    let mut config = Config::from_defaults();
+    config.set("level", "loud").unwrap();
+
+    let problems = config.validate().expect_err("invalid level should fail");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].starts_with("logging.level"));
+}
+
+#[test]
+fn test_validate_invalid_report_format() {
+    let mut config = Config::from_defaults();
+    config.set("report_format", "docx").unwrap();
+
+    let problems = config.validate().expect_err("invalid format should fail");
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].starts_with("report.format"));
+}
+
+#[test]
+fn test_validate_path_under_file() {
+    let (_temp_dir, config_file) = setup_temp_config();
+    fs::write(&config_file, "").unwrap();
+
+    let mut config = Config::from_defaults();
+    config.paths.metrics_dir = config_file.join("metrics").to_string_lossy().to_string();
+
+    let problems = config
+        .validate()
+        .expect_err("path under a file should fail");
+    assert!(problems.iter().any(|p| p.starts_with("paths.metrics_dir")));
+}
+
+#[test]
+fn test_set_records_audit_entry_with_prior_value() {
+    let (temp_dir, _config_file) = setup_temp_config();
+    let audit_file = temp_dir.path().join("config.audit.log");
+
+    let mut config = Config::from_defaults();
+    config.database.endpoint = "https://old.example.edu".to_string();
+
+    // Auditing is off by default, so nothing is recorded
+    config.set("level", "info").unwrap();
+    assert!(config.pending_audit().is_empty());
+
+    config.set("audit", "true").unwrap();
+    config.set("endpoint", "https://new.example.edu").unwrap();
+
+    let entry = config
+        .pending_audit()
+        .iter()
+        .find(|e| e.key == "endpoint")
+        .expect("endpoint change should be audited");
+    assert_eq!(entry.operation, "set");
+    assert_eq!(entry.old.as_deref(), Some("https://old.example.edu"));
+    assert_eq!(entry.new.as_deref(), Some("https://new.example.edu"));
+
+    config.append_audit_log(&audit_file).unwrap();
+    assert!(config.pending_audit().is_empty());
+
+    let log = fs::read_to_string(&audit_file).unwrap();
+    assert_eq!(log.lines().count(), 2);
+    assert!(log.contains("set audit: \"false\" -> \"true\""));
+    assert!(
+        log.contains("set endpoint: \"https://old.example.edu\" -> \"https://new.example.edu\"")
+    );
+
+    // Later changes are appended rather than overwriting
+    config.unset("endpoint", &Config::from_defaults()).unwrap();
+    config.append_audit_log(&audit_file).unwrap();
+    let log = fs::read_to_string(&audit_file).unwrap();
+    assert_eq!(log.lines().count(), 3);
+    assert!(log.lines().last().unwrap().contains("unset endpoint:"));
+}
+
+#[test]
+fn test_audit_redacts_token_values() {
+    let (temp_dir, _config_file) = setup_temp_config();
+    let audit_file = temp_dir.path().join("config.audit.log");
+
+    let mut config = Config::from_defaults();
+    config.database.token = "old-secret".to_string();
+    config.set("audit", "on").unwrap();
+    config.set("token", "new-secret").unwrap();
+    config.unset("token", &Config::from_defaults()).unwrap();
+
+    let entries: Vec<_> = config
+        .pending_audit()
+        .iter()
+        .filter(|e| e.key == "token")
+        .collect();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].old.as_deref(), Some("***"));
+    assert_eq!(entries[0].new.as_deref(), Some("***"));
+
+    config.append_audit_log(&audit_file).unwrap();
+    let log = fs::read_to_string(&audit_file).unwrap();
+    assert!(log.contains("set token: \"***\" -> \"***\""));
+    assert!(!log.contains("secret"));
+}
+
+#[test]
+fn test_external_defaults_take_precedence() {
+    let (temp_dir, _config_file) = setup_temp_config();
+    let compiled = Config::compiled_defaults();
+
+    // No defaults.toml: the compiled-in defaults are used
+    let defaults = Config::from_defaults_in(temp_dir.path());
+    assert_eq!(defaults.logging.level, compiled.logging.level);
+    assert_eq!(defaults.report.format, compiled.report.format);
+
+    fs::write(
+        temp_dir.path().join("defaults.toml"),
+        "[logging]\nlevel = \"error\"\n\n[report]\nformat = \"md\"\n",
+    )
+    .unwrap();
+    let defaults = Config::from_defaults_in(temp_dir.path());
+    assert_eq!(defaults.logging.level, "error");
+    assert_eq!(defaults.report.format, "md");
+    // Fields the external file omits still come from the compiled defaults
+    assert_eq!(defaults.paths.metrics_dir, compiled.paths.metrics_dir);
+
+    // An invalid file falls back to the compiled defaults
+    fs::write(temp_dir.path().join("defaults.toml"), "not = [valid").unwrap();
+    let defaults = Config::from_defaults_in(temp_dir.path());
+    assert_eq!(defaults.logging.level, compiled.logging.level);
+}
@@ -0,0 +1,36 @@
+//! Integration tests that run the `nuanalytics` binary
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn planner_writes_report_and_metrics_to_separate_dirs() {
+    let dir = tempfile::tempdir().unwrap();
+    let reports = dir.path().join("reports");
+    let metrics = dir.path().join("metrics");
+    let input = Path::new(env!("CARGO_MANIFEST_DIR")).join("samples/plans/Colostate_CSDegree.csv");
+
+    // Run from the temp dir with HOME pointed there so no user config or logs are touched
+    let output = Command::new(env!("CARGO_BIN_EXE_nuanalytics"))
+        .current_dir(dir.path())
+        .env("HOME", dir.path())
+        .env("XDG_CONFIG_HOME", dir.path().join(".config"))
+        .arg("planner")
+        .arg(&input)
+        .args(["--report-format", "html", "--report-dir"])
+        .arg(&reports)
+        .arg("--metrics-dir")
+        .arg(&metrics)
+        .output()
+        .expect("run nuanalytics");
+    assert!(
+        output.status.success(),
+        "planner failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert!(reports.join("Colostate_CSDegree_report.html").is_file());
+    assert!(metrics.join("Colostate_CSDegree_w_metrics.csv").is_file());
+    assert!(!reports.join("Colostate_CSDegree_w_metrics.csv").exists());
+    assert!(!metrics.join("Colostate_CSDegree_report.html").exists());
+}
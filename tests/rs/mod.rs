@@ -1,5 +1,6 @@
 //! Rust integration test modules
 
+pub mod cli;
 pub mod metrics_comparison;
 pub mod planner;
 pub mod smoke;
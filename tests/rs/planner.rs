@@ -1,6 +1,8 @@
 //! Integration tests for planner CSV parsing
 
+use nu_analytics::core::approx::credits_eq;
 use nu_analytics::core::planner::csv_parser::parse_curriculum_csv;
+use std::collections::HashMap;
 
 #[test]
 fn test_parse_colostate_cs_curriculum() {
@@ -45,7 +47,7 @@ fn test_parse_colostate_cs_curriculum() {
     assert_eq!(cs220.name, "Discrete Structures and their Applications");
     assert_eq!(cs220.prefix, "CS");
     assert_eq!(cs220.number, "220");
-    assert!((cs220.credit_hours - 4.0).abs() < f32::EPSILON);
+    assert!(credits_eq(cs220.credit_hours, 4.0));
 
     // Verify prerequisites were resolved correctly
     assert!(
@@ -94,3 +96,75 @@ fn test_parse_nonexistent_file() {
     let result = parse_curriculum_csv(csv_path);
     assert!(result.is_err(), "Should fail for nonexistent file");
 }
+
+#[test]
+fn test_plan_total_credits_matches_school_lookup() {
+    let school = parse_curriculum_csv("samples/plans/Colostate_CSDegree.csv").unwrap();
+    let plan = &school.plans[0];
+
+    let school_total: f32 = plan
+        .courses
+        .iter()
+        .filter_map(|key| school.get_course(key))
+        .map(|c| c.credit_hours)
+        .sum();
+
+    // Detach the credits from the school, as a trimmed model would
+    let credits: HashMap<String, f32> = school
+        .courses_with_keys()
+        .map(|(key, c)| (key.clone(), c.credit_hours))
+        .collect();
+    let closure_total = plan.total_credits(|key| credits.get(key).copied());
+
+    assert!(school_total > 0.0);
+    assert!(credits_eq(closure_total, school_total));
+}
+
+#[test]
+fn test_strict_corequisites_survive_export_round_trip() {
+    use nu_analytics::core::{metrics, metrics_export};
+    use std::io::Write;
+
+    let dir = tempfile::tempdir().expect("temp dir");
+    let input = dir.path().join("strict.csv");
+    let mut file = std::fs::File::create(&input).expect("create input");
+    writeln!(file, "Curriculum,Strict Coreqs").unwrap();
+    writeln!(file, "Institution,Test U").unwrap();
+    writeln!(file, "Degree Type,BS").unwrap();
+    writeln!(file, "System Type,semester").unwrap();
+    writeln!(file, "CIP,11.0701").unwrap();
+    writeln!(file, "Courses").unwrap();
+    writeln!(
+        file,
+        "Course ID,Course Name,Prefix,Number,Prerequisites,Corequisites,Strict-Corequisites,Credit Hours,Institution,Canonical Name"
+    )
+    .unwrap();
+    writeln!(file, "1,Physics I,PHYS,101,,,,3,,").unwrap();
+    writeln!(file, "2,Physics I Lab,PHYS,101L,,,1,1,,").unwrap();
+    writeln!(file, "3,Calculus I,MATH,151,,1,,4,,").unwrap();
+    drop(file);
+
+    let school = parse_curriculum_csv(&input).expect("parse original");
+    let plan = school.plans.first().expect("default plan").clone();
+    let all_metrics = metrics::compute_all_metrics(&school.build_dag()).expect("metrics");
+
+    let exported = dir.path().join("strict_w_metrics.csv");
+    metrics_export::export_metrics_csv(&school, &plan, &all_metrics, &exported).expect("export");
+
+    let reparsed = parse_curriculum_csv(&exported).expect("reparse export");
+    for school in [&school, &reparsed] {
+        let lab = school.get_course("PHYS101L").expect("lab exists");
+        assert_eq!(lab.strict_corequisites, vec!["PHYS101".to_string()]);
+        assert!(lab.corequisites.is_empty(), "strict coreq demoted");
+
+        let calc = school.get_course("MATH151").expect("calculus exists");
+        assert_eq!(calc.corequisites, vec!["PHYS101".to_string()]);
+        assert!(
+            calc.strict_corequisites.is_empty(),
+            "regular coreq promoted"
+        );
+    }
+    assert!(reparsed
+        .build_dag()
+        .is_strict_corequisite("PHYS101L", "PHYS101"));
+}